@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use bencher_adapter::{Adaptable, Settings};
+use bencher_adapter::{AdapterConvert, Settings};
 use bencher_json::project::report::Adapter;
 
 const JSON_RESULT: &str = r#"{
@@ -37,14 +37,14 @@ const JSON_RESULT: &str = r#"{
 fn adapter_magic_json(c: &mut Criterion) {
     c.bench_function("Adapter::Magic (JSON)", |b| {
         let settings = Settings::default();
-        b.iter(|| Adapter::Magic.convert(JSON_RESULT, settings));
+        b.iter(|| Adapter::Magic.convert(JSON_RESULT, settings.clone()));
     });
 }
 
 fn adapter_json(c: &mut Criterion) {
     c.bench_function("Adapter::Json", |b| {
         let settings = Settings::default();
-        b.iter(|| Adapter::Json.convert(JSON_RESULT, settings));
+        b.iter(|| Adapter::Json.convert(JSON_RESULT, settings.clone()));
     });
 }
 
@@ -64,21 +64,21 @@ test result: ok. 0 passed; 0 failed; 1 ignored; 4 measured; 0 filtered out; fini
 fn adapter_magic_rust(c: &mut Criterion) {
     c.bench_function("Adapter::Magic (Rust)", |b| {
         let settings = Settings::default();
-        b.iter(|| Adapter::Magic.convert(RUST_RESULT, settings));
+        b.iter(|| Adapter::Magic.convert(RUST_RESULT, settings.clone()));
     });
 }
 
 fn adapter_rust(c: &mut Criterion) {
     c.bench_function("Adapter::Rust", |b| {
         let settings = Settings::default();
-        b.iter(|| Adapter::Rust.convert(RUST_RESULT, settings));
+        b.iter(|| Adapter::Rust.convert(RUST_RESULT, settings.clone()));
     });
 }
 
 fn adapter_rust_bench(c: &mut Criterion) {
     c.bench_function("Adapter::RustBench", |b| {
         let settings = Settings::default();
-        b.iter(|| Adapter::RustBench.convert(RUST_RESULT, settings));
+        b.iter(|| Adapter::RustBench.convert(RUST_RESULT, settings.clone()));
     });
 }
 
@@ -1,25 +1,39 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonNewMetric, MeasureNameId,
+};
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
-    adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    adapters::util::{benchmark_parameters, latency_as_nanos, try_parse_json, Units},
+    results::adapter_results::{
+        AdapterResults, ALLOCATED_BYTES_NAME_ID, GEN0_COLLECTIONS_NAME_ID,
+        GEN1_COLLECTIONS_NAME_ID, GEN2_COLLECTIONS_NAME_ID, LATENCY_NAME_ID,
+    },
+    Adaptable, AdapterError, ParseError, Settings,
 };
 
 pub struct AdapterCSharpDotNet;
 
 impl Adaptable for AdapterCSharpDotNet {
+    const ADAPTER: Adapter = Adapter::CSharpDotNet;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        serde_json::from_str::<DotNet>(input)
-            .ok()?
-            .convert(settings)
-            .ok()?
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |dot_net: DotNet| {
+            dot_net.convert(&settings)
+        })
     }
 }
 
+// BenchmarkDotNet reports garbage collections per 1,000 operations
+const GC_OPERATIONS: f64 = 1_000.0;
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DotNet {
@@ -35,7 +49,9 @@ pub struct Benchmarks(pub Vec<Benchmark>);
 pub struct Benchmark {
     pub namespace: BenchmarkName,
     pub method: BenchmarkName,
+    pub parameters: Option<String>,
     pub statistics: Statistics,
+    pub memory: Option<Memory>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -49,47 +65,151 @@ pub struct Statistics {
     pub median: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub interquartile_range: Decimal,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub q1: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub q3: Option<Decimal>,
+}
+
+// Only available when using the `MemoryDiagnoser`
+// https://benchmarkdotnet.org/articles/configs/diagnosers.html
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Memory {
+    pub gen0_collections: u64,
+    pub gen1_collections: u64,
+    pub gen2_collections: u64,
+    pub total_operations: u64,
+    pub bytes_allocated_per_operation: u64,
 }
 
 impl DotNet {
-    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+    fn convert(self, settings: &Settings) -> Result<Option<AdapterResults>, AdapterError> {
         let benchmarks = self.benchmarks.0;
         let mut benchmark_metrics = Vec::with_capacity(benchmarks.len());
         for benchmark in benchmarks {
             let Benchmark {
                 namespace: mut benchmark_name,
                 method,
+                parameters,
                 statistics,
+                memory,
             } = benchmark;
             let Statistics {
                 mean,
                 standard_deviation,
                 median,
                 interquartile_range,
+                q1,
+                q3,
             } = statistics;
 
             benchmark_name.try_push('.', &method)?;
+            if let Some(parameters) = parameters.as_deref().and_then(parameters_suffix) {
+                benchmark_name = format!("{benchmark_name}{parameters}").parse()?;
+            }
 
             // JSON output is always in nanos
             let units = Units::Nano;
             // The `Mode` is called `Throughput` but it appears to be measuring latency
             // https://benchmarkdotnet.org/articles/guides/choosing-run-strategy.html#throughput
-            let (average, spread) = match settings.average.unwrap_or_default() {
-                JsonAverage::Mean => (mean, standard_deviation),
-                JsonAverage::Median => (median, interquartile_range),
-            };
-            let value = latency_as_nanos(average, units);
-            let spread = latency_as_nanos(spread, units);
-            let json_metric = JsonNewMetric {
-                value,
-                lower_value: Some(value - spread),
-                upper_value: Some(value + spread),
+            let (value, lower_value, upper_value) = match settings.average.unwrap_or_default() {
+                JsonAverage::Mean => {
+                    let value = latency_as_nanos(mean, units);
+                    let spread = latency_as_nanos(standard_deviation, units);
+                    (value, value - spread, value + spread)
+                },
+                JsonAverage::Median => {
+                    let value = latency_as_nanos(median, units);
+                    // Use the 25th and 75th percentiles as the bounds when available
+                    if let (Some(q1), Some(q3)) = (q1, q3) {
+                        (
+                            value,
+                            latency_as_nanos(q1, units),
+                            latency_as_nanos(q3, units),
+                        )
+                    } else {
+                        let spread = latency_as_nanos(interquartile_range, units);
+                        (value, value - spread, value + spread)
+                    }
+                },
             };
-
-            benchmark_metrics.push((benchmark_name, json_metric));
+            let mut metrics = vec![(
+                LATENCY_NAME_ID.clone(),
+                JsonNewMetric {
+                    value,
+                    lower_value: Some(lower_value),
+                    upper_value: Some(upper_value),
+                    samples: None,
+                },
+            )];
+            if let Some(memory) = memory {
+                metrics.extend(memory.into_metrics());
+            }
+
+            benchmark_metrics.push((benchmark_name, metrics));
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+// The parameters are formatted as `Name=Value&Name=Value` in the order they are declared.
+fn parameters_suffix(parameters: &str) -> Option<String> {
+    benchmark_parameters(
+        parameters
+            .split('&')
+            .filter(|parameter| !parameter.is_empty())
+            .map(|parameter| parameter.split_once('=').unwrap_or((parameter, ""))),
+    )
+}
+
+impl Memory {
+    #[allow(clippy::cast_precision_loss)]
+    fn into_metrics(self) -> Vec<(MeasureNameId, JsonNewMetric)> {
+        let Self {
+            gen0_collections,
+            gen1_collections,
+            gen2_collections,
+            total_operations,
+            bytes_allocated_per_operation,
+        } = self;
+        let per_operations = |collections: u64| -> JsonNewMetric {
+            let value = if total_operations == 0 {
+                0.0
+            } else {
+                collections as f64 * GC_OPERATIONS / total_operations as f64
+            };
+            JsonNewMetric {
+                value: value.into(),
+                lower_value: None,
+                upper_value: None,
+                samples: None,
+            }
+        };
+        vec![
+            (
+                ALLOCATED_BYTES_NAME_ID.clone(),
+                JsonNewMetric {
+                    value: (bytes_allocated_per_operation as f64).into(),
+                    lower_value: None,
+                    upper_value: None,
+                    samples: None,
+                },
+            ),
+            (
+                GEN0_COLLECTIONS_NAME_ID.clone(),
+                per_operations(gen0_collections),
+            ),
+            (
+                GEN1_COLLECTIONS_NAME_ID.clone(),
+                per_operations(gen1_collections),
+            ),
+            (
+                GEN2_COLLECTIONS_NAME_ID.clone(),
+                per_operations(gen2_collections),
+            ),
+        ]
     }
 }
 
@@ -101,6 +221,7 @@ pub(crate) mod test_c_sharp_dot_net {
     use crate::{
         adapters::test_util::{
             convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_measure,
         },
         AdapterResults, Settings,
     };
@@ -131,9 +252,7 @@ pub(crate) mod test_c_sharp_dot_net {
 
         let results = opt_convert_file_path::<AdapterCSharpDotNet>(
             &file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_c_sharp_dot_net(&results);
@@ -175,8 +294,8 @@ pub(crate) mod test_c_sharp_dot_net {
         validate_latency(
             metrics,
             10_360_382.695_312_5,
-            Some(10_305_689.064_843_748),
-            Some(10_415_076.325_781_252),
+            Some(10_337_369.528_437_5),
+            Some(10_392_063.158_906_251),
         );
 
         let metrics = results
@@ -185,8 +304,8 @@ pub(crate) mod test_c_sharp_dot_net {
         validate_latency(
             metrics,
             20_362_636.192_500_003,
-            Some(20_283_296.531_562_5),
-            Some(20_441_975.853_437_506),
+            Some(20_325_342.761_249_997),
+            Some(20_404_682.422_187_5),
         );
     }
 
@@ -221,16 +340,55 @@ pub(crate) mod test_c_sharp_dot_net {
         validate_latency(
             metrics,
             24.419_498_533_010_483,
-            Some(24.247_002_340_853_214),
-            Some(24.591_994_725_167_75),
+            Some(24.332_126_095_891),
+            Some(24.504_622_288_048_267),
         );
 
         let metrics = results.get("Sample.Fib20").unwrap();
         validate_latency(
             metrics,
             51.401_955_902_576_45,
-            Some(50.498_313_307_762_146),
-            Some(52.305_598_497_390_75),
+            Some(51.034_146_726_131_44),
+            Some(51.937_789_320_945_74),
         );
     }
+
+    #[test]
+    fn test_adapter_c_sharp_dot_net_memory() {
+        let results = convert_c_sharp_dot_net("memory");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("Sample.Concat(Count: 2, Size: 10)").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_measure(metrics, "latency", 100.0, Some(90.0), Some(110.0));
+        validate_measure(metrics, "allocated-bytes", 56.0, None, None);
+        validate_measure(metrics, "gen0-collections", 0.03, None, None);
+        validate_measure(metrics, "gen1-collections", 0.0, None, None);
+        validate_measure(metrics, "gen2-collections", 0.0, None, None);
+
+        let metrics = results.get("Sample.Concat(Count: 2, Size: 100)").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_measure(metrics, "latency", 400.0, Some(380.0), Some(420.0));
+        validate_measure(metrics, "allocated-bytes", 256.0, None, None);
+        validate_measure(metrics, "gen0-collections", 0.15, None, None);
+        validate_measure(metrics, "gen1-collections", 0.002, None, None);
+        validate_measure(metrics, "gen2-collections", 0.001, None, None);
+
+        let metrics = results.get("Sample.Builder").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_measure(metrics, "latency", 250.0, Some(245.0), Some(255.0));
+        validate_measure(metrics, "allocated-bytes", 0.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_c_sharp_dot_net_memory_median() {
+        let results = convert_c_sharp_dot_net_median("memory");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("Sample.Concat(Count: 2, Size: 10)").unwrap();
+        validate_measure(metrics, "latency", 98.0, Some(95.0), Some(105.0));
+
+        let metrics = results.get("Sample.Concat(Count: 2, Size: 100)").unwrap();
+        validate_measure(metrics, "latency", 390.0, Some(380.0), Some(410.0));
+    }
 }
@@ -1,13 +1,27 @@
 pub mod dot_net;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
+use bencher_json::project::report::Adapter;
 use dot_net::AdapterCSharpDotNet;
 
 pub struct AdapterCSharp;
 
 impl Adaptable for AdapterCSharp {
+    const ADAPTER: Adapter = Adapter::CSharp;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterCSharpDotNet::parse(input, settings)
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterCSharpDotNet::try_parse],
+        )
     }
 }
 
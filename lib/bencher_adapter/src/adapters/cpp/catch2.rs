@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric, MeasureNameId,
+};
 use nom::{
     character::complete::{anychar, space0, space1},
     combinator::{eof, map, map_res},
@@ -7,47 +10,72 @@ use nom::{
     IResult,
 };
 use ordered_float::OrderedFloat;
+use serde::Deserialize;
 
 use crate::{
     adapters::util::{
         latency_as_nanos, nom_error, parse_benchmark_name_chars, parse_f64, parse_u64, parse_units,
-        NomError, Units,
+        try_parse_xml, FurthestLine, NomError, Units,
     },
-    results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    results::adapter_results::{AdapterResults, LATENCY_NAME_ID},
+    Adaptable, AdapterError, ParseError, Settings,
 };
 
 pub struct AdapterCppCatch2;
 
 impl Adaptable for AdapterCppCatch2 {
+    const ADAPTER: Adapter = Adapter::CppCatch2;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
+            Some(JsonAverage::Median) => return Err(ParseError::new(Self::ADAPTER)),
         }
 
-        let mut benchmark_metrics = Vec::new();
+        let console_error = match parse_catch2_console(input) {
+            Ok(results) => return Ok(results),
+            Err(e) => e,
+        };
+        // Only report the XML reporter error if the output looks like XML
+        if input.trim_start().starts_with('<') {
+            try_parse_xml(Self::ADAPTER, input, Catch2Run::convert)
+        } else {
+            Err(console_error)
+        }
+    }
+}
 
-        let mut benchmark_name_line = None;
-        let mut mean_line = None;
-        for line in input.lines() {
-            if let Some(benchmark_name_line) = benchmark_name_line {
-                if let Some(mean_line) = mean_line {
-                    if let Ok((remainder, benchmark_metric)) =
-                        parse_catch2(benchmark_name_line, mean_line, line)
-                    {
-                        if remainder.is_empty() {
-                            benchmark_metrics.push(benchmark_metric);
-                        }
+// The console reporter
+fn parse_catch2_console(input: &str) -> Result<AdapterResults, ParseError> {
+    let mut benchmark_metrics = Vec::new();
+
+    let mut furthest_line = FurthestLine::default();
+    let mut benchmark_name_line = None;
+    let mut mean_line = None;
+    for (index, line) in input.lines().enumerate() {
+        // Each timing row is tracked, as the benchmark name is only parsed after the standard deviation
+        furthest_line.track(index, line, &parse_catch2_time(line));
+        if let Some(benchmark_name_line) = benchmark_name_line {
+            if let Some(mean_line) = mean_line {
+                if let Ok((remainder, benchmark_metric)) =
+                    parse_catch2(benchmark_name_line, mean_line, line)
+                {
+                    if remainder.is_empty() {
+                        benchmark_metrics.push(benchmark_metric);
                     }
                 }
             }
-
-            benchmark_name_line = mean_line.replace(line);
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        benchmark_name_line = mean_line.replace(line);
     }
+
+    AdapterResults::new_latency(benchmark_metrics)
+        .ok_or_else(|| furthest_line.into_error(AdapterCppCatch2::ADAPTER, input))
 }
 
 fn parse_catch2<'i>(
@@ -72,6 +100,7 @@ fn parse_catch2<'i>(
                     value: mean,
                     lower_value: Some(mean - std_dev),
                     upper_value: Some(mean + std_dev),
+                    samples: None,
                 };
 
                 Ok((benchmark_name, json_metric))
@@ -147,13 +176,175 @@ fn parse_catch2_prelude(input: &str) -> IResult<&str, Prelude> {
     )(input)
 }
 
+pub const LATENCY_STD_DEV_SLUG_STR: &str = "latency-std-dev";
+pub const OUTLIERS_SLUG_STR: &str = "outliers";
+pub const CATCH2_NAME_SEPARATOR: &str = "/";
+
+// The XML reporter
+// Catch2 v2 wraps the test cases in a `<Group>`, while Catch2 v3 does not.
+// https://github.com/catchorg/Catch2/blob/devel/docs/reporters.md
+#[derive(Debug, Clone, Deserialize)]
+pub struct Catch2Run {
+    #[serde(rename = "Group", default)]
+    pub groups: Vec<Catch2Run>,
+    #[serde(rename = "TestCase", default)]
+    pub test_cases: Vec<Catch2Section>,
+}
+
+// Both `<TestCase>` and `<Section>` elements may contain benchmarks and sections
+#[derive(Debug, Clone, Deserialize)]
+pub struct Catch2Section {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "Section", default)]
+    pub sections: Vec<Catch2Section>,
+    #[serde(rename = "BenchmarkResults", default)]
+    pub benchmark_results: Vec<Catch2BenchmarkResults>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Catch2BenchmarkResults {
+    #[serde(rename = "@name")]
+    pub name: String,
+    pub mean: Catch2Estimate,
+    #[serde(rename = "standardDeviation")]
+    pub standard_deviation: Catch2Estimate,
+    pub outliers: Option<Catch2Outliers>,
+}
+
+// All values are in nanoseconds
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Catch2Estimate {
+    #[serde(rename = "@value")]
+    pub value: f64,
+    #[serde(rename = "@lowerBound")]
+    pub lower_bound: f64,
+    #[serde(rename = "@upperBound")]
+    pub upper_bound: f64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Catch2Outliers {
+    #[serde(rename = "@lowMild")]
+    pub low_mild: u64,
+    #[serde(rename = "@lowSevere")]
+    pub low_severe: u64,
+    #[serde(rename = "@highMild")]
+    pub high_mild: u64,
+    #[serde(rename = "@highSevere")]
+    pub high_severe: u64,
+}
+
+impl Catch2Run {
+    fn convert(self) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::new();
+        self.push_metrics(&mut benchmark_metrics)?;
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+
+    fn push_metrics(
+        self,
+        benchmark_metrics: &mut Vec<(BenchmarkName, Vec<(MeasureNameId, JsonNewMetric)>)>,
+    ) -> Result<(), AdapterError> {
+        let Self { groups, test_cases } = self;
+        for group in groups {
+            group.push_metrics(benchmark_metrics)?;
+        }
+        for test_case in test_cases {
+            test_case.push_metrics(&[], benchmark_metrics)?;
+        }
+        Ok(())
+    }
+}
+
+impl Catch2Section {
+    // The benchmark name is the `TEST_CASE` name followed by any `SECTION` names
+    fn push_metrics(
+        self,
+        parents: &[&str],
+        benchmark_metrics: &mut Vec<(BenchmarkName, Vec<(MeasureNameId, JsonNewMetric)>)>,
+    ) -> Result<(), AdapterError> {
+        let Self {
+            name,
+            sections,
+            benchmark_results,
+        } = self;
+        let mut path = parents.to_vec();
+        path.push(&name);
+        for benchmark_result in benchmark_results {
+            let benchmark_name = [path.as_slice(), &[&benchmark_result.name]]
+                .concat()
+                .join(CATCH2_NAME_SEPARATOR)
+                .parse()?;
+            benchmark_metrics.push((benchmark_name, benchmark_result.into_metrics()?));
+        }
+        for section in sections {
+            section.push_metrics(&path, benchmark_metrics)?;
+        }
+        Ok(())
+    }
+}
+
+impl Catch2BenchmarkResults {
+    #[allow(clippy::cast_precision_loss)]
+    fn into_metrics(self) -> Result<Vec<(MeasureNameId, JsonNewMetric)>, AdapterError> {
+        let Self {
+            mean,
+            standard_deviation,
+            outliers,
+            ..
+        } = self;
+        let mut metrics = vec![
+            (LATENCY_NAME_ID.clone(), mean.into_metric()),
+            (
+                LATENCY_STD_DEV_SLUG_STR.parse()?,
+                standard_deviation.into_metric(),
+            ),
+        ];
+        if let Some(Catch2Outliers {
+            low_mild,
+            low_severe,
+            high_mild,
+            high_severe,
+        }) = outliers
+        {
+            let outliers = low_mild + low_severe + high_mild + high_severe;
+            metrics.push((
+                OUTLIERS_SLUG_STR.parse()?,
+                JsonNewMetric {
+                    value: (outliers as f64).into(),
+                    lower_value: None,
+                    upper_value: None,
+                    samples: None,
+                },
+            ));
+        }
+        Ok(metrics)
+    }
+}
+
+impl Catch2Estimate {
+    // The bounds are the confidence interval for the estimate
+    fn into_metric(self) -> JsonNewMetric {
+        JsonNewMetric {
+            value: self.value.into(),
+            lower_value: Some(self.lower_bound.into()),
+            upper_value: Some(self.upper_bound.into()),
+            samples: None,
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test_cpp_catch2 {
     use bencher_json::project::report::JsonAverage;
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, opt_convert_file_path, parse_error_file_path, validate_latency,
+            validate_measure, validate_parse_line,
+        },
         AdapterResults, Settings,
     };
 
@@ -200,9 +391,7 @@ pub(crate) mod test_cpp_catch2 {
         let file_path = "./tool_output/cpp/catch2/four.txt";
         let results = opt_convert_file_path::<AdapterCppCatch2>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_cpp_catch2(&results);
@@ -211,9 +400,7 @@ pub(crate) mod test_cpp_catch2 {
             None,
             opt_convert_file_path::<AdapterCppCatch2>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
@@ -251,4 +438,75 @@ pub(crate) mod test_cpp_catch2 {
         let metrics = results.get("Fibonacci 20").unwrap();
         validate_latency(metrics, 1.0, Some(1.0), Some(1.0));
     }
+
+    fn convert_cpp_catch2_xml(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/cpp/catch2/{suffix}.xml");
+        convert_file_path::<AdapterCppCatch2>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_cpp_catch2_xml() {
+        let results = convert_cpp_catch2_xml("four");
+        validate_adapter_cpp_catch2_xml(&results);
+    }
+
+    pub fn validate_adapter_cpp_catch2_xml(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 4);
+
+        let metrics = results.get("Fibonacci/small/Fibonacci 10").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, "latency", 344.0, Some(341.0), Some(349.0));
+        validate_measure(metrics, "latency-std-dev", 19.0, Some(11.0), Some(29.0));
+        validate_measure(metrics, "outliers", 3.0, None, None);
+
+        let metrics = results
+            .get("Fibonacci/large/recursive/Fibonacci 20")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(
+            metrics,
+            "latency",
+            41_731.5,
+            Some(41_250.0),
+            Some(42_622.25),
+        );
+        validate_measure(
+            metrics,
+            "latency-std-dev",
+            3_256.0,
+            Some(2_163.0),
+            Some(5_353.0),
+        );
+        validate_measure(metrics, "outliers", 0.0, None, None);
+
+        let metrics = results.get("More Fibonacci/Fibonacci~ 5!").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, "latency", 36.0, Some(35.0), Some(37.0));
+        validate_measure(metrics, "latency-std-dev", 4.0, Some(3.0), Some(6.0));
+        validate_measure(metrics, "outliers", 4.0, None, None);
+
+        let metrics = results.get("More Fibonacci/Fibonacci-15_bench").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, "latency", 3_789.0, Some(3_734.0), Some(3_888.0));
+        validate_measure(metrics, "latency-std-dev", 362.0, Some(234.0), Some(539.0));
+        validate_measure(metrics, "outliers", 2.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_cpp_catch2_xml_group() {
+        let results = convert_cpp_catch2_xml("group");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("Fibonacci/Fibonacci 10").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, "latency", 344.0, Some(341.0), Some(349.0));
+        validate_measure(metrics, "latency-std-dev", 19.0, Some(11.0), Some(29.0));
+        validate_measure(metrics, "outliers", 3.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_cpp_catch2_parse_error() {
+        let err = parse_error_file_path::<AdapterCppCatch2>("./tool_output/cpp/catch2/invalid.txt");
+        validate_parse_line(&err, 17, Some(60), "344 xs        341 ns        349 ns");
+    }
 }
@@ -1,29 +1,59 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
+use std::collections::HashMap;
+
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonAny, JsonNewMetric, MeasureNameId,
+};
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
-    adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    adapters::util::{latency_as_nanos, try_parse_json, Units},
+    results::adapter_results::{AdapterResults, LATENCY_NAME_ID, THROUGHPUT_NAME_ID},
+    Adaptable, AdapterError, ParseError, Settings,
 };
 
 pub struct AdapterCppGoogle;
 
 impl Adaptable for AdapterCppGoogle {
+    const ADAPTER: Adapter = Adapter::CppGoogle;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
-        }
+        Self::try_parse(input, settings).ok()
+    }
 
-        serde_json::from_str::<Google>(input)
-            .ok()?
-            .try_into()
-            .ok()?
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |google: Google| {
+            google.convert(&settings)
+        })
     }
 }
 
+pub const BYTES_PER_SECOND_SLUG_STR: &str = "bytes-per-second";
+
+// Keys that are always present in the benchmark JSON object.
+// Any other numeric key is a user counter.
+// https://github.com/google/benchmark/blob/v1.8.4/src/json_reporter.cc
+const RESERVED_KEYS: [&str; 16] = [
+    "family_index",
+    "per_family_instance_index",
+    "repetitions",
+    "repetition_index",
+    "threads",
+    "iterations",
+    "cpu_time",
+    "aggregate_unit",
+    "label",
+    "error_message",
+    "big_o",
+    "rms",
+    "cpu_coefficient",
+    "real_coefficient",
+    "complexity_n",
+    "time_unit",
+];
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Google {
     pub context: Context,
@@ -38,33 +68,160 @@ pub struct Context {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Benchmark {
     pub name: BenchmarkName,
+    pub run_name: Option<BenchmarkName>,
+    pub run_type: Option<RunType>,
+    pub aggregate_name: Option<String>,
     #[serde(with = "rust_decimal::serde::float")]
     pub real_time: Decimal,
     pub time_unit: Units,
+    pub bytes_per_second: Option<f64>,
+    pub items_per_second: Option<f64>,
+    pub error_occurred: Option<bool>,
+    #[serde(flatten)]
+    pub counters: HashMap<String, Value>,
 }
 
-impl TryFrom<Google> for Option<AdapterResults> {
-    type Error = AdapterError;
-
-    fn try_from(google: Google) -> Result<Self, Self::Error> {
-        let mut benchmark_metrics = Vec::with_capacity(google.benchmarks.len());
-        for benchmark in google.benchmarks {
-            let Benchmark {
-                name,
-                real_time,
-                time_unit,
-            } = benchmark;
-            let value = latency_as_nanos(real_time, time_unit);
-            let json_metric = JsonNewMetric {
-                value,
-                lower_value: None,
-                upper_value: None,
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunType {
+    Iteration,
+    Aggregate,
+}
+
+// Each measure for a single run or aggregate
+type Sample = Vec<(MeasureNameId, f64)>;
+
+#[derive(Debug, Default)]
+struct Run {
+    iterations: Vec<Sample>,
+    mean: Option<Sample>,
+    median: Option<Sample>,
+    stddev: Option<Sample>,
+}
+
+impl Google {
+    fn convert(self, settings: &Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut run_names = Vec::new();
+        let mut runs: HashMap<BenchmarkName, Run> = HashMap::new();
+        for benchmark in self.benchmarks {
+            if benchmark.error_occurred.unwrap_or_default() {
+                continue;
+            }
+            // Repetitions and their aggregates all share the same run name
+            let run_name = benchmark
+                .run_name
+                .clone()
+                .unwrap_or_else(|| benchmark.name.clone());
+            let run_type = benchmark.run_type.unwrap_or(RunType::Iteration);
+            let aggregate_name = benchmark.aggregate_name.clone();
+            let sample = benchmark.into_sample()?;
+
+            let run = runs.entry(run_name.clone()).or_insert_with(|| {
+                run_names.push(run_name);
+                Run::default()
+            });
+            match (run_type, aggregate_name.as_deref()) {
+                (RunType::Iteration, _) => run.iterations.push(sample),
+                (RunType::Aggregate, Some("mean")) => run.mean = Some(sample),
+                (RunType::Aggregate, Some("median")) => run.median = Some(sample),
+                (RunType::Aggregate, Some("stddev")) => run.stddev = Some(sample),
+                // The coefficient of variation, complexity, and custom statistics are ignored
+                (RunType::Aggregate, _) => {},
+            }
+        }
+
+        let average = settings.average.unwrap_or_default();
+        let mut benchmark_metrics = Vec::with_capacity(run_names.len());
+        for run_name in run_names {
+            let Some(run) = runs.remove(&run_name) else {
+                continue;
             };
+            let measures = run.into_measures(average);
+            if !measures.is_empty() {
+                benchmark_metrics.push((run_name, measures));
+            }
+        }
+
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+impl Benchmark {
+    fn into_sample(self) -> Result<Sample, AdapterError> {
+        let Self {
+            real_time,
+            time_unit,
+            bytes_per_second,
+            items_per_second,
+            counters,
+            ..
+        } = self;
 
-            benchmark_metrics.push((name, json_metric));
+        let mut sample = vec![(
+            LATENCY_NAME_ID.clone(),
+            latency_as_nanos(real_time, time_unit).into_inner(),
+        )];
+        if let Some(items_per_second) = items_per_second {
+            sample.push((THROUGHPUT_NAME_ID.clone(), items_per_second));
+        }
+        if let Some(bytes_per_second) = bytes_per_second {
+            sample.push((BYTES_PER_SECOND_SLUG_STR.parse()?, bytes_per_second));
+        }
+        let mut counters = counters
+            .into_iter()
+            .filter(|(key, _)| !RESERVED_KEYS.contains(&key.as_str()))
+            .filter_map(|(key, value)| value.as_f64().map(|value| (key, value)))
+            .collect::<Vec<_>>();
+        counters.sort_by(|a, b| a.0.cmp(&b.0));
+        for (key, value) in counters {
+            // Skip any user counters that are not valid measure names
+            if let Ok(measure) = key.parse() {
+                sample.push((measure, value));
+            }
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        Ok(sample)
+    }
+}
+
+impl Run {
+    fn into_measures(self, average: JsonAverage) -> Vec<(MeasureNameId, JsonNewMetric)> {
+        let Self {
+            mut iterations,
+            mean,
+            median,
+            stddev,
+        } = self;
+
+        // Google Benchmark only reports aggregates when there are repetitions,
+        // so a lone iteration is its own mean and median.
+        let center = match average {
+            JsonAverage::Mean => mean,
+            JsonAverage::Median => median,
+        }
+        .or_else(|| (iterations.len() == 1).then(|| iterations.remove(0)));
+        let Some(center) = center else {
+            return Vec::new();
+        };
+
+        center
+            .into_iter()
+            .map(|(measure, center)| {
+                let stddev = stddev.as_ref().and_then(|stddev| {
+                    stddev
+                        .iter()
+                        .find(|(m, _)| *m == measure)
+                        .map(|(_, stddev)| *stddev)
+                });
+                let json_metric = JsonNewMetric {
+                    value: center.into(),
+                    lower_value: stddev.map(|stddev| (center - stddev).into()),
+                    upper_value: stddev.map(|stddev| (center + stddev).into()),
+                    samples: None,
+                };
+                (measure, json_metric)
+            })
+            .collect()
     }
 }
 
@@ -74,7 +231,10 @@ pub(crate) mod test_cpp_google {
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_measure,
+        },
         AdapterResults, Settings,
     };
 
@@ -90,22 +250,18 @@ pub(crate) mod test_cpp_google {
         let file_path = "./tool_output/cpp/google/two.txt";
         let results = opt_convert_file_path::<AdapterCppGoogle>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_cpp_google(&results);
 
-        assert_eq!(
-            None,
-            opt_convert_file_path::<AdapterCppGoogle>(
-                file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
-            )
-        );
+        // Without any repetitions, each benchmark is its own median
+        let results = opt_convert_file_path::<AdapterCppGoogle>(
+            file_path,
+            Settings::new(Some(JsonAverage::Median)),
+        )
+        .unwrap();
+        validate_adapter_cpp_google(&results);
     }
 
     #[test]
@@ -114,6 +270,33 @@ pub(crate) mod test_cpp_google {
         validate_adapter_cpp_google(&results);
     }
 
+    #[test]
+    fn test_adapter_cpp_google_repetitions() {
+        let results =
+            convert_file_path::<AdapterCppGoogle>("./tool_output/cpp/google/repetitions.json");
+        validate_adapter_cpp_google_repetitions(&results);
+    }
+
+    #[test]
+    fn test_adapter_cpp_google_repetitions_median() {
+        let results = convert_file_path_median::<AdapterCppGoogle>(
+            "./tool_output/cpp/google/repetitions.json",
+        );
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("BM_memcpy/8").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_measure(metrics, "latency", 11.0, Some(9.0), Some(13.0));
+        validate_measure(
+            metrics,
+            "throughput",
+            70_000_000.0,
+            Some(50_000_000.0),
+            Some(90_000_000.0),
+        );
+        validate_measure(metrics, "cache_misses", 5.0, Some(3.0), Some(7.0));
+    }
+
     pub fn validate_adapter_cpp_google(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 2);
 
@@ -123,4 +306,31 @@ pub(crate) mod test_cpp_google {
         let metrics = results.get("fib_20").unwrap();
         validate_latency(metrics, 27_455.600_415_007_055, None, None);
     }
+
+    pub fn validate_adapter_cpp_google_repetitions(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+        assert_eq!(results.get("BM_error"), None);
+
+        let metrics = results.get("BM_memcpy/8").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_measure(metrics, "latency", 12.0, Some(10.0), Some(14.0));
+        validate_measure(
+            metrics,
+            "throughput",
+            80_000_000.0,
+            Some(60_000_000.0),
+            Some(100_000_000.0),
+        );
+        validate_measure(
+            metrics,
+            "bytes-per-second",
+            600_000_000.0,
+            Some(400_000_000.0),
+            Some(800_000_000.0),
+        );
+        validate_measure(metrics, "cache_misses", 6.0, Some(4.0), Some(8.0));
+
+        let metrics = results.get("BM_sort/1024").unwrap();
+        validate_latency(metrics, 35_500.0, None, None);
+    }
 }
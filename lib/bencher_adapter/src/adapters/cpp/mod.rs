@@ -1,16 +1,29 @@
 pub mod catch2;
 pub mod google;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
+use bencher_json::project::report::Adapter;
 use catch2::AdapterCppCatch2;
 use google::AdapterCppGoogle;
 
 pub struct AdapterCpp;
 
 impl Adaptable for AdapterCpp {
+    const ADAPTER: Adapter = Adapter::Cpp;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterCppCatch2::parse(input, settings)
-            .or_else(|| AdapterCppGoogle::parse(input, settings))
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterCppCatch2::try_parse, AdapterCppGoogle::try_parse],
+        )
     }
 }
 
@@ -28,9 +41,21 @@ mod test_cpp {
         test_cpp_catch2::validate_adapter_cpp_catch2(&results);
     }
 
+    #[test]
+    fn test_adapter_cpp_catch2_xml() {
+        let results = convert_file_path::<AdapterCpp>("./tool_output/cpp/catch2/four.xml");
+        test_cpp_catch2::validate_adapter_cpp_catch2_xml(&results);
+    }
+
     #[test]
     fn test_adapter_cpp_google() {
         let results = convert_file_path::<AdapterCpp>("./tool_output/cpp/google/two.txt");
         test_cpp_google::validate_adapter_cpp_google(&results);
     }
+
+    #[test]
+    fn test_adapter_cpp_google_repetitions() {
+        let results = convert_file_path::<AdapterCpp>("./tool_output/cpp/google/repetitions.json");
+        test_cpp_google::validate_adapter_cpp_google_repetitions(&results);
+    }
 }
@@ -0,0 +1,417 @@
+use bencher_json::{
+    project::report::{Adapter, JsonCsvDelimiter, JsonCsvMeasure, JsonCsvSettings},
+    BenchmarkName, JsonNewMetric, MeasureNameId,
+};
+use std::fmt;
+
+use csv::{ReaderBuilder, StringRecord, Trim};
+
+use crate::{
+    adapters::util::value_column,
+    results::adapter_results::{AdapterResults, LATENCY_NAME_ID},
+    Adaptable, ParseError, Settings,
+};
+
+pub struct AdapterCsv;
+
+const COMMENT: u8 = b'#';
+
+impl Adaptable for AdapterCsv {
+    const ADAPTER: Adapter = Adapter::Csv;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        let csv_settings = settings.csv.unwrap_or_default();
+        let delimiter = csv_settings
+            .delimiter
+            .unwrap_or_else(|| detect_delimiter(input));
+        // Every row must have the same number of columns
+        let rows = ReaderBuilder::new()
+            .delimiter(delimiter.as_byte())
+            .has_headers(false)
+            .comment(Some(COMMENT))
+            .trim(Trim::All)
+            .from_reader(input.as_bytes())
+            .into_records()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                let number = e
+                    .position()
+                    .and_then(|position| usize::try_from(position.line()).ok())
+                    .unwrap_or_default();
+                ParseError::new(Self::ADAPTER)
+                    .with_reason(e)
+                    .with_line(input, number, None)
+            })?;
+
+        let (first_row, other_rows) = rows
+            .split_first()
+            .ok_or_else(|| ParseError::new(Self::ADAPTER))?;
+        let header = has_header(&csv_settings, first_row).then_some(first_row);
+        let rows = if header.is_some() {
+            other_rows
+        } else {
+            rows.as_slice()
+        };
+
+        let benchmark_column = if let Some(benchmark) = &csv_settings.benchmark {
+            column_index(header, first_row.len(), benchmark)?
+        } else {
+            0
+        };
+        let measure_columns = if let Some(measures) = &csv_settings.measures {
+            measures
+                .iter()
+                .map(|measure| MeasureColumns::new(header, first_row.len(), measure))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            MeasureColumns::all(input, header, first_row.len(), benchmark_column)?
+        };
+        if measure_columns.is_empty() {
+            return Err(ParseError::new(Self::ADAPTER).with_reason("No measure columns"));
+        }
+
+        let mut benchmark_metrics = Vec::with_capacity(rows.len());
+        for row in rows {
+            let benchmark_name = row.get(benchmark_column).unwrap_or_default();
+            let benchmark_name: BenchmarkName = benchmark_name
+                .parse()
+                .map_err(|e| field_error(input, row, benchmark_name, e))?;
+            let mut metrics = Vec::with_capacity(measure_columns.len());
+            for measure_columns in &measure_columns {
+                let metric = measure_columns.metric(row).map_err(|field| {
+                    field_error(
+                        input,
+                        row,
+                        field,
+                        format!("Failed to parse `{field}` as a number"),
+                    )
+                })?;
+                if let Some(json_metric) = metric {
+                    metrics.push((measure_columns.measure.clone(), json_metric));
+                }
+            }
+            benchmark_metrics.push((benchmark_name, metrics));
+        }
+
+        AdapterResults::new_measures(benchmark_metrics)
+            .ok_or_else(|| ParseError::new(Self::ADAPTER))
+    }
+}
+
+// The reader only tracks the line of each row, so the column is that of the offending field
+fn field_error<R>(input: &str, row: &StringRecord, field: &str, reason: R) -> ParseError
+where
+    R: fmt::Display,
+{
+    let number = row
+        .position()
+        .and_then(|position| usize::try_from(position.line()).ok())
+        .unwrap_or_default();
+    let column = number
+        .checked_sub(1)
+        .and_then(|index| input.lines().nth(index))
+        .map(|line| value_column(line, field) + 1);
+    ParseError::new(AdapterCsv::ADAPTER)
+        .with_reason(reason)
+        .with_line(input, number, column)
+}
+
+// A tab anywhere in the first row means the input is tab-separated
+fn detect_delimiter(input: &str) -> JsonCsvDelimiter {
+    let first_row = input
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with(char::from(COMMENT)));
+    if first_row.is_some_and(|line| line.contains('\t')) {
+        JsonCsvDelimiter::Tab
+    } else {
+        JsonCsvDelimiter::Comma
+    }
+}
+
+// A header is required if any column is referenced by name.
+// Otherwise, the first row is a header if it does not contain any numbers.
+fn has_header(csv_settings: &JsonCsvSettings, first_row: &StringRecord) -> bool {
+    if let Some(header) = csv_settings.header {
+        return header;
+    }
+    let mut columns = csv_settings.benchmark.iter().collect::<Vec<_>>();
+    for measure in csv_settings.measures.iter().flatten() {
+        columns.push(&measure.value);
+        columns.extend(measure.lower_value.iter());
+        columns.extend(measure.upper_value.iter());
+    }
+    columns
+        .into_iter()
+        .any(|column| column.parse::<usize>().is_err())
+        || !first_row.iter().any(|field| field.parse::<f64>().is_ok())
+}
+
+// A column is either a header name or a zero-based index
+fn column_index(
+    header: Option<&StringRecord>,
+    len: usize,
+    column: &str,
+) -> Result<usize, ParseError> {
+    header
+        .and_then(|header| header.iter().position(|name| name == column))
+        .or_else(|| column.parse().ok())
+        .filter(|index| *index < len)
+        .ok_or_else(|| {
+            ParseError::new(AdapterCsv::ADAPTER).with_reason(format!("Column not found: {column}"))
+        })
+}
+
+struct MeasureColumns {
+    measure: MeasureNameId,
+    value: usize,
+    lower_value: Option<usize>,
+    upper_value: Option<usize>,
+}
+
+impl MeasureColumns {
+    fn new(
+        header: Option<&StringRecord>,
+        len: usize,
+        measure: &JsonCsvMeasure,
+    ) -> Result<Self, ParseError> {
+        let JsonCsvMeasure {
+            measure,
+            value,
+            lower_value,
+            upper_value,
+        } = measure;
+        Ok(Self {
+            measure: measure.clone(),
+            value: column_index(header, len, value)?,
+            lower_value: if let Some(lower_value) = lower_value {
+                Some(column_index(header, len, lower_value)?)
+            } else {
+                None
+            },
+            upper_value: if let Some(upper_value) = upper_value {
+                Some(column_index(header, len, upper_value)?)
+            } else {
+                None
+            },
+        })
+    }
+
+    // With a header, every other column is a measure named after its header.
+    // Without a header, the first other column is the latency.
+    fn all(
+        input: &str,
+        header: Option<&StringRecord>,
+        len: usize,
+        benchmark_column: usize,
+    ) -> Result<Vec<Self>, ParseError> {
+        let Some(header) = header else {
+            let value = (0..len)
+                .find(|index| *index != benchmark_column)
+                .ok_or_else(|| {
+                    ParseError::new(AdapterCsv::ADAPTER).with_reason("No measure columns")
+                })?;
+            return Ok(vec![Self {
+                measure: LATENCY_NAME_ID.clone(),
+                value,
+                lower_value: None,
+                upper_value: None,
+            }]);
+        };
+        header
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != benchmark_column)
+            .map(|(value, name)| {
+                Ok(Self {
+                    measure: name
+                        .parse()
+                        .map_err(|e| field_error(input, header, name, e))?,
+                    value,
+                    lower_value: None,
+                    upper_value: None,
+                })
+            })
+            .collect()
+    }
+
+    // An empty value skips the measure for that row,
+    // but any value that is not a number is an error.
+    // Returns the offending field on failure.
+    fn metric<'r>(&self, row: &'r StringRecord) -> Result<Option<JsonNewMetric>, &'r str> {
+        let Some(value) = parse_field(row, Some(self.value))? else {
+            return Ok(None);
+        };
+        Ok(Some(JsonNewMetric {
+            value: value.into(),
+            lower_value: parse_field(row, self.lower_value)?.map(Into::into),
+            upper_value: parse_field(row, self.upper_value)?.map(Into::into),
+            samples: None,
+        }))
+    }
+}
+
+// All rows have the same number of columns, so a missing field is empty
+fn parse_field(row: &StringRecord, column: Option<usize>) -> Result<Option<f64>, &str> {
+    match column.and_then(|column| row.get(column)) {
+        None | Some("") => Ok(None),
+        Some(field) => field.parse().map(Some).map_err(|_e| field),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_csv {
+    use bencher_json::project::report::{JsonCsvDelimiter, JsonCsvMeasure, JsonCsvSettings};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            opt_convert_file_path, parse_error_file_path, validate_latency, validate_measure,
+            validate_parse_line,
+        },
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::AdapterCsv;
+
+    fn convert_csv(suffix: &str, csv_settings: Option<JsonCsvSettings>) -> AdapterResults {
+        let file_path = format!("./tool_output/csv/{suffix}");
+        let settings = Settings::new(None).with_csv(csv_settings);
+        opt_convert_file_path::<AdapterCsv>(&file_path, settings).unwrap()
+    }
+
+    fn csv_measure(measure: &str, value: &str, bounds: Option<(&str, &str)>) -> JsonCsvMeasure {
+        JsonCsvMeasure {
+            measure: measure.parse().unwrap(),
+            value: value.into(),
+            lower_value: bounds.map(|(lower_value, _)| lower_value.into()),
+            upper_value: bounds.map(|(_, upper_value)| upper_value.into()),
+        }
+    }
+
+    #[test]
+    fn test_adapter_csv_header() {
+        let results = convert_csv("header.csv", None);
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib_10").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, "latency", 224.0, None, None);
+        validate_measure(metrics, "instructions", 1_500.0, None, None);
+
+        let metrics = results.get("fib_20").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, "latency", 27_500.0, None, None);
+        validate_measure(metrics, "instructions", 180_000.0, None, None);
+
+        // An empty value skips the measure
+        let metrics = results.get("parse").unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        validate_measure(metrics, "latency", 512.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_csv_no_header() {
+        let results = convert_csv("no_header.csv", None);
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fib_10").unwrap();
+        validate_latency(metrics, 224.0, None, None);
+
+        let metrics = results.get("fib_20").unwrap();
+        validate_latency(metrics, 27_500.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_csv_mapping() {
+        let csv_settings = JsonCsvSettings {
+            benchmark: Some("name".into()),
+            measures: Some(vec![
+                csv_measure("latency", "mean_ns", Some(("min_ns", "max_ns"))),
+                csv_measure("throughput", "ops", None),
+            ]),
+            ..Default::default()
+        };
+        let results = convert_csv("mapping.csv", Some(csv_settings));
+        validate_adapter_csv_mapping(&results);
+    }
+
+    #[test]
+    fn test_adapter_csv_mapping_tsv() {
+        let csv_settings = JsonCsvSettings {
+            benchmark: Some("1".into()),
+            measures: Some(vec![
+                csv_measure("latency", "3", Some(("2", "4"))),
+                csv_measure("throughput", "5", None),
+            ]),
+            ..Default::default()
+        };
+        // The delimiter is auto-detected
+        let results = convert_csv("mapping.tsv", Some(csv_settings.clone()));
+        validate_adapter_csv_mapping(&results);
+
+        let csv_settings = JsonCsvSettings {
+            delimiter: Some(JsonCsvDelimiter::Tab),
+            ..csv_settings
+        };
+        let results = convert_csv("mapping.tsv", Some(csv_settings));
+        validate_adapter_csv_mapping(&results);
+    }
+
+    fn validate_adapter_csv_mapping(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("tests::bench_a").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, "latency", 3_247.0, Some(2_203.0), Some(4_291.0));
+        validate_measure(metrics, "throughput", 307_976.6, None, None);
+
+        let metrics = results.get("tests::bench_b").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, "latency", 3_443.0, Some(1_168.0), Some(5_718.0));
+        validate_measure(metrics, "throughput", 290_444.4, None, None);
+    }
+
+    #[test]
+    fn test_adapter_csv_invalid() {
+        let settings = Settings::new(None);
+        // Not a number
+        assert!(
+            opt_convert_file_path::<AdapterCsv>("./tool_output/csv/invalid.csv", settings)
+                .is_none()
+        );
+        // Missing column
+        let csv_settings = JsonCsvSettings {
+            measures: Some(vec![csv_measure("latency", "median", None)]),
+            ..Default::default()
+        };
+        let settings = Settings::new(None).with_csv(Some(csv_settings));
+        assert!(
+            opt_convert_file_path::<AdapterCsv>("./tool_output/csv/header.csv", settings).is_none()
+        );
+    }
+
+    #[test]
+    fn test_adapter_csv_parse_error() {
+        let err = parse_error_file_path::<AdapterCsv>("./tool_output/csv/invalid.csv");
+        validate_parse_line(&err, 2, Some(8), "fib_10,fast");
+        assert_eq!(
+            err.reason.as_deref(),
+            Some("Failed to parse `fast` as a number")
+        );
+
+        let csv_settings = JsonCsvSettings {
+            measures: Some(vec![csv_measure("latency", "median", None)]),
+            ..Default::default()
+        };
+        let contents = std::fs::read_to_string("./tool_output/csv/header.csv").unwrap();
+        let err =
+            AdapterCsv::try_parse(&contents, Settings::new(None).with_csv(Some(csv_settings)))
+                .unwrap_err();
+        assert_eq!(err.reason.as_deref(), Some("Column not found: median"));
+    }
+}
@@ -1,93 +1,161 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric, MeasureNameId,
+};
 use nom::{
-    branch::alt,
-    bytes::complete::{tag, take_till1},
-    character::complete::{anychar, space1},
+    bytes::complete::take_till1,
+    character::complete::{space0, space1},
     combinator::{eof, map, map_res},
-    multi::many_till,
+    multi::many1,
     sequence::tuple,
     IResult,
 };
+use ordered_float::OrderedFloat;
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units, NomError,
+        latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, FurthestLine, NomError, Units,
+    },
+    results::adapter_results::{
+        AdapterResults, ALLOCATED_BYTES_NAME_ID, ALLOCATIONS_NAME_ID, LATENCY_NAME_ID,
     },
-    results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterGoBench;
 
 impl Adaptable for AdapterGoBench {
+    const ADAPTER: Adapter = Adapter::GoBench;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
+            Some(JsonAverage::Median) => return Err(ParseError::new(Self::ADAPTER)),
         }
 
+        let strip_cpu = settings.go_strip_cpu.unwrap_or_default();
         let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_go(line) {
+        let mut furthest_line = FurthestLine::default();
+        for (index, line) in input.lines().enumerate() {
+            let result = parse_go(line, strip_cpu);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                 }
             }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        AdapterResults::new_measures(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
-fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
+const BYTES_PER_OP: &str = "B/op";
+const ALLOCS_PER_OP: &str = "allocs/op";
+const PER_OP: &str = "/op";
+
+#[allow(clippy::type_complexity)]
+fn parse_go(
+    input: &str,
+    strip_cpu: bool,
+) -> IResult<&str, (BenchmarkName, Vec<(MeasureNameId, JsonNewMetric)>)> {
     map_res(
         tuple((
             take_till1(|c| c == ' ' || c == '\t'),
             space1,
             parse_u64,
-            space1,
-            parse_go_bench,
-            alt((
-                map(eof, |_| ()),
-                map(
-                    tuple((space1, parse_f64, space1, many_till(anychar, eof))),
-                    |_| (),
-                ),
-            )),
+            many1(map(tuple((space1, parse_go_metric)), |(_, metric)| metric)),
+            space0,
+            eof,
         )),
-        |(name, _, _iter, _, json_metric, ())| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
+        |(name, _, _iter, metrics, _, _)| -> Result<(BenchmarkName, Vec<(MeasureNameId, JsonNewMetric)>), NomError> {
+            let name = if strip_cpu { strip_cpu_suffix(name) } else { name };
             let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
+            Ok((benchmark_name, metrics.into_iter().flatten().collect()))
         },
     )(input)
 }
 
-fn parse_go_bench(input: &str) -> IResult<&str, JsonNewMetric> {
-    map_res(
-        tuple((parse_f64, space1, parse_units, tag("/op"))),
-        |(duration, _, units, _)| -> Result<JsonNewMetric, NomError> {
-            let value = latency_as_nanos(duration, units);
-            Ok(JsonNewMetric {
-                value,
-                lower_value: None,
-                upper_value: None,
+// Go appends the `GOMAXPROCS` value used for the run to the benchmark name (ie `BenchmarkFoo-8`),
+// unless it is `1`.
+fn strip_cpu_suffix(name: &str) -> &str {
+    match name.rsplit_once('-') {
+        Some((prefix, cpu))
+            if !prefix.is_empty() && !cpu.is_empty() && cpu.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            prefix
+        },
+        _ => name,
+    }
+}
+
+// Each metric is a `value unit` pair.
+// This includes `ns/op`, the `-benchmem` `B/op` and `allocs/op`,
+// and any custom metrics reported with `testing.B.ReportMetric`.
+fn parse_go_metric(input: &str) -> IResult<&str, Option<(MeasureNameId, JsonNewMetric)>> {
+    map(
+        tuple((parse_f64, space1, take_till1(char::is_whitespace))),
+        |(value, _, unit)| {
+            go_measure(value, unit).map(|(measure, value)| {
+                (
+                    measure,
+                    JsonNewMetric {
+                        value,
+                        lower_value: None,
+                        upper_value: None,
+                        samples: None,
+                    },
+                )
             })
         },
     )(input)
 }
 
+fn go_measure(value: f64, unit: &str) -> Option<(MeasureNameId, OrderedFloat<f64>)> {
+    match unit {
+        BYTES_PER_OP => Some((ALLOCATED_BYTES_NAME_ID.clone(), value.into())),
+        ALLOCS_PER_OP => Some((ALLOCATIONS_NAME_ID.clone(), value.into())),
+        _ => {
+            if let Some(units) = unit
+                .strip_suffix(PER_OP)
+                .and_then(|units| units.parse::<Units>().ok())
+            {
+                Some((LATENCY_NAME_ID.clone(), latency_as_nanos(value, units)))
+            } else {
+                unit.parse().ok().map(|measure| (measure, value.into()))
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test_go_bench {
     use bencher_json::{project::report::JsonAverage, JsonNewMetric};
+    use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
     use crate::{
         adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
-        AdapterResults, Settings,
+        results::{adapter_metrics::AdapterMetrics, adapter_results::LATENCY_NAME_ID},
+        Adaptable, AdapterResults, ParseLine, Settings,
     };
 
-    use super::{parse_go, AdapterGoBench};
+    use super::{parse_go, strip_cpu_suffix, AdapterGoBench};
+
+    fn go_metric(value: f64) -> JsonNewMetric {
+        JsonNewMetric {
+            value: value.into(),
+            lower_value: None,
+            upper_value: None,
+            samples: None,
+        }
+    }
 
     fn convert_go_bench(suffix: &str) -> AdapterResults {
         let file_path = format!("./tool_output/go/bench/{suffix}.txt");
@@ -102,11 +170,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib10-8".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 325.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![(LATENCY_NAME_ID.clone(), go_metric(325.0))],
                     ),
                 )),
                 "BenchmarkFib10-8   		 					5000000		325 ns/op",
@@ -116,11 +180,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 40_537.123.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![(LATENCY_NAME_ID.clone(), go_metric(40_537.123))],
                     ),
                 )),
                 "BenchmarkFib20  	 	   					30000		40537.123 ns/op",
@@ -130,11 +190,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_10-8".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 325.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![(LATENCY_NAME_ID.clone(), go_metric(325.0))],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_10-8    	5000000		325 ns/op",
@@ -144,11 +200,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_20".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 40_537.123.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![(LATENCY_NAME_ID.clone(), go_metric(40_537.123))],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_20		30000		40537.123 ns/op",
@@ -158,11 +210,7 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my/tabled/benchmark_-_20".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 40_537.456.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![(LATENCY_NAME_ID.clone(), go_metric(40_537.456))],
                     ),
                 )),
                 "BenchmarkFib/my/tabled/benchmark_-_20		30001		40537.456 ns/op",
@@ -172,11 +220,10 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20WithAuxMetric-8".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 25_829.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![
+                            (LATENCY_NAME_ID.clone(), go_metric(25_829.0)),
+                            ("auxMetricUnits".parse().unwrap(), go_metric(4.0)),
+                        ],
                     ),
                 )),
                 "BenchmarkFib20WithAuxMetric-8              46714             25829 ns/op                 4.000 auxMetricUnits",
@@ -185,18 +232,90 @@ pub(crate) mod test_go_bench {
         .into_iter()
         .enumerate()
         {
-            assert_eq!(expected, parse_go(input), "#{index}: {input}");
+            assert_eq!(expected, parse_go(input, false), "#{index}: {input}");
         }
     }
 
+    #[test]
+    fn test_strip_cpu_suffix() {
+        for (expected, input) in [
+            ("BenchmarkFib10", "BenchmarkFib10-8"),
+            ("BenchmarkFib20", "BenchmarkFib20"),
+            ("BenchmarkParse/json", "BenchmarkParse/json-16"),
+            (
+                "BenchmarkFib/my_tabled_benchmark_-_10",
+                "BenchmarkFib/my_tabled_benchmark_-_10-8",
+            ),
+            (
+                "BenchmarkFib/my_tabled_benchmark_-_20",
+                "BenchmarkFib/my_tabled_benchmark_-_20",
+            ),
+            ("BenchmarkFib-", "BenchmarkFib-"),
+            ("-8", "-8"),
+        ] {
+            assert_eq!(expected, strip_cpu_suffix(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_adapter_go_bench_strip_cpu() {
+        let results = opt_convert_file_path::<AdapterGoBench>(
+            "./tool_output/go/bench/benchmem.txt",
+            Settings::default().with_go_strip_cpu(Some(true)),
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 3);
+        assert_eq!(results.get("BenchmarkFib10-16"), None);
+
+        let metrics = results.get("BenchmarkFib10").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_go_metric(metrics, "latency", 210.2);
+
+        let metrics = results.get("BenchmarkFib20").unwrap();
+        validate_go_metric(metrics, "latency", 26_264.0);
+
+        let metrics = results.get("BenchmarkParse/json").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_go_metric(metrics, "latency", 1_500_000.0);
+    }
+
+    #[test]
+    fn test_adapter_go_bench_parse_error() {
+        let input = "goos: linux\ngoarch: amd64\nBenchmarkFib10-8   five   325 ns/op\nPASS\n";
+        let err = AdapterGoBench::try_parse(input, Settings::default()).unwrap_err();
+        assert_eq!(
+            err.line,
+            Some(ParseLine {
+                number: 3,
+                column: Some(20),
+                content: "BenchmarkFib10-8   five   325 ns/op".to_owned(),
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "go_bench: No benchmark results found (line 3, column 20: `BenchmarkFib10-8   five   325 ns/op`)"
+        );
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "adapter": "go_bench",
+                "reason": null,
+                "line": {
+                    "number": 3,
+                    "column": 20,
+                    "content": "BenchmarkFib10-8   five   325 ns/op",
+                },
+                "candidates": [],
+            })
+        );
+    }
+
     #[test]
     fn test_adapter_go_bench_average() {
         let file_path = "./tool_output/go/bench/five.txt";
         let results = opt_convert_file_path::<AdapterGoBench>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_go_bench(&results);
@@ -205,9 +324,7 @@ pub(crate) mod test_go_bench {
             None,
             opt_convert_file_path::<AdapterGoBench>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
@@ -255,6 +372,41 @@ pub(crate) mod test_go_bench {
         validate_latency(metrics, 26264.0, None, None);
 
         let metrics = results.get("BenchmarkFib20WithAuxMetric-8").unwrap();
-        validate_latency(metrics, 25829.0, None, None);
+        assert_eq!(metrics.inner.len(), 2);
+        validate_go_metric(metrics, "latency", 25829.0);
+        validate_go_metric(metrics, "auxMetricUnits", 4.0);
+    }
+
+    #[test]
+    fn test_adapter_go_bench_benchmem() {
+        let results = convert_go_bench("benchmem");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("BenchmarkFib10-16").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_go_metric(metrics, "latency", 210.2);
+        validate_go_metric(metrics, "allocated-bytes", 16.0);
+        validate_go_metric(metrics, "allocations", 1.0);
+
+        let metrics = results.get("BenchmarkFib20-16").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_go_metric(metrics, "latency", 26_264.0);
+        validate_go_metric(metrics, "allocated-bytes", 1024.0);
+        validate_go_metric(metrics, "allocations", 8.0);
+
+        let metrics = results.get("BenchmarkParse/json-16").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_go_metric(metrics, "latency", 1_500_000.0);
+        validate_go_metric(metrics, "MB/s", 97.34);
+        validate_go_metric(metrics, "allocated-bytes", 512.0);
+        validate_go_metric(metrics, "allocations", 4.0);
+        validate_go_metric(metrics, "hits/op", 0.5);
+    }
+
+    fn validate_go_metric(metrics: &AdapterMetrics, key: &str, value: f64) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value));
+        assert_eq!(metric.lower_value, None);
+        assert_eq!(metric.upper_value, None);
     }
 }
@@ -1,13 +1,27 @@
 pub mod bench;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
 use bench::AdapterGoBench;
+use bencher_json::project::report::Adapter;
 
 pub struct AdapterGo;
 
 impl Adaptable for AdapterGo {
+    const ADAPTER: Adapter = Adapter::Go;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterGoBench::parse(input, settings)
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterGoBench::try_parse],
+        )
     }
 }
 
@@ -1,27 +1,45 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
+use std::collections::HashMap;
 
-use rust_decimal::Decimal;
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric, MeasureNameId,
+};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
-    adapters::util::{latency_as_nanos, throughput_as_secs},
-    results::adapter_results::{AdapterMeasure, AdapterResults},
-    Adaptable, AdapterError, Settings,
+    adapters::util::{benchmark_parameters, latency_as_nanos, throughput_as_secs, try_parse_json},
+    results::adapter_results::{
+        AdapterResults, ALLOCATED_BYTES_NAME_ID, ALLOCATION_RATE_NAME_ID, GC_COUNT_NAME_ID,
+        GC_TIME_NAME_ID, LATENCY_NAME_ID, THROUGHPUT_NAME_ID,
+    },
+    Adaptable, AdapterError, ParseError, Settings,
 };
 
 pub struct AdapterJavaJmh;
 
 impl Adaptable for AdapterJavaJmh {
+    const ADAPTER: Adapter = Adapter::JavaJmh;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
-        }
+        Self::try_parse(input, settings).ok()
+    }
 
-        serde_json::from_str::<Jmh>(input).ok()?.try_into().ok()?
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |jmh: Jmh| jmh.convert(&settings))
     }
 }
 
+// Secondary metrics from profilers are prefixed with a middle dot, ie `·gc.alloc.rate`
+const SECONDARY_METRIC_PREFIX: char = '·';
+// https://github.com/openjdk/jmh/blob/1.37/jmh-core/src/main/java/org/openjdk/jmh/profile/GCProfiler.java
+const GC_ALLOC_RATE: &str = "gc.alloc.rate";
+const GC_ALLOC_RATE_NORM: &str = "gc.alloc.rate.norm";
+const GC_COUNT: &str = "gc.count";
+const GC_TIME: &str = "gc.time";
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Jmh(pub Vec<Benchmark>);
 
@@ -29,8 +47,11 @@ pub struct Jmh(pub Vec<Benchmark>);
 #[serde(rename_all = "camelCase")]
 pub struct Benchmark {
     pub benchmark: BenchmarkName,
+    pub params: Option<HashMap<String, String>>,
     pub primary_metric: PrimaryMetric,
-    pub secondary_metrics: JsonAny,
+    // Secondary metrics may not have a valid score confidence,
+    // so they are each deserialized separately.
+    pub secondary_metrics: HashMap<String, Value>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -39,7 +60,10 @@ pub struct PrimaryMetric {
     #[serde(with = "rust_decimal::serde::float")]
     pub score: Decimal,
     pub score_confidence: ScoreConfidence,
+    pub score_percentiles: Option<ScorePercentiles>,
     pub score_unit: String,
+    // The score of every measurement iteration, grouped by fork
+    pub raw_data: Option<Vec<Vec<f64>>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -49,64 +73,174 @@ pub struct ScoreConfidence(
     #[serde(with = "rust_decimal::serde::float")] Decimal,
 );
 
-impl TryFrom<Jmh> for Option<AdapterResults> {
-    type Error = AdapterError;
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScorePercentiles {
+    #[serde(rename = "0.0", with = "rust_decimal::serde::float")]
+    pub p0: Decimal,
+    #[serde(rename = "50.0", with = "rust_decimal::serde::float")]
+    pub p50: Decimal,
+    #[serde(rename = "100.0", with = "rust_decimal::serde::float")]
+    pub p100: Decimal,
+}
 
-    fn try_from(jmh: Jmh) -> Result<Self, Self::Error> {
-        let mut benchmark_metrics = Vec::with_capacity(jmh.0.len());
-        for benchmark in jmh.0 {
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecondaryMetric {
+    pub score: f64,
+    pub score_confidence: Option<(Value, Value)>,
+    pub score_percentiles: Option<ScorePercentiles>,
+}
+
+impl Jmh {
+    fn convert(self, settings: &Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let average = settings.average.unwrap_or_default();
+        let mut benchmark_metrics = Vec::with_capacity(self.0.len());
+        for benchmark in self.0 {
             let Benchmark {
-                benchmark: benchmark_name,
+                benchmark: mut benchmark_name,
+                params,
                 primary_metric,
-                ..
+                secondary_metrics,
             } = benchmark;
-            let PrimaryMetric {
-                score,
-                score_confidence,
-                score_unit,
-            } = primary_metric;
-
-            let measure = if let Some((unit, slash_op)) = score_unit.split_once("/op") {
-                if !slash_op.is_empty() {
-                    return Err(AdapterError::BenchmarkUnits(slash_op.into()));
-                }
 
-                let time_unit = unit.parse()?;
-                let value = latency_as_nanos(score, time_unit);
-                let lower_value = latency_as_nanos(score_confidence.0, time_unit);
-                let upper_value = latency_as_nanos(score_confidence.1, time_unit);
-                let json_metric = JsonNewMetric {
-                    value,
-                    lower_value: Some(lower_value),
-                    upper_value: Some(upper_value),
+            if let Some(parameters) = params.and_then(benchmark_parameters) {
+                benchmark_name = format!("{benchmark_name}{parameters}").parse()?;
+            }
+
+            let mut metrics = vec![primary_metric.convert(average)?];
+            let mut secondary_metrics = secondary_metrics.into_iter().collect::<Vec<_>>();
+            secondary_metrics.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key, value) in secondary_metrics {
+                // Skip any secondary metrics that can not be parsed
+                let Ok(secondary_metric) = serde_json::from_value::<SecondaryMetric>(value) else {
+                    continue;
                 };
-                AdapterMeasure::Latency(json_metric)
-            } else if let Some((ops_slash, unit)) = score_unit.split_once("ops/") {
-                if !ops_slash.is_empty() {
-                    return Err(AdapterError::BenchmarkUnits(ops_slash.into()));
+                if let Some(metric) = secondary_metric.convert(&key, average) {
+                    metrics.push(metric);
                 }
+            }
+
+            benchmark_metrics.push((benchmark_name, metrics));
+        }
 
-                let time_unit = unit.parse()?;
-                let value = throughput_as_secs(score, time_unit);
-                let lower_value = throughput_as_secs(score_confidence.0, time_unit);
-                let upper_value = throughput_as_secs(score_confidence.1, time_unit);
-                let json_metric = JsonNewMetric {
-                    value,
-                    lower_value: Some(lower_value),
-                    upper_value: Some(upper_value),
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+impl PrimaryMetric {
+    fn convert(self, average: JsonAverage) -> Result<(MeasureNameId, JsonNewMetric), AdapterError> {
+        let Self {
+            score,
+            score_confidence,
+            score_percentiles,
+            score_unit,
+            raw_data,
+        } = self;
+        let raw_data = raw_data.map(|raw_data| raw_data.into_iter().flatten().collect::<Vec<_>>());
+
+        // The mean uses the score confidence interval as its bounds,
+        // and the median uses the minimum and maximum percentiles.
+        let (value, lower_value, upper_value) = match average {
+            JsonAverage::Mean => (score, score_confidence.0, score_confidence.1),
+            JsonAverage::Median => {
+                let Some(ScorePercentiles { p0, p50, p100 }) = score_percentiles else {
+                    return Err(AdapterError::ScorePercentiles);
                 };
-                AdapterMeasure::Throughput(json_metric)
-            } else {
-                return Err(AdapterError::BenchmarkUnits(score_unit));
+                (p50, p0, p100)
+            },
+        };
+
+        if let Some((unit, slash_op)) = score_unit.split_once("/op") {
+            if !slash_op.is_empty() {
+                return Err(AdapterError::BenchmarkUnits(slash_op.into()));
+            }
+
+            let time_unit = unit.parse()?;
+            let json_metric = JsonNewMetric {
+                value: latency_as_nanos(value, time_unit),
+                lower_value: Some(latency_as_nanos(lower_value, time_unit)),
+                upper_value: Some(latency_as_nanos(upper_value, time_unit)),
+                samples: raw_data.map(|raw_data| {
+                    raw_data
+                        .into_iter()
+                        .map(|datum| latency_as_nanos(datum, time_unit))
+                        .collect()
+                }),
             };
-
-            benchmark_metrics.push((benchmark_name, measure));
+            Ok((LATENCY_NAME_ID.clone(), json_metric))
+        } else if let Some((ops_slash, unit)) = score_unit.split_once("ops/") {
+            if !ops_slash.is_empty() {
+                return Err(AdapterError::BenchmarkUnits(ops_slash.into()));
+            }
+
+            let time_unit = unit.parse()?;
+            let json_metric = JsonNewMetric {
+                value: throughput_as_secs(value, time_unit),
+                lower_value: Some(throughput_as_secs(lower_value, time_unit)),
+                upper_value: Some(throughput_as_secs(upper_value, time_unit)),
+                samples: raw_data.map(|raw_data| {
+                    raw_data
+                        .into_iter()
+                        .map(|datum| throughput_as_secs(datum, time_unit))
+                        .collect()
+                }),
+            };
+            Ok((THROUGHPUT_NAME_ID.clone(), json_metric))
+        } else {
+            Err(AdapterError::BenchmarkUnits(score_unit))
         }
+    }
+}
 
-        Ok(AdapterResults::new(benchmark_metrics))
+impl SecondaryMetric {
+    fn convert(self, key: &str, average: JsonAverage) -> Option<(MeasureNameId, JsonNewMetric)> {
+        let Self {
+            score,
+            score_confidence,
+            score_percentiles,
+        } = self;
+
+        let key = key.trim_start_matches(SECONDARY_METRIC_PREFIX);
+        let measure = match key {
+            GC_ALLOC_RATE => ALLOCATION_RATE_NAME_ID.clone(),
+            GC_ALLOC_RATE_NORM => ALLOCATED_BYTES_NAME_ID.clone(),
+            GC_COUNT => GC_COUNT_NAME_ID.clone(),
+            GC_TIME => GC_TIME_NAME_ID.clone(),
+            // All other secondary metrics, such as `perfnorm` counters, are auto-created
+            _ => key.parse().ok()?,
+        };
+
+        let json_metric = match average {
+            JsonAverage::Mean => {
+                // The score confidence is `NaN` when there are too few samples
+                let (lower_value, upper_value) = score_confidence
+                    .and_then(|(lower, upper)| lower.as_f64().zip(upper.as_f64()))
+                    .unzip();
+                JsonNewMetric {
+                    value: score.into(),
+                    lower_value: lower_value.map(Into::into),
+                    upper_value: upper_value.map(Into::into),
+                    samples: None,
+                }
+            },
+            JsonAverage::Median => {
+                let ScorePercentiles { p0, p50, p100 } = score_percentiles?;
+                JsonNewMetric {
+                    value: decimal_as_f64(p50).into(),
+                    lower_value: Some(decimal_as_f64(p0).into()),
+                    upper_value: Some(decimal_as_f64(p100).into()),
+                    samples: None,
+                }
+            },
+        };
+        Some((measure, json_metric))
     }
 }
 
+fn decimal_as_f64(decimal: Decimal) -> f64 {
+    decimal.to_f64().unwrap_or_default()
+}
+
 #[cfg(test)]
 pub(crate) mod test_java_jmh {
     use bencher_json::project::report::JsonAverage;
@@ -114,7 +248,8 @@ pub(crate) mod test_java_jmh {
 
     use crate::{
         adapters::test_util::{
-            convert_file_path, opt_convert_file_path, validate_latency, validate_throughput,
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_measure, validate_throughput,
         },
         AdapterResults, Settings,
     };
@@ -163,21 +298,21 @@ pub(crate) mod test_java_jmh {
         let file_path = "./tool_output/java/jmh/six.json";
         let results = opt_convert_file_path::<AdapterJavaJmh>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_java_jmh(&results);
 
-        assert_eq!(
-            None,
-            opt_convert_file_path::<AdapterJavaJmh>(
-                file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
-            )
+        let results = convert_file_path_median::<AdapterJavaJmh>(file_path);
+        assert_eq!(results.inner.len(), 6);
+        let metrics = results
+            .get("com.github.caffeine.caffeine.cache.ComputeBenchmark.compute_sameKey(computeType: Caffeine)")
+            .unwrap();
+        validate_throughput(
+            metrics,
+            152_495_705.994_703_1,
+            Some(152_340_547.886_636_82),
+            Some(152_724_143.150_725_93),
         );
     }
 
@@ -191,7 +326,7 @@ pub(crate) mod test_java_jmh {
         assert_eq!(results.inner.len(), 6);
 
         let metrics = results
-            .get("com.github.caffeine.caffeine.cache.ComputeBenchmark.compute_sameKey")
+            .get("com.github.caffeine.caffeine.cache.ComputeBenchmark.compute_sameKey(computeType: Caffeine)")
             .unwrap();
         validate_throughput(
             metrics,
@@ -201,7 +336,7 @@ pub(crate) mod test_java_jmh {
         );
 
         let metrics = results
-            .get("com.github.guava.caffeine.cache.ComputeBenchmark.compute_sameKey")
+            .get("com.github.guava.caffeine.cache.ComputeBenchmark.compute_sameKey(computeType: Guava)")
             .unwrap();
         validate_throughput(
             metrics,
@@ -211,7 +346,7 @@ pub(crate) mod test_java_jmh {
         );
 
         let metrics = results
-            .get("com.github.hashmap.caffeine.cache.ComputeBenchmark.compute_sameKey")
+            .get("com.github.hashmap.caffeine.cache.ComputeBenchmark.compute_sameKey(computeType: ConcurrentHashMap)")
             .unwrap();
         validate_throughput(
             metrics,
@@ -221,7 +356,7 @@ pub(crate) mod test_java_jmh {
         );
 
         let metrics = results
-            .get("com.github.caffeine.caffeine.cache.ComputeBenchmark.compute_spread")
+            .get("com.github.caffeine.caffeine.cache.ComputeBenchmark.compute_spread(computeType: Caffeine)")
             .unwrap();
         validate_throughput(
             metrics,
@@ -231,7 +366,7 @@ pub(crate) mod test_java_jmh {
         );
 
         let metrics = results
-            .get("com.github.guava.caffeine.cache.ComputeBenchmark.compute_spread")
+            .get("com.github.guava.caffeine.cache.ComputeBenchmark.compute_spread(computeType: Guava)")
             .unwrap();
         validate_throughput(
             metrics,
@@ -241,7 +376,7 @@ pub(crate) mod test_java_jmh {
         );
 
         let metrics = results
-            .get("com.github.hashmap.caffeine.cache.ComputeBenchmark.compute_spread")
+            .get("com.github.hashmap.caffeine.cache.ComputeBenchmark.compute_spread(computeType: ConcurrentHashMap)")
             .unwrap();
         validate_throughput(
             metrics,
@@ -250,4 +385,61 @@ pub(crate) mod test_java_jmh {
             Some(122_105_511.371_739_3),
         );
     }
+
+    #[test]
+    fn test_adapter_java_jmh_gc() {
+        let results = convert_java_jmh("gc");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results
+            .get("org.example.ListBenchmark.add(size: 10, type: ArrayList)")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 6);
+        validate_measure(metrics, "latency", 20.0, Some(19.0), Some(21.0));
+        validate_measure(
+            metrics,
+            "allocation-rate",
+            1_500.0,
+            Some(1_490.0),
+            Some(1_510.0),
+        );
+        validate_measure(metrics, "allocated-bytes", 56.0, Some(56.0), Some(56.0));
+        validate_measure(metrics, "gc-count", 12.0, None, None);
+        validate_measure(metrics, "gc-time", 8.0, None, None);
+        validate_measure(metrics, "instructions", 120.0, Some(118.0), Some(122.0));
+
+        let metrics = results
+            .get("org.example.ListBenchmark.add(size: 1000, type: LinkedList)")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 6);
+        validate_measure(metrics, "latency", 400.0, Some(375.0), Some(425.0));
+        validate_measure(
+            metrics,
+            "allocated-bytes",
+            48_016.0,
+            Some(48_016.0),
+            Some(48_016.0),
+        );
+    }
+
+    #[test]
+    fn test_adapter_java_jmh_gc_median() {
+        let results = convert_file_path_median::<AdapterJavaJmh>("./tool_output/java/jmh/gc.json");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results
+            .get("org.example.ListBenchmark.add(size: 10, type: ArrayList)")
+            .unwrap();
+        // Secondary metrics without percentiles are skipped
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, "latency", 20.5, Some(19.0), Some(21.0));
+        validate_measure(
+            metrics,
+            "allocation-rate",
+            1_500.0,
+            Some(1_490.0),
+            Some(1_510.0),
+        );
+        validate_measure(metrics, "allocated-bytes", 56.0, Some(56.0), Some(56.0));
+    }
 }
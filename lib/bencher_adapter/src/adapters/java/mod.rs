@@ -1,13 +1,27 @@
 pub mod jmh;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
+use bencher_json::project::report::Adapter;
 use jmh::AdapterJavaJmh;
 
 pub struct AdapterJava;
 
 impl Adaptable for AdapterJava {
+    const ADAPTER: Adapter = Adapter::Java;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJavaJmh::parse(input, settings)
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterJavaJmh::try_parse],
+        )
     }
 }
 
@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 
 use nom::{
     bytes::complete::tag,
@@ -11,26 +14,35 @@ use nom::{
 
 use crate::{
     adapters::util::{
-        nom_error, parse_benchmark_name_chars, parse_f64, parse_u64, throughput_as_secs, NomError,
-        Units,
+        nom_error, parse_benchmark_name_chars, parse_f64, parse_u64, throughput_as_secs,
+        FurthestLine, NomError, Units,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterJsBenchmark;
 
 impl Adaptable for AdapterJsBenchmark {
+    const ADAPTER: Adapter = Adapter::JsBenchmark;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             Some(JsonAverage::Median) | None => {},
-            Some(JsonAverage::Mean) => return None,
+            Some(JsonAverage::Mean) => return Err(ParseError::new(Self::ADAPTER)),
         }
 
         let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_benchmark(line) {
+        let mut furthest_line = FurthestLine::default();
+        for (index, line) in input.lines().enumerate() {
+            let result = parse_benchmark(line);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                 }
@@ -38,6 +50,7 @@ impl Adaptable for AdapterJsBenchmark {
         }
 
         AdapterResults::new_throughput(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
@@ -79,6 +92,7 @@ fn parse_benchmark_time(input: &str) -> IResult<&str, JsonNewMetric> {
                 value,
                 lower_value: Some(value - error),
                 upper_value: Some(value + error),
+                samples: None,
             }
         },
     )(input)
@@ -108,17 +122,13 @@ pub(crate) mod test_js_benchmark {
             None,
             opt_convert_file_path::<AdapterJsBenchmark>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
         let results = opt_convert_file_path::<AdapterJsBenchmark>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Median),
-            },
+            Settings::new(Some(JsonAverage::Median)),
         )
         .unwrap();
         validate_adapter_js_benchmark(&results);
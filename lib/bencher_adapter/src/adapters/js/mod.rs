@@ -1,15 +1,29 @@
 pub mod benchmark;
 pub mod time;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
+use bencher_json::project::report::Adapter;
 use benchmark::AdapterJsBenchmark;
 use time::AdapterJsTime;
 
 pub struct AdapterJs;
 
 impl Adaptable for AdapterJs {
+    const ADAPTER: Adapter = Adapter::Js;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJsBenchmark::parse(input, settings).or_else(|| AdapterJsTime::parse(input, settings))
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterJsBenchmark::try_parse, AdapterJsTime::try_parse],
+        )
     }
 }
 
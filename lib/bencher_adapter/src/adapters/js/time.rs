@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 
 use nom::{
     bytes::complete::tag,
@@ -11,24 +14,34 @@ use nom::{
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, nom_error, parse_benchmark_name_chars, parse_u64, parse_units, NomError,
+        latency_as_nanos, nom_error, parse_benchmark_name_chars, parse_u64, parse_units,
+        FurthestLine, NomError,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterJsTime;
 
 impl Adaptable for AdapterJsTime {
+    const ADAPTER: Adapter = Adapter::JsTime;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         if let Some(JsonAverage::Mean | JsonAverage::Median) = settings.average {
-            return None;
+            return Err(ParseError::new(Self::ADAPTER));
         }
 
         let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_time(line) {
+        let mut furthest_line = FurthestLine::default();
+        for (index, line) in input.lines().enumerate() {
+            let result = parse_time(line);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                 }
@@ -36,6 +49,7 @@ impl Adaptable for AdapterJsTime {
         }
 
         AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
@@ -74,6 +88,7 @@ fn parse_time_time(input: &str) -> IResult<&str, JsonNewMetric> {
                 value,
                 lower_value: None,
                 upper_value: None,
+                samples: None,
             }
         },
     )(input)
@@ -103,9 +118,7 @@ pub(crate) mod test_js_time {
             None,
             opt_convert_file_path::<AdapterJsTime>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
@@ -113,9 +126,7 @@ pub(crate) mod test_js_time {
             None,
             opt_convert_file_path::<AdapterJsTime>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
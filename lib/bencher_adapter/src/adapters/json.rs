@@ -1,10 +1,23 @@
-use crate::{results::adapter_results::AdapterResults, Adaptable, Settings};
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    adapters::util::try_parse_json, results::adapter_results::AdapterResults, Adaptable,
+    ParseError, Settings,
+};
 
 pub struct AdapterJson;
 
 impl Adaptable for AdapterJson {
-    fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
-        serde_json::from_str(input).ok()
+    const ADAPTER: Adapter = Adapter::Json;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |results: AdapterResults| {
+            Ok(Some(results))
+        })
     }
 }
 
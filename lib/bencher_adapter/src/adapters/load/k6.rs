@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bencher_json::{project::report::Adapter, BenchmarkName};
+use serde::Deserialize;
+
+use crate::{
+    adapters::{
+        load::LoadSummary,
+        util::{latency_as_nanos, try_parse_json, Units},
+    },
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterError, ParseError, Settings,
+};
+
+pub struct AdapterLoadK6;
+
+impl Adaptable for AdapterLoadK6 {
+    const ADAPTER: Adapter = Adapter::LoadK6;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |k6: K6| k6.convert())
+    }
+}
+
+// Metrics without a scenario tag are for the `default` scenario
+pub const K6_DEFAULT_SCENARIO: &str = "default";
+const SCENARIO_TAG: &str = "scenario";
+
+const HTTP_REQ_DURATION: &str = "http_req_duration";
+const HTTP_REQS: &str = "http_reqs";
+const HTTP_REQ_FAILED: &str = "http_req_failed";
+
+// https://grafana.com/docs/k6/latest/results-output/end-of-test/
+// Both the `--summary-export` and the `handleSummary` JSON formats are supported.
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6 {
+    pub metrics: HashMap<String, K6Metric>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct K6Metric {
+    // `handleSummary` nests the values
+    pub values: Option<K6Values>,
+    #[serde(flatten)]
+    pub summary_export: K6Values,
+}
+
+// All trend values are in milliseconds
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct K6Values {
+    pub med: Option<f64>,
+    #[serde(rename = "p(90)")]
+    pub p90: Option<f64>,
+    #[serde(rename = "p(99)")]
+    pub p99: Option<f64>,
+    // The per second rate of a counter,
+    // or the fraction of non-zero values for a rate with `handleSummary`
+    pub rate: Option<f64>,
+    // The fraction of non-zero values for a rate with `--summary-export`
+    pub value: Option<f64>,
+}
+
+impl K6 {
+    fn convert(self) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut scenarios: BTreeMap<String, LoadSummary> = BTreeMap::new();
+        for (key, metric) in self.metrics {
+            let Some((name, scenario)) = parse_metric_key(&key) else {
+                continue;
+            };
+            let values = metric.values.unwrap_or(metric.summary_export);
+            let summary = scenarios.entry(scenario.to_owned()).or_default();
+            match name {
+                HTTP_REQ_DURATION => {
+                    let as_nanos = |time: f64| latency_as_nanos(time, Units::Milli).into();
+                    summary.p50 = values.med.map(as_nanos);
+                    summary.p90 = values.p90.map(as_nanos);
+                    summary.p99 = values.p99.map(as_nanos);
+                },
+                HTTP_REQS => summary.throughput = values.rate,
+                HTTP_REQ_FAILED => {
+                    summary.error_rate = values.value.or(values.rate).map(|rate| rate * 100.0);
+                },
+                _ => {},
+            }
+        }
+
+        let mut benchmark_metrics = Vec::with_capacity(scenarios.len());
+        for (scenario, summary) in scenarios {
+            let metrics = summary.into_metrics();
+            if metrics.is_empty() {
+                continue;
+            }
+            let benchmark_name: BenchmarkName = scenario.parse()?;
+            benchmark_metrics.push((benchmark_name, metrics));
+        }
+
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+// `http_req_duration` or `http_req_duration{scenario:browse}`
+// Sub-metrics for any other tags are skipped.
+fn parse_metric_key(key: &str) -> Option<(&str, &str)> {
+    let Some((name, tags)) = key.split_once('{') else {
+        return Some((key, K6_DEFAULT_SCENARIO));
+    };
+    let (tag, scenario) = tags.strip_suffix('}')?.split_once(':')?;
+    (tag == SCENARIO_TAG && !scenario.is_empty()).then_some((name, scenario))
+}
+
+#[cfg(test)]
+pub(crate) mod test_load_k6 {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::{load::test_load::validate_load_summary, test_util::convert_file_path},
+        AdapterResults,
+    };
+
+    use super::{AdapterLoadK6, K6_DEFAULT_SCENARIO};
+
+    fn convert_load_k6(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/load/k6/{suffix}.json");
+        convert_file_path::<AdapterLoadK6>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_load_k6_summary() {
+        let results = convert_load_k6("summary");
+        validate_adapter_load_k6(&results);
+    }
+
+    pub fn validate_adapter_load_k6(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get(K6_DEFAULT_SCENARIO).unwrap();
+        validate_load_summary(
+            metrics,
+            [25_500_000.0, 60_250_000.0, 125_000_000.0],
+            95.5,
+            2.0,
+        );
+
+        let metrics = results.get("checkout").unwrap();
+        validate_load_summary(
+            metrics,
+            [40_000_000.0, 80_500_000.0, 150_000_000.0],
+            12.25,
+            5.0,
+        );
+    }
+
+    #[test]
+    fn test_adapter_load_k6_handle_summary() {
+        let results = convert_load_k6("handle_summary");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get(K6_DEFAULT_SCENARIO).unwrap();
+        validate_load_summary(
+            metrics,
+            [25_500_000.0, 60_250_000.0, 125_000_000.0],
+            95.5,
+            2.0,
+        );
+    }
+}
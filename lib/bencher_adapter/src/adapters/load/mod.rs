@@ -0,0 +1,128 @@
+pub mod k6;
+pub mod vegeta;
+pub mod wrk;
+
+use bencher_json::{project::report::Adapter, JsonNewMetric, MeasureNameId};
+use k6::AdapterLoadK6;
+use vegeta::AdapterLoadVegeta;
+use wrk::AdapterLoadWrk;
+
+use crate::{
+    adapters::util::try_parse_candidates,
+    results::adapter_results::{
+        ERROR_RATE_NAME_ID, LATENCY_P50_NAME_ID, LATENCY_P90_NAME_ID, LATENCY_P99_NAME_ID,
+        THROUGHPUT_NAME_ID,
+    },
+    Adaptable, AdapterResults, ParseError, Settings,
+};
+
+pub struct AdapterLoad;
+
+impl Adaptable for AdapterLoad {
+    const ADAPTER: Adapter = Adapter::Load;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[
+                AdapterLoadK6::try_parse,
+                AdapterLoadWrk::try_parse,
+                AdapterLoadVegeta::try_parse,
+            ],
+        )
+    }
+}
+
+/// The summary of a load test scenario.
+/// Latencies are in nanoseconds, throughput is in requests per second,
+/// and the error rate is the percentage of failed requests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoadSummary {
+    pub p50: Option<f64>,
+    pub p90: Option<f64>,
+    pub p99: Option<f64>,
+    pub throughput: Option<f64>,
+    pub error_rate: Option<f64>,
+}
+
+impl LoadSummary {
+    pub fn into_metrics(self) -> Vec<(MeasureNameId, JsonNewMetric)> {
+        let Self {
+            p50,
+            p90,
+            p99,
+            throughput,
+            error_rate,
+        } = self;
+        [
+            (&*LATENCY_P50_NAME_ID, p50),
+            (&*LATENCY_P90_NAME_ID, p90),
+            (&*LATENCY_P99_NAME_ID, p99),
+            (&*THROUGHPUT_NAME_ID, throughput),
+            (&*ERROR_RATE_NAME_ID, error_rate),
+        ]
+        .into_iter()
+        .filter_map(|(measure, value)| {
+            let json_metric = JsonNewMetric {
+                value: value?.into(),
+                lower_value: None,
+                upper_value: None,
+                samples: None,
+            };
+            Some((measure.clone(), json_metric))
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_load {
+    use super::AdapterLoad;
+    use crate::{
+        adapters::{
+            load::{k6::test_load_k6, vegeta::test_load_vegeta, wrk::test_load_wrk},
+            test_util::{convert_file_path, validate_measure},
+        },
+        results::adapter_metrics::AdapterMetrics,
+    };
+
+    // The latencies are in nanoseconds
+    pub fn validate_load_summary(
+        metrics: &AdapterMetrics,
+        latencies: [f64; 3],
+        throughput: f64,
+        error_rate: f64,
+    ) {
+        assert_eq!(metrics.inner.len(), 5);
+        let [p50, p90, p99] = latencies;
+        validate_measure(metrics, "latency-p50", p50, None, None);
+        validate_measure(metrics, "latency-p90", p90, None, None);
+        validate_measure(metrics, "latency-p99", p99, None, None);
+        validate_measure(metrics, "throughput", throughput, None, None);
+        validate_measure(metrics, "error-rate", error_rate, None, None);
+    }
+
+    #[test]
+    fn test_adapter_load_k6() {
+        let results = convert_file_path::<AdapterLoad>("./tool_output/load/k6/summary.json");
+        test_load_k6::validate_adapter_load_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_load_wrk() {
+        let results = convert_file_path::<AdapterLoad>("./tool_output/load/wrk/latency.txt");
+        test_load_wrk::validate_adapter_load_wrk(&results);
+    }
+
+    #[test]
+    fn test_adapter_load_vegeta() {
+        let results = convert_file_path::<AdapterLoad>("./tool_output/load/vegeta/report.json");
+        test_load_vegeta::validate_adapter_load_vegeta(&results);
+    }
+}
@@ -0,0 +1,99 @@
+use bencher_json::{project::report::Adapter, BenchmarkName};
+use serde::Deserialize;
+
+use crate::{
+    adapters::{load::LoadSummary, util::try_parse_json},
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterError, ParseError, Settings,
+};
+
+pub struct AdapterLoadVegeta;
+
+impl Adaptable for AdapterLoadVegeta {
+    const ADAPTER: Adapter = Adapter::LoadVegeta;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |vegeta: Vegeta| vegeta.convert())
+    }
+}
+
+// A `vegeta report` is for a single attack, which does not have a name
+pub const VEGETA_BENCHMARK_NAME: &str = "vegeta attack";
+
+// https://github.com/tsenart/vegeta#json
+// `vegeta report -type=json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Vegeta {
+    pub latencies: VegetaLatencies,
+    pub throughput: f64,
+    pub success: f64,
+}
+
+// All latencies are in nanoseconds
+#[derive(Debug, Clone, Deserialize)]
+pub struct VegetaLatencies {
+    #[serde(rename = "50th")]
+    pub p50: f64,
+    #[serde(rename = "90th")]
+    pub p90: f64,
+    #[serde(rename = "99th")]
+    pub p99: f64,
+}
+
+impl Vegeta {
+    fn convert(self) -> Result<Option<AdapterResults>, AdapterError> {
+        let Self {
+            latencies: VegetaLatencies { p50, p90, p99 },
+            throughput,
+            success,
+        } = self;
+        // The success ratio is the fraction of 2xx and 3xx responses
+        let summary = LoadSummary {
+            p50: Some(p50),
+            p90: Some(p90),
+            p99: Some(p99),
+            throughput: Some(throughput),
+            error_rate: Some((1.0 - success) * 100.0),
+        };
+        let benchmark_name: BenchmarkName = VEGETA_BENCHMARK_NAME.parse()?;
+        Ok(AdapterResults::new_measures(vec![(
+            benchmark_name,
+            summary.into_metrics(),
+        )]))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_load_vegeta {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::{load::test_load::validate_load_summary, test_util::convert_file_path},
+        AdapterResults,
+    };
+
+    use super::{AdapterLoadVegeta, VEGETA_BENCHMARK_NAME};
+
+    #[test]
+    fn test_adapter_load_vegeta_report() {
+        let results =
+            convert_file_path::<AdapterLoadVegeta>("./tool_output/load/vegeta/report.json");
+        validate_adapter_load_vegeta(&results);
+    }
+
+    pub fn validate_adapter_load_vegeta(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get(VEGETA_BENCHMARK_NAME).unwrap();
+        validate_load_summary(
+            metrics,
+            [1_246_500.0, 3_512_250.0, 12_500_000.0],
+            99.5,
+            25.0,
+        );
+    }
+}
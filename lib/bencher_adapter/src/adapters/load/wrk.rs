@@ -0,0 +1,192 @@
+use bencher_json::{project::report::Adapter, BenchmarkName};
+
+use crate::{
+    adapters::{
+        load::LoadSummary,
+        util::{latency_as_nanos, parse_benchmark_name, value_column, FurthestLine, Units},
+    },
+    results::adapter_results::AdapterResults,
+    Adaptable, ParseError, Settings,
+};
+
+pub struct AdapterLoadWrk;
+
+impl Adaptable for AdapterLoadWrk {
+    const ADAPTER: Adapter = Adapter::LoadWrk;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        let mut benchmark_metrics = Vec::new();
+        let mut furthest_line = FurthestLine::default();
+        let mut wrk_run: Option<(BenchmarkName, WrkRun)> = None;
+        for (index, raw_line) in input.lines().enumerate() {
+            let line = raw_line.trim();
+            // `Running 30s test @ http://127.0.0.1:8080/index.html`
+            if let Some((_, url)) = line
+                .strip_prefix("Running ")
+                .and_then(|line| line.split_once(" test @ "))
+            {
+                if let Some((benchmark_name, wrk_run)) = wrk_run.take() {
+                    benchmark_metrics.push((benchmark_name, wrk_run.into_summary().into_metrics()));
+                }
+                let benchmark_name = parse_benchmark_name(url.trim()).map_err(|e| {
+                    ParseError::new(Self::ADAPTER).with_reason(e).with_line(
+                        input,
+                        index + 1,
+                        Some(value_column(raw_line, url.trim()) + 1),
+                    )
+                })?;
+                wrk_run = Some((benchmark_name, WrkRun::default()));
+                continue;
+            }
+            let Some((_, wrk_run)) = wrk_run.as_mut() else {
+                continue;
+            };
+            if let Err(value) = wrk_run.parse_line(line) {
+                furthest_line.track_column(index, value_column(raw_line, value));
+            }
+        }
+        if let Some((benchmark_name, wrk_run)) = wrk_run {
+            benchmark_metrics.push((benchmark_name, wrk_run.into_summary().into_metrics()));
+        }
+        benchmark_metrics.retain(|(_, metrics)| !metrics.is_empty());
+
+        AdapterResults::new_measures(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
+    }
+}
+
+// https://github.com/wg/wrk
+#[derive(Debug, Clone, Copy, Default)]
+struct WrkRun {
+    summary: LoadSummary,
+    requests: Option<f64>,
+    errors: f64,
+}
+
+impl WrkRun {
+    // Returns the value that could not be parsed, if any
+    fn parse_line<'l>(&mut self, line: &'l str) -> Result<(), &'l str> {
+        // The latency distribution is only printed with `--latency`
+        // `50%  250.00us`
+        if let Some((percentile, latency)) = line.split_once("%  ") {
+            let latency = latency.trim();
+            let value = Some(parse_wrk_latency(latency).ok_or(latency)?);
+            match percentile.trim() {
+                "50" => self.summary.p50 = value,
+                "90" => self.summary.p90 = value,
+                "99" => self.summary.p99 = value,
+                _ => {},
+            }
+        // `22464657 requests in 30.00s, 17.76GB read`
+        } else if let Some((requests, _)) = line.split_once(" requests in ") {
+            let requests = requests.trim();
+            self.requests = Some(requests.parse().map_err(|_e| requests)?);
+        // `Socket errors: connect 0, read 0, write 0, timeout 15`
+        } else if let Some(socket_errors) = line.strip_prefix("Socket errors:") {
+            self.errors += socket_errors
+                .split(',')
+                .filter_map(|error| error.split_whitespace().last()?.parse::<f64>().ok())
+                .sum::<f64>();
+        // `Non-2xx or 3xx responses: 1234`
+        } else if let Some(responses) = line.strip_prefix("Non-2xx or 3xx responses:") {
+            self.errors += responses.trim().parse::<f64>().unwrap_or_default();
+        // `Requests/sec: 748868.53`
+        } else if let Some(requests_per_sec) = line.strip_prefix("Requests/sec:") {
+            let requests_per_sec = requests_per_sec.trim();
+            self.summary.throughput =
+                Some(requests_per_sec.parse().map_err(|_e| requests_per_sec)?);
+        }
+        Ok(())
+    }
+
+    // Both socket errors and non-2xx or 3xx responses are counted as errors
+    fn into_summary(self) -> LoadSummary {
+        let Self {
+            mut summary,
+            requests,
+            errors,
+        } = self;
+        summary.error_rate = requests
+            .filter(|requests| *requests > 0.0)
+            .map(|requests| errors / requests * 100.0);
+        summary
+    }
+}
+
+// `250.00us`, `5.80ms`, `1.02s`, or `1.50m`
+fn parse_wrk_latency(latency: &str) -> Option<f64> {
+    let (latency, units) = if let Some(latency) = latency.strip_suffix("us") {
+        (latency, Units::Micro)
+    } else if let Some(latency) = latency.strip_suffix("ms") {
+        (latency, Units::Milli)
+    } else if let Some(latency) = latency.strip_suffix('s') {
+        (latency, Units::Sec)
+    } else if let Some(latency) = latency.strip_suffix('m') {
+        return Some(latency_as_nanos(latency.parse::<f64>().ok()? * 60.0, Units::Sec).into());
+    } else {
+        return None;
+    };
+    Some(latency_as_nanos(latency.parse::<f64>().ok()?, units).into())
+}
+
+#[cfg(test)]
+pub(crate) mod test_load_wrk {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::{
+            load::test_load::validate_load_summary,
+            test_util::{
+                convert_file_path, parse_error_file_path, validate_measure, validate_parse_line,
+            },
+        },
+        AdapterResults,
+    };
+
+    use super::AdapterLoadWrk;
+
+    fn convert_load_wrk(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/load/wrk/{suffix}.txt");
+        convert_file_path::<AdapterLoadWrk>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_load_wrk_latency() {
+        let results = convert_load_wrk("latency");
+        validate_adapter_load_wrk(&results);
+    }
+
+    pub fn validate_adapter_load_wrk(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("http://127.0.0.1:8080/index.html").unwrap();
+        validate_load_summary(
+            metrics,
+            [250_000.0, 700_000.0, 5_800_000.0],
+            748_868.53,
+            0.05,
+        );
+    }
+
+    #[test]
+    fn test_adapter_load_wrk_no_latency() {
+        let results = convert_load_wrk("no_latency");
+        assert_eq!(results.inner.len(), 1);
+
+        // Without `--latency` there is no latency distribution
+        let metrics = results.get("http://localhost:3000/api").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, "throughput", 3_200.5, None, None);
+        validate_measure(metrics, "error-rate", 0.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_load_wrk_parse_error() {
+        let err = parse_error_file_path::<AdapterLoadWrk>("./tool_output/load/wrk/invalid.txt");
+        validate_parse_line(&err, 6, Some(17), "Requests/sec:   32O0.50");
+    }
+}
@@ -1,28 +1,47 @@
+use bencher_json::project::report::Adapter;
+
 use crate::{
-    results::adapter_results::AdapterResults, Adaptable, AdapterCSharp, AdapterCpp, AdapterGo,
-    AdapterJava, AdapterJs, AdapterJson, AdapterPython, AdapterRuby, AdapterRust, AdapterShell,
-    Settings,
+    adapters::util::try_parse_candidates, results::adapter_results::AdapterResults, Adaptable,
+    AdapterCSharp, AdapterCpp, AdapterGo, AdapterJava, AdapterJs, AdapterJson, AdapterLoad,
+    AdapterPython, AdapterRuby, AdapterRust, AdapterShell, AdapterTest, ParseError, Settings,
 };
 
 pub struct AdapterMagic;
 
 impl Adaptable for AdapterMagic {
+    const ADAPTER: Adapter = Adapter::Magic;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterJson::parse(input, settings)
-            .or_else(|| AdapterCSharp::parse(input, settings))
-            .or_else(|| AdapterCpp::parse(input, settings))
-            .or_else(|| AdapterGo::parse(input, settings))
-            .or_else(|| AdapterJava::parse(input, settings))
-            .or_else(|| AdapterJs::parse(input, settings))
-            .or_else(|| AdapterPython::parse(input, settings))
-            .or_else(|| AdapterRuby::parse(input, settings))
-            .or_else(|| AdapterRust::parse(input, settings))
-            .or_else(|| AdapterShell::parse(input, settings))
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[
+                AdapterJson::try_parse,
+                AdapterCSharp::try_parse,
+                AdapterCpp::try_parse,
+                AdapterGo::try_parse,
+                AdapterJava::try_parse,
+                AdapterJs::try_parse,
+                AdapterPython::try_parse,
+                AdapterRuby::try_parse,
+                AdapterRust::try_parse,
+                AdapterShell::try_parse,
+                AdapterTest::try_parse,
+                AdapterLoad::try_parse,
+            ],
+        )
     }
 }
 
 #[cfg(test)]
 mod test_magic {
+    use pretty_assertions::assert_eq;
+
     use super::AdapterMagic;
     use crate::adapters::{
         c_sharp::{dot_net::test_c_sharp_dot_net, AdapterCSharp},
@@ -31,15 +50,18 @@ mod test_magic {
         java::jmh::test_java_jmh,
         js::{benchmark::test_js_benchmark, time::test_js_time},
         json::test_json,
+        load::{k6::test_load_k6, vegeta::test_load_vegeta, wrk::test_load_wrk},
         python::{asv::test_python_asv, pytest::test_python_pytest},
         ruby::benchmark::test_ruby_benchmark,
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
-            iai_callgrind::test_rust_iai_callgrind,
+            bench::test_rust_bench, criterion::test_rust_criterion, divan::test_rust_divan,
+            iai::test_rust_iai, iai_callgrind::test_rust_iai_callgrind,
         },
-        shell::hyperfine::test_shell_hyperfine,
+        shell::{hyperfine::test_shell_hyperfine, perf_stat::test_shell_perf_stat},
+        test::{junit::test_test_junit, tap::test_test_tap},
         test_util::convert_file_path,
     };
+    use crate::{Adaptable, ParseLine, Settings};
 
     #[test]
     fn test_adapter_magic_json_latency() {
@@ -65,12 +87,25 @@ mod test_magic {
         test_cpp_google::validate_adapter_cpp_google(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_cpp_google_repetitions() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/cpp/google/repetitions.json");
+        test_cpp_google::validate_adapter_cpp_google_repetitions(&results);
+    }
+
     #[test]
     fn test_adapter_magic_cpp_catch2() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/cpp/catch2/four.txt");
         test_cpp_catch2::validate_adapter_cpp_catch2(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_cpp_catch2_xml() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/cpp/catch2/four.xml");
+        test_cpp_catch2::validate_adapter_cpp_catch2_xml(&results);
+    }
+
     #[test]
     fn test_adapter_magic_go_bench() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/go/bench/five.txt");
@@ -137,9 +172,115 @@ mod test_magic {
         test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_rust_iai_callgrind_summary() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/rust/iai_callgrind/summary.json");
+        test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind_summary(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_rust_divan() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/rust/divan/tree.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
+
     #[test]
     fn test_adapter_magic_shell_hyperfine() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn test_adapter_magic_shell_perf_stat() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/perf_stat/repeat.txt");
+        test_shell_perf_stat::validate_adapter_shell_perf_stat(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_test_junit() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/test/junit/pytest.xml");
+        test_test_junit::validate_adapter_test_junit(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_test_tap() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/test/tap/node.txt");
+        test_test_tap::validate_adapter_test_tap(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_load_k6() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/load/k6/summary.json");
+        test_load_k6::validate_adapter_load_k6(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_load_wrk() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/load/wrk/latency.txt");
+        test_load_wrk::validate_adapter_load_wrk(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_load_vegeta() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/load/vegeta/report.json");
+        test_load_vegeta::validate_adapter_load_vegeta(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_parse_error() {
+        let input = "{\n  \"benchmarks\": [\n    oops\n  ]\n}\n";
+        let err = AdapterMagic::try_parse(input, Settings::default()).unwrap_err();
+        assert_eq!(err.adapter.to_string(), "magic");
+        assert_eq!(err.reason, None);
+
+        // Every leaf adapter is listed, in the order it was attempted
+        let candidates = err
+            .candidates
+            .iter()
+            .map(|candidate| candidate.adapter.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            candidates,
+            [
+                "json",
+                "c_sharp_dot_net",
+                "cpp_catch2",
+                "cpp_google",
+                "go_bench",
+                "java_jmh",
+                "js_benchmark",
+                "js_time",
+                "python_asv",
+                "python_pytest",
+                "ruby_benchmark",
+                "rust_bench",
+                "rust_criterion",
+                "rust_iai",
+                "rust_iai_callgrind",
+                "rust_divan",
+                "shell_hyperfine",
+                "shell_perf_stat",
+                "test_junit",
+                "test_tap",
+                "load_k6",
+                "load_wrk",
+                "load_vegeta",
+            ]
+        );
+
+        let json = err.candidates.first().unwrap();
+        assert_eq!(json.reason.as_deref(), Some("expected value"));
+        assert_eq!(
+            json.line,
+            Some(ParseLine {
+                number: 3,
+                column: Some(5),
+                content: "    oops".to_owned(),
+            })
+        );
+        assert!(err
+            .to_string()
+            .contains("\n  - json: expected value (line 3, column 5: `oops`)"));
+    }
 }
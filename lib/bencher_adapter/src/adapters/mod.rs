@@ -2,15 +2,19 @@ use nom::IResult;
 
 pub mod c_sharp;
 pub mod cpp;
+pub mod csv;
 pub mod go;
 pub mod java;
 pub mod js;
 pub mod json;
+pub mod load;
 pub mod magic;
+pub mod open_metrics;
 pub mod python;
 pub mod ruby;
 pub mod rust;
 pub mod shell;
+pub mod test;
 mod util;
 
 #[allow(dead_code, clippy::print_stdout, clippy::unnecessary_wraps)]
@@ -33,7 +37,7 @@ pub(crate) mod test_util {
 
     use crate::{
         results::{adapter_metrics::AdapterMetrics, adapter_results::AdapterResults},
-        Adaptable, Settings,
+        Adaptable, ParseError, Settings,
     };
 
     pub fn convert_file_path<A>(file_path: &str) -> AdapterResults
@@ -48,9 +52,7 @@ pub(crate) mod test_util {
     where
         A: Adaptable,
     {
-        let settings = Settings {
-            average: Some(JsonAverage::Median),
-        };
+        let settings = Settings::new(Some(JsonAverage::Median));
         opt_convert_file_path::<A>(file_path, settings)
             .unwrap_or_else(|| panic!("Failed to convert contents of {file_path}"))
     }
@@ -64,6 +66,33 @@ pub(crate) mod test_util {
         A::parse(&contents, settings)
     }
 
+    pub fn parse_error_file_path<A>(file_path: &str) -> ParseError
+    where
+        A: Adaptable,
+    {
+        let contents = std::fs::read_to_string(file_path)
+            .unwrap_or_else(|e| panic!("Failed to read test file {file_path}: {e}"));
+        A::try_parse(&contents, Settings::default())
+            .err()
+            .unwrap_or_else(|| panic!("Unexpectedly converted contents of {file_path}"))
+    }
+
+    pub fn validate_parse_line(
+        err: &ParseError,
+        number: usize,
+        column: Option<usize>,
+        content: &str,
+    ) {
+        let line = err
+            .line
+            .as_ref()
+            .unwrap_or_else(|| panic!("Missing offending line: {err}"));
+        assert_eq!(line.number, number, "{err}");
+        assert_eq!(line.column, column, "{err}");
+        // The content is a snippet around the offending column
+        assert_eq!(line.content.trim(), content, "{err}");
+    }
+
     pub fn validate_latency(
         metrics: &AdapterMetrics,
         value: f64,
@@ -96,9 +125,27 @@ pub(crate) mod test_util {
         upper_value: Option<f64>,
     ) {
         assert_eq!(metrics.inner.len(), 1);
+        validate_measure(metrics, key, value, lower_value, upper_value);
+    }
+
+    pub fn validate_measure(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
         let metric = metrics.get(key).unwrap();
-        assert_eq!(metric.value, OrderedFloat::from(value));
-        assert_eq!(metric.lower_value, lower_value.map(OrderedFloat::from));
-        assert_eq!(metric.upper_value, upper_value.map(OrderedFloat::from));
+        assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+        assert_eq!(
+            metric.lower_value,
+            lower_value.map(OrderedFloat::from),
+            "{key}"
+        );
+        assert_eq!(
+            metric.upper_value,
+            upper_value.map(OrderedFloat::from),
+            "{key}"
+        );
     }
 }
@@ -0,0 +1,428 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bencher_json::{project::report::Adapter, BenchmarkName, JsonNewMetric, MeasureNameId};
+
+use crate::{results::adapter_results::AdapterResults, Adaptable, ParseError, Settings};
+
+pub struct AdapterOpenMetrics;
+
+const QUANTILE_LABEL: &str = "quantile";
+const BUCKET_LABEL: &str = "le";
+// Histograms estimate the median along with these quantiles from their buckets
+const HISTOGRAM_LOWER_QUANTILE: f64 = 0.1;
+const HISTOGRAM_UPPER_QUANTILE: f64 = 0.9;
+
+impl Adaptable for AdapterOpenMetrics {
+    const ADAPTER: Adapter = Adapter::OpenMetrics;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        let mut metric_types = HashMap::new();
+        let mut samples = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // https://github.com/OpenObservability/OpenMetrics/blob/main/specification/OpenMetrics.md#metricfamily
+            if let Some(comment) = line.strip_prefix('#') {
+                let mut descriptor = comment.split_whitespace();
+                if let (Some("TYPE"), Some(family), Some(metric_type)) =
+                    (descriptor.next(), descriptor.next(), descriptor.next())
+                {
+                    metric_types.insert(family.to_owned(), MetricType::from(metric_type));
+                }
+                continue;
+            }
+            // Every other line must be a sample
+            let sample = Sample::parse(line).ok_or_else(|| {
+                ParseError::new(Self::ADAPTER)
+                    .with_reason("Invalid sample")
+                    .with_line(input, index + 1, None)
+            })?;
+            samples.push(sample);
+        }
+
+        convert_samples(&metric_types, samples).ok_or_else(|| ParseError::new(Self::ADAPTER))
+    }
+}
+
+fn convert_samples(
+    metric_types: &HashMap<String, MetricType>,
+    samples: Vec<Sample>,
+) -> Option<AdapterResults> {
+    let mut metric_families: BTreeMap<(String, Labels), MetricFamily> = BTreeMap::new();
+    for sample in samples {
+        let (family, suffix) = family_name(metric_types, &sample.name);
+        let metric_type = metric_types
+            .get(family)
+            .copied()
+            .unwrap_or(MetricType::Unknown);
+        let Sample {
+            mut labels, value, ..
+        } = sample;
+        let quantile = labels.remove(QUANTILE_LABEL);
+        let bucket = labels.remove(BUCKET_LABEL);
+        let metric_family = metric_families
+            .entry((family.to_owned(), labels))
+            .or_insert_with(|| MetricFamily::new(metric_type));
+        match (suffix, quantile, bucket) {
+            (Suffix::None, Some(quantile), _) => {
+                metric_family
+                    .quantiles
+                    .push((quantile.parse().ok()?, value));
+            },
+            (Suffix::Bucket, _, Some(bucket)) => {
+                metric_family.buckets.push((bucket.parse().ok()?, value));
+            },
+            (Suffix::None | Suffix::Total, None, _) => metric_family.value = Some(value),
+            (Suffix::Sum, _, _) => metric_family.sum = Some(value),
+            (Suffix::Count, _, _) => metric_family.count = Some(value),
+            _ => {},
+        }
+    }
+
+    let mut benchmark_metrics = Vec::with_capacity(metric_families.len());
+    for ((family, labels), metric_family) in metric_families {
+        let Some(json_metric) = metric_family.into_metric() else {
+            continue;
+        };
+        let benchmark_name = benchmark_name(&family, &labels)?;
+        let measure: MeasureNameId = family.parse().ok()?;
+        benchmark_metrics.push((benchmark_name, vec![(measure, json_metric)]));
+    }
+
+    AdapterResults::new_measures(benchmark_metrics)
+}
+
+// Labels are sorted by name so the benchmark name is stable
+type Labels = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricType {
+    Counter,
+    Gauge,
+    Summary,
+    Histogram,
+    GaugeHistogram,
+    Unknown,
+}
+
+impl From<&str> for MetricType {
+    fn from(metric_type: &str) -> Self {
+        match metric_type {
+            "counter" => Self::Counter,
+            "gauge" => Self::Gauge,
+            "summary" => Self::Summary,
+            "histogram" => Self::Histogram,
+            "gaugehistogram" => Self::GaugeHistogram,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Suffix {
+    None,
+    Total,
+    Sum,
+    Count,
+    Bucket,
+    Other,
+}
+
+// A sample name is its metric family name, optionally followed by a suffix for the metric type
+fn family_name<'n>(metric_types: &HashMap<String, MetricType>, name: &'n str) -> (&'n str, Suffix) {
+    for (suffix_str, suffix) in [
+        ("_total", Suffix::Total),
+        ("_sum", Suffix::Sum),
+        ("_gsum", Suffix::Sum),
+        ("_count", Suffix::Count),
+        ("_gcount", Suffix::Count),
+        ("_bucket", Suffix::Bucket),
+        ("_created", Suffix::Other),
+        ("_info", Suffix::Other),
+    ] {
+        if let Some(family) = name.strip_suffix(suffix_str) {
+            if metric_types.contains_key(family) {
+                return (family, suffix);
+            }
+        }
+    }
+    (name, Suffix::None)
+}
+
+#[derive(Debug, Clone)]
+struct Sample {
+    name: String,
+    labels: Labels,
+    value: f64,
+}
+
+impl Sample {
+    // `name{label="value",...} value [timestamp] [# exemplar]`
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.split_once(" # ").map_or(line, |(sample, _)| sample);
+        let name_end = line
+            .find(|c: char| c == '{' || c.is_whitespace())
+            .unwrap_or(line.len());
+        let (name, rest) = line.split_at(name_end);
+        if name.is_empty() {
+            return None;
+        }
+        let (labels, rest) = if let Some(rest) = rest.strip_prefix('{') {
+            parse_labels(rest)?
+        } else {
+            (Labels::new(), rest)
+        };
+        // The optional timestamp is ignored
+        let value = rest.split_whitespace().next()?.parse().ok()?;
+        Some(Self {
+            name: name.to_owned(),
+            labels,
+            value,
+        })
+    }
+}
+
+// `label="value",...}` with `\"`, `\\`, and `\n` escapes in the values
+fn parse_labels(input: &str) -> Option<(Labels, &str)> {
+    let mut labels = Labels::new();
+    let mut rest = input.trim_start();
+    loop {
+        rest = rest.trim_start_matches([',', ' ']);
+        if let Some(rest) = rest.strip_prefix('}') {
+            return Some((labels, rest));
+        }
+        let (name, value) = rest.split_once('=')?;
+        let value = value.trim_start().strip_prefix('"')?;
+        let mut label_value = String::new();
+        let mut chars = value.char_indices();
+        let end = loop {
+            match chars.next()? {
+                (index, '"') => break index,
+                (_, '\\') => match chars.next()? {
+                    (_, 'n') => label_value.push('\n'),
+                    (_, c) => label_value.push(c),
+                },
+                (_, c) => label_value.push(c),
+            }
+        };
+        labels.insert(name.trim().to_owned(), label_value);
+        rest = value.get(end + 1..)?;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MetricFamily {
+    metric_type: MetricType,
+    value: Option<f64>,
+    quantiles: Vec<(f64, f64)>,
+    buckets: Vec<(f64, f64)>,
+    sum: Option<f64>,
+    count: Option<f64>,
+}
+
+impl MetricFamily {
+    fn new(metric_type: MetricType) -> Self {
+        Self {
+            metric_type,
+            value: None,
+            quantiles: Vec::new(),
+            buckets: Vec::new(),
+            sum: None,
+            count: None,
+        }
+    }
+
+    fn into_metric(self) -> Option<JsonNewMetric> {
+        let json_metric = match self.metric_type {
+            MetricType::Summary => self.summary_metric(),
+            MetricType::Histogram | MetricType::GaugeHistogram => self.histogram_metric(),
+            MetricType::Counter | MetricType::Gauge | MetricType::Unknown => {
+                self.value.map(|value| JsonNewMetric {
+                    value: value.into(),
+                    lower_value: None,
+                    upper_value: None,
+                    samples: None,
+                })
+            },
+        }?;
+        json_metric.value.is_finite().then_some(json_metric)
+    }
+
+    fn mean(&self) -> Option<f64> {
+        match (self.sum, self.count) {
+            (Some(sum), Some(count)) if count > 0.0 => Some(sum / count),
+            _ => None,
+        }
+    }
+
+    // The median is the value, and the lowest and highest quantiles are the bounds.
+    // Without a median, the mean is used instead.
+    fn summary_metric(mut self) -> Option<JsonNewMetric> {
+        self.quantiles.retain(|(_, value)| value.is_finite());
+        self.quantiles.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        let median = self
+            .quantiles
+            .iter()
+            .find(|(quantile, _)| (*quantile - 0.5).abs() < f64::EPSILON)
+            .map(|(_, value)| *value);
+        let value = median.or_else(|| self.mean())?;
+        Some(JsonNewMetric {
+            value: value.into(),
+            lower_value: self.quantiles.first().map(|(_, value)| (*value).into()),
+            upper_value: self.quantiles.last().map(|(_, value)| (*value).into()),
+            samples: None,
+        })
+    }
+
+    fn histogram_metric(mut self) -> Option<JsonNewMetric> {
+        self.buckets.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        let value = histogram_quantile(&self.buckets, 0.5).or_else(|| self.mean())?;
+        Some(JsonNewMetric {
+            value: value.into(),
+            lower_value: histogram_quantile(&self.buckets, HISTOGRAM_LOWER_QUANTILE)
+                .map(Into::into),
+            upper_value: histogram_quantile(&self.buckets, HISTOGRAM_UPPER_QUANTILE)
+                .map(Into::into),
+            samples: None,
+        })
+    }
+}
+
+// Linear interpolation within the cumulative bucket that contains the quantile,
+// the same as the Prometheus `histogram_quantile` function.
+fn histogram_quantile(buckets: &[(f64, f64)], quantile: f64) -> Option<f64> {
+    let (_, total) = buckets.last()?;
+    if *total <= 0.0 {
+        return None;
+    }
+    let rank = quantile * total;
+    let mut lower_bound = 0.0;
+    let mut lower_count = 0.0;
+    for (upper_bound, count) in buckets {
+        if *count >= rank {
+            // The quantile is in the `+Inf` bucket, so use the highest finite bound
+            if upper_bound.is_infinite() {
+                return Some(lower_bound);
+            }
+            let bucket_count = count - lower_count;
+            if bucket_count <= 0.0 {
+                return Some(*upper_bound);
+            }
+            return Some(
+                lower_bound + (upper_bound - lower_bound) * (rank - lower_count) / bucket_count,
+            );
+        }
+        lower_bound = *upper_bound;
+        lower_count = *count;
+    }
+    None
+}
+
+// `family{label="value",...}`
+fn benchmark_name(family: &str, labels: &Labels) -> Option<BenchmarkName> {
+    if labels.is_empty() {
+        return family.parse().ok();
+    }
+    let labels = labels
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{name}=\"{value}\"")
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{family}{{{labels}}}").parse().ok()
+}
+
+#[cfg(test)]
+pub(crate) mod test_open_metrics {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_metric},
+        Adaptable, AdapterResults, ParseLine, Settings,
+    };
+
+    use super::AdapterOpenMetrics;
+
+    fn convert_open_metrics(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/open_metrics/{suffix}.txt");
+        convert_file_path::<AdapterOpenMetrics>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_open_metrics_exporter() {
+        let results = convert_open_metrics("exporter");
+        assert_eq!(results.inner.len(), 6);
+
+        let metrics = results
+            .get(r#"http_requests{code="200",method="get"}"#)
+            .unwrap();
+        validate_metric(metrics, "http_requests", 1027.0, None, None);
+
+        let metrics = results
+            .get(r#"http_requests{code="500",method="get"}"#)
+            .unwrap();
+        validate_metric(metrics, "http_requests", 3.0, None, None);
+
+        let metrics = results.get("vus").unwrap();
+        validate_metric(metrics, "vus", 50.0, None, None);
+
+        let metrics = results
+            .get(r#"http_req_duration_seconds{scenario="browse \"home\""}"#)
+            .unwrap();
+        validate_metric(
+            metrics,
+            "http_req_duration_seconds",
+            0.052,
+            Some(0.021),
+            Some(0.310),
+        );
+
+        // Without quantiles, the mean is used
+        let metrics = results.get("iteration_duration_seconds").unwrap();
+        validate_metric(metrics, "iteration_duration_seconds", 1.5, None, None);
+
+        let metrics = results
+            .get(r#"http_req_waiting_ms{scenario="checkout"}"#)
+            .unwrap();
+        validate_metric(metrics, "http_req_waiting_ms", 15.0, Some(5.0), Some(75.0));
+
+        assert_eq!(results.get("process_start_time_seconds"), None);
+    }
+
+    #[test]
+    fn test_adapter_open_metrics_invalid() {
+        assert_eq!(
+            opt_convert_file_path::<AdapterOpenMetrics>(
+                "./tool_output/csv/header.csv",
+                Settings::default()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_adapter_open_metrics_invalid_line() {
+        let input = "# TYPE http_requests counter\nhttp_requests_total 1027\nnot a sample\n";
+        let err = AdapterOpenMetrics::try_parse(input, Settings::default()).unwrap_err();
+        assert_eq!(err.reason.as_deref(), Some("Invalid sample"));
+        assert_eq!(
+            err.line,
+            Some(ParseLine {
+                number: 3,
+                column: None,
+                content: "not a sample".to_owned(),
+            })
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 use nom::{
     bytes::complete::{tag, take_until1, take_while1},
     character::complete::{space0, space1},
@@ -9,25 +12,35 @@ use nom::{
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
+        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, FurthestLine,
+        NomError,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterPythonAsv;
 
 impl Adaptable for AdapterPythonAsv {
+    const ADAPTER: Adapter = Adapter::PythonAsv;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             Some(JsonAverage::Median) | None => {},
-            Some(JsonAverage::Mean) => return None,
+            Some(JsonAverage::Mean) => return Err(ParseError::new(Self::ADAPTER)),
         }
 
         let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_asv(line) {
+        let mut furthest_line = FurthestLine::default();
+        for (index, line) in input.lines().enumerate() {
+            let result = parse_asv(line);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                 }
@@ -35,6 +48,7 @@ impl Adaptable for AdapterPythonAsv {
         }
 
         AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
@@ -71,6 +85,7 @@ fn parse_asv_time(input: &str) -> IResult<&str, JsonNewMetric> {
                 value,
                 lower_value: Some(value - range),
                 upper_value: Some(value + range),
+                samples: None,
             }
         },
     )(input)
@@ -100,17 +115,13 @@ pub(crate) mod test_python_asv {
             None,
             opt_convert_file_path::<AdapterPythonAsv>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
         let results = opt_convert_file_path::<AdapterPythonAsv>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Median),
-            },
+            Settings::new(Some(JsonAverage::Median)),
         )
         .unwrap();
         validate_adapter_python_asv(&results);
@@ -1,16 +1,29 @@
 pub mod asv;
 pub mod pytest;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
 use asv::AdapterPythonAsv;
+use bencher_json::project::report::Adapter;
 use pytest::AdapterPythonPytest;
 
 pub struct AdapterPython;
 
 impl Adaptable for AdapterPython {
+    const ADAPTER: Adapter = Adapter::Python;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterPythonAsv::parse(input, settings)
-            .or_else(|| AdapterPythonPytest::parse(input, settings))
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterPythonAsv::try_parse, AdapterPythonPytest::try_parse],
+        )
     }
 }
 
@@ -1,40 +1,71 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
+use std::collections::HashMap;
+
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, GitHash, JsonNewMetric, MeasureNameId, NameId,
+};
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
-    adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    adapters::util::{latency_as_nanos, try_parse_json, Units},
+    results::adapter_results::{AdapterResults, LATENCY_NAME_ID, THROUGHPUT_NAME_ID},
+    Adaptable, AdapterError, ParseError, Settings,
 };
 
 pub struct AdapterPythonPytest;
 
 impl Adaptable for AdapterPythonPytest {
+    const ADAPTER: Adapter = Adapter::PythonPytest;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        serde_json::from_str::<Pytest>(input)
-            .ok()?
-            .convert(settings)
-            .ok()?
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |pytest: Pytest| {
+            pytest.convert(&settings)
+        })
     }
 }
 
+pub const LATENCY_MIN_SLUG_STR: &str = "latency-min";
+pub const LATENCY_MAX_SLUG_STR: &str = "latency-max";
+pub const ROUNDS_SLUG_STR: &str = "rounds";
+
+const GROUP_SEPARATOR: &str = "::";
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Pytest {
-    pub machine_info: JsonAny,
-    pub commit_info: JsonAny,
+    pub machine_info: MachineInfo,
+    pub commit_info: CommitInfo,
     pub benchmarks: Benchmarks,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct MachineInfo {
+    pub node: Option<String>,
+}
+
+// If the benchmarks were not run in a git repository, then only an `error` is present.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitInfo {
+    pub id: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Benchmarks(pub Vec<Benchmark>);
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Benchmark {
+    pub group: Option<String>,
     pub fullname: BenchmarkName,
+    pub param: Option<String>,
+    pub extra_info: Option<HashMap<String, Value>>,
     pub stats: Stats,
 }
 
@@ -49,16 +80,48 @@ pub struct Stats {
     pub median: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub iqr: Decimal,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub min: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub max: Option<Decimal>,
+    pub ops: Option<f64>,
+    pub rounds: Option<u64>,
+    // Only present when run with `--benchmark-save-data`
+    pub data: Option<Vec<Value>>,
+}
+
+/// The testbed and git hash that `pytest-benchmark` recorded for its run.
+#[derive(Debug, Clone, Default)]
+pub struct PytestContext {
+    pub testbed: Option<NameId>,
+    pub hash: Option<GitHash>,
+}
+
+impl AdapterPythonPytest {
+    pub fn context(input: &str) -> Option<PytestContext> {
+        let Pytest {
+            machine_info,
+            commit_info,
+            ..
+        } = serde_json::from_str::<Pytest>(input).ok()?;
+        Some(PytestContext {
+            testbed: machine_info.node.and_then(|node| node.parse().ok()),
+            hash: commit_info.id.and_then(|id| id.parse().ok()),
+        })
+    }
 }
 
 impl Pytest {
-    #[allow(clippy::unnecessary_wraps)]
-    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+    #[allow(clippy::cast_precision_loss)]
+    fn convert(self, settings: &Settings) -> Result<Option<AdapterResults>, AdapterError> {
         let benchmarks = self.benchmarks.0;
         let mut benchmark_metrics = Vec::with_capacity(benchmarks.len());
         for benchmark in benchmarks {
             let Benchmark {
-                fullname: benchmark_name,
+                group,
+                fullname,
+                param,
+                extra_info,
                 stats,
             } = benchmark;
             let Stats {
@@ -66,8 +129,15 @@ impl Pytest {
                 stddev,
                 median,
                 iqr,
+                min,
+                max,
+                ops,
+                rounds,
+                data,
             } = stats;
 
+            let benchmark_name = benchmark_name(group, &fullname, param)?;
+
             // JSON output is always in seconds
             let units = Units::Sec;
             let (average, spread) = match settings.average.unwrap_or_default() {
@@ -76,16 +146,88 @@ impl Pytest {
             };
             let value = latency_as_nanos(average, units);
             let spread = latency_as_nanos(spread, units);
-            let json_metric = JsonNewMetric {
-                value,
-                lower_value: Some(value - spread),
-                upper_value: Some(value + spread),
-            };
+            // Ignore the samples entirely if any of them are not numbers, such as a truncated list
+            let samples = data.and_then(|data| {
+                data.iter()
+                    .map(|datum| datum.as_f64().map(|datum| latency_as_nanos(datum, units)))
+                    .collect()
+            });
+            let mut metrics = vec![(
+                LATENCY_NAME_ID.clone(),
+                JsonNewMetric {
+                    value,
+                    lower_value: Some(value - spread),
+                    upper_value: Some(value + spread),
+                    samples,
+                },
+            )];
+
+            let extra_measures = [
+                (
+                    LATENCY_MIN_SLUG_STR,
+                    min.map(|min| latency_as_nanos(min, units).into_inner()),
+                ),
+                (
+                    LATENCY_MAX_SLUG_STR,
+                    max.map(|max| latency_as_nanos(max, units).into_inner()),
+                ),
+                (ROUNDS_SLUG_STR, rounds.map(|rounds| rounds as f64)),
+            ];
+            for (slug, value) in extra_measures {
+                if let Some(value) = value {
+                    metrics.push((slug.parse()?, extra_metric(value)));
+                }
+            }
+            if let Some(ops) = ops {
+                metrics.push((THROUGHPUT_NAME_ID.clone(), extra_metric(ops)));
+            }
+
+            // Only numeric `extra_info` values can be used as measures
+            let mut extra_info = extra_info
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(key, value)| value.as_f64().map(|value| (key, value)))
+                .collect::<Vec<_>>();
+            extra_info.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key, value) in extra_info {
+                // Skip any keys that are not valid measure names
+                if let Ok(measure) = key.parse::<MeasureNameId>() {
+                    metrics.push((measure, extra_metric(value)));
+                }
+            }
+
+            benchmark_metrics.push((benchmark_name, metrics));
+        }
 
-            benchmark_metrics.push((benchmark_name, json_metric));
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+// The `fullname` already includes the parameter for parametrized benchmarks, ie `test_fib[10]`.
+fn benchmark_name(
+    group: Option<String>,
+    fullname: &BenchmarkName,
+    param: Option<String>,
+) -> Result<BenchmarkName, AdapterError> {
+    let mut benchmark_name = fullname.to_string();
+    if let Some(param) = param {
+        let param = format!("[{param}]");
+        if !benchmark_name.ends_with(&param) {
+            benchmark_name.push_str(&param);
         }
+    }
+    if let Some(group) = group.filter(|group| !group.is_empty()) {
+        benchmark_name = format!("{group}{GROUP_SEPARATOR}{benchmark_name}");
+    }
+    benchmark_name.parse().map_err(Into::into)
+}
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+fn extra_metric(value: f64) -> JsonNewMetric {
+    JsonNewMetric {
+        value: value.into(),
+        lower_value: None,
+        upper_value: None,
+        samples: None,
     }
 }
 
@@ -96,7 +238,7 @@ pub(crate) mod test_python_pytest {
 
     use crate::{
         adapters::test_util::{
-            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_measure,
         },
         AdapterResults, Settings,
     };
@@ -123,16 +265,18 @@ pub(crate) mod test_python_pytest {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             24_088.681_333_229_408,
             Some(17_913.591_143_368_08),
             Some(30_263.771_523_090_734),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             2_985_030.672_661_863,
             Some(2_810_500.507_247_766),
             Some(3_159_560.838_075_959_6),
@@ -145,16 +289,18 @@ pub(crate) mod test_python_pytest {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             22_300.000_000_363_696,
             Some(21_033.000_000_003_63),
             Some(23_567.000_000_723_76),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             2_960_582.500_000_308_3,
             Some(2_740_893.500_000_618_4),
             Some(3_180_271.499_999_998),
@@ -171,9 +317,7 @@ pub(crate) mod test_python_pytest {
 
         let results = opt_convert_file_path::<AdapterPythonPytest>(
             &file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_python_pytest(&results);
@@ -186,32 +330,37 @@ pub(crate) mod test_python_pytest {
         assert_eq!(results.inner.len(), 4);
 
         let metrics = results.get("bench.py::test_fib_1").unwrap();
-        validate_latency(
+        assert_eq!(metrics.inner.len(), 5);
+        validate_measure(
             metrics,
+            "latency",
             149.956_102_486_288_36,
             Some(120.604_370_534_148_98),
             Some(179.307_834_438_427_73),
         );
 
         let metrics = results.get("bench.py::test_sleep_2").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             2_003_843_046.999_999_8,
             Some(2_001_965_388.274_841),
             Some(2_005_720_705.725_158_5),
         );
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             28_857.540_124_844_24,
             Some(23_621.602_642_835_765),
             Some(34_093.477_606_852_71),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             3_611_916.368_852_473,
             Some(3_238_118.086_634_651_3),
             Some(3_985_714.651_070_294_4),
@@ -222,35 +371,89 @@ pub(crate) mod test_python_pytest {
         assert_eq!(results.inner.len(), 4);
 
         let metrics = results.get("bench.py::test_fib_1").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             143.760_000_002_046_7,
             Some(143.090_000_004_342_24),
             Some(144.429_999_999_751_15),
         );
 
         let metrics = results.get("bench.py::test_sleep_2").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             2_005_124_842.999_999,
             Some(2_002_304_321.999_998_8),
             Some(2_007_945_363.999_999_3),
         );
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             28_052.999_999_861_328,
             Some(27_927.999_999_732_834),
             Some(28_177.999_999_989_82),
         );
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
-        validate_latency(
+        validate_measure(
             metrics,
+            "latency",
             3_471_104.000_000_169,
             Some(3_369_463.000_000_072),
             Some(3_572_745.000_000_266),
         );
     }
+
+    #[test]
+    fn test_adapter_python_pytest_group() {
+        let results = convert_python_pytest("group");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results
+            .get("parse::tests/test_parse.py::test_parse[small]")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 6);
+        validate_measure(
+            metrics,
+            "latency",
+            1_000_000.0,
+            Some(900_000.0),
+            Some(1_100_000.0),
+        );
+        validate_measure(metrics, "latency-min", 800_000.0, None, None);
+        validate_measure(metrics, "latency-max", 2_000_000.0, None, None);
+        validate_measure(metrics, "throughput", 1_000.0, None, None);
+        validate_measure(metrics, "rounds", 1_000.0, None, None);
+        validate_measure(metrics, "bytes", 1_024.0, None, None);
+        assert_eq!(metrics.get("backend"), None);
+
+        let metrics = results
+            .get("parse::tests/test_parse.py::test_parse[large]")
+            .unwrap();
+        validate_measure(
+            metrics,
+            "latency",
+            500_000_000.0,
+            Some(475_000_000.0),
+            Some(525_000_000.0),
+        );
+        validate_measure(metrics, "bytes", 1_048_576.0, None, None);
+
+        let metrics = results.get("tests/test_parse.py::test_noop").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+    }
+
+    #[test]
+    fn test_adapter_python_pytest_context() {
+        let input = std::fs::read_to_string(file_path("group")).unwrap();
+        let context = AdapterPythonPytest::context(&input).unwrap();
+        assert_eq!(context.testbed.unwrap().as_ref(), "ci-runner-01");
+        assert_eq!(
+            context.hash.unwrap().as_ref(),
+            "1234567890abcdef1234567890abcdef12345678"
+        );
+    }
 }
@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{anychar, space1},
@@ -9,29 +12,42 @@ use nom::{
 };
 
 use crate::{
-    adapters::util::{latency_as_nanos, parse_benchmark_name_chars, parse_f64, NomError, Units},
+    adapters::util::{
+        latency_as_nanos, parse_benchmark_name_chars, parse_f64, FurthestLine, NomError, Units,
+    },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterRubyBenchmark;
 
 impl Adaptable for AdapterRubyBenchmark {
+    const ADAPTER: Adapter = Adapter::RubyBenchmark;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         if let Some(JsonAverage::Mean | JsonAverage::Median) = settings.average {
-            return None;
+            return Err(ParseError::new(Self::ADAPTER));
         }
 
         let mut benchmark_metrics = Vec::new();
 
+        let mut furthest_line = FurthestLine::default();
         let mut header = false;
-        for line in input.lines() {
+        for (index, line) in input.lines().enumerate() {
             if !header {
-                header = parse_header(line).is_ok();
+                let result = parse_header(line);
+                furthest_line.track(index, line, &result);
+                header = result.is_ok();
                 continue;
             }
 
-            if let Ok((remainder, benchmark_metric)) = parse_ruby(line) {
+            let result = parse_ruby(line);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                     continue;
@@ -42,6 +58,7 @@ impl Adaptable for AdapterRubyBenchmark {
         }
 
         AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
@@ -92,6 +109,7 @@ fn parse_ruby_benchmark(input: &str) -> IResult<&str, JsonNewMetric> {
                 value,
                 lower_value: None,
                 upper_value: None,
+                samples: None,
             })
         },
     )(input)
@@ -121,9 +139,7 @@ pub(crate) mod test_ruby_benchmark {
             None,
             opt_convert_file_path::<AdapterRubyBenchmark>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
@@ -131,9 +147,7 @@ pub(crate) mod test_ruby_benchmark {
             None,
             opt_convert_file_path::<AdapterRubyBenchmark>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
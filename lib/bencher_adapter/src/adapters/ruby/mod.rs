@@ -1,13 +1,27 @@
 pub mod benchmark;
 
-use crate::{Adaptable, AdapterResults, Settings};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
+use bencher_json::project::report::Adapter;
 use benchmark::AdapterRubyBenchmark;
 
 pub struct AdapterRuby;
 
 impl Adaptable for AdapterRuby {
+    const ADAPTER: Adapter = Adapter::Ruby;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterRubyBenchmark::parse(input, settings)
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterRubyBenchmark::try_parse],
+        )
     }
 }
 
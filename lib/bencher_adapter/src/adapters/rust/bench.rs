@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 use nom::{
     bytes::complete::{tag, take_until1},
     character::complete::space1,
@@ -9,25 +12,35 @@ use nom::{
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_number_as_f64, parse_units, NomError,
+        latency_as_nanos, parse_benchmark_name, parse_number_as_f64, parse_units, FurthestLine,
+        NomError,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterRustBench;
 
 impl Adaptable for AdapterRustBench {
+    const ADAPTER: Adapter = Adapter::RustBench;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             Some(JsonAverage::Median) | None => {},
-            Some(JsonAverage::Mean) => return None,
+            Some(JsonAverage::Mean) => return Err(ParseError::new(Self::ADAPTER)),
         }
 
         let mut benchmark_metrics = Vec::new();
 
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_cargo(line) {
+        let mut furthest_line = FurthestLine::default();
+        for (index, line) in input.lines().enumerate() {
+            let result = parse_cargo(line);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                 }
@@ -35,6 +48,7 @@ impl Adaptable for AdapterRustBench {
         }
 
         AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
@@ -82,6 +96,7 @@ fn parse_cargo_bench(input: &str) -> IResult<&str, JsonNewMetric> {
                 value,
                 lower_value: variance.map(|v| value - v),
                 upper_value: variance.map(|v| value + v),
+                samples: None,
             }
         },
     )(input)
@@ -125,17 +140,13 @@ pub(crate) mod test_rust_bench {
             None,
             opt_convert_file_path::<AdapterRustBench>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Mean)
-                }
+                Settings::new(Some(JsonAverage::Mean))
             )
         );
 
         let results = opt_convert_file_path::<AdapterRustBench>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Median),
-            },
+            Settings::new(Some(JsonAverage::Median)),
         )
         .unwrap();
         validate_adapter_rust_bench(&results);
@@ -152,6 +163,7 @@ pub(crate) mod test_rust_bench {
                         value: 5_280.0.into(),
                         lower_value: Some(4_947.0.into()),
                         upper_value: Some(5_613.0.into()),
+                        samples: None,
                     },
                 ),
             )),
@@ -1,4 +1,7 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonNewMetric};
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{anychar, space1},
@@ -11,26 +14,36 @@ use ordered_float::OrderedFloat;
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
+        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, FurthestLine,
+        NomError,
     },
     results::adapter_results::AdapterResults,
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterRustCriterion;
 
 impl Adaptable for AdapterRustCriterion {
+    const ADAPTER: Adapter = Adapter::RustCriterion;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
+            Some(JsonAverage::Median) => return Err(ParseError::new(Self::ADAPTER)),
         }
 
         let mut benchmark_metrics = Vec::new();
 
+        let mut furthest_line = FurthestLine::default();
         let mut prior_line = None;
-        for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_criterion(prior_line, line) {
+        for (index, line) in input.lines().enumerate() {
+            let result = parse_criterion(prior_line, line);
+            furthest_line.track(index, line, &result);
+            if let Ok((remainder, benchmark_metric)) = result {
                 if remainder.is_empty() {
                     benchmark_metrics.push(benchmark_metric);
                 }
@@ -40,6 +53,7 @@ impl Adaptable for AdapterRustCriterion {
         }
 
         AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
@@ -89,6 +103,7 @@ fn parse_criterion_metric(input: &str) -> IResult<&str, JsonNewMetric> {
             value,
             lower_value: Some(lower_value),
             upper_value: Some(upper_value),
+            samples: None,
         },
     )(input)
 }
@@ -129,6 +144,7 @@ pub(crate) mod test_rust_criterion {
                             value: 280.0.into(),
                             lower_value: Some(222.2.into()),
                             upper_value: Some(333.33.into()),
+                            samples: None,
                         },
                     ),
                 )),
@@ -143,6 +159,7 @@ pub(crate) mod test_rust_criterion {
                             value: 5.280.into(),
                             lower_value: Some(0.222.into()),
                             upper_value: Some(0.33333.into()),
+                            samples: None,
                         },
                     ),
                 )),
@@ -157,6 +174,7 @@ pub(crate) mod test_rust_criterion {
                             value: 18_019.0.into(),
                             lower_value: Some(16_652.0.into()),
                             upper_value: Some(19_562.0.into()),
+                            samples: None,
                         },
                     ),
                 )),
@@ -175,9 +193,7 @@ pub(crate) mod test_rust_criterion {
         let file_path = "./tool_output/rust/criterion/many.txt";
         let results = opt_convert_file_path::<AdapterRustCriterion>(
             file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_rust_criterion(&results);
@@ -186,9 +202,7 @@ pub(crate) mod test_rust_criterion {
             None,
             opt_convert_file_path::<AdapterRustCriterion>(
                 file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
+                Settings::new(Some(JsonAverage::Median))
             )
         );
     }
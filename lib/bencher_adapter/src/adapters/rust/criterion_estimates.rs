@@ -0,0 +1,306 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric, MeasureNameId, ValidError,
+};
+use ordered_float::OrderedFloat;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{
+    results::adapter_results::{AdapterResults, LATENCY_NAME_ID, THROUGHPUT_NAME_ID},
+    AdapterError, ParseError, Settings,
+};
+
+pub const BENCHMARK_FILE_NAME: &str = "benchmark.json";
+pub const ESTIMATES_FILE_NAME: &str = "estimates.json";
+pub const SAMPLE_FILE_NAME: &str = "sample.json";
+
+/// The machine-readable output that `criterion` saves for each benchmark,
+/// in `target/criterion/<directory_name>/new/{benchmark,estimates,sample}.json`.
+#[derive(Debug, Clone, Default)]
+pub struct CriterionEstimates {
+    pub benchmarks: Vec<(Benchmark, Estimates, Option<Sample>)>,
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/report.rs#L120
+#[derive(Debug, Clone, Deserialize)]
+pub struct Benchmark {
+    pub group_id: String,
+    pub function_id: Option<String>,
+    pub value_str: Option<String>,
+    pub throughput: Option<Throughput>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum Throughput {
+    Bytes(u64),
+    BytesDecimal(u64),
+    Elements(u64),
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/estimate.rs#L38
+// All estimates are in nanoseconds.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Estimates {
+    pub mean: Estimate,
+    pub median: Estimate,
+    pub median_abs_dev: Estimate,
+    pub slope: Option<Estimate>,
+    pub std_dev: Estimate,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Estimate {
+    pub confidence_interval: ConfidenceInterval,
+    pub point_estimate: f64,
+    pub standard_error: f64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ConfidenceInterval {
+    pub confidence_level: f64,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/fs.rs
+// Each time is the total for its number of iterations in nanoseconds.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Sample {
+    pub iters: Vec<f64>,
+    pub times: Vec<f64>,
+}
+
+impl CriterionEstimates {
+    pub fn push(
+        &mut self,
+        benchmark: &str,
+        estimates: &str,
+        sample: Option<&str>,
+    ) -> Result<(), ParseError> {
+        let benchmark = parse_json(benchmark)?;
+        let estimates = parse_json(estimates)?;
+        let sample = sample.map(parse_json).transpose()?;
+        self.benchmarks.push((benchmark, estimates, sample));
+        Ok(())
+    }
+
+    pub fn convert(self, settings: &Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::with_capacity(self.benchmarks.len());
+        for (benchmark, estimates, sample) in self.benchmarks {
+            let benchmark_name = benchmark.name()?;
+            let Benchmark { throughput, .. } = benchmark;
+            let Estimates {
+                mean,
+                median,
+                slope,
+                ..
+            } = estimates;
+
+            // This mirrors the estimate that `criterion` displays as the `time`.
+            // The slope is only available when using linear sampling.
+            let estimate = match settings.average.unwrap_or_default() {
+                JsonAverage::Mean => slope.unwrap_or(mean),
+                JsonAverage::Median => median,
+            };
+            let mut latency = estimate.latency();
+            latency.samples = sample.map(Sample::per_iteration);
+            let mut metrics: Vec<(MeasureNameId, JsonNewMetric)> =
+                vec![(LATENCY_NAME_ID.clone(), latency)];
+            if let Some(throughput) = throughput {
+                metrics.push((THROUGHPUT_NAME_ID.clone(), estimate.throughput(throughput)));
+            }
+            benchmark_metrics.push((benchmark_name, metrics));
+        }
+
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+// Each saved file is reported as the `criterion` adapter, since it is converted from its output
+fn parse_json<T>(input: &str) -> Result<T, ParseError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(input).map_err(|e| ParseError::json(Adapter::RustCriterion, input, &e))
+}
+
+impl Benchmark {
+    // This mirrors how `criterion` builds the `full_id` for a benchmark
+    fn name(&self) -> Result<BenchmarkName, ValidError> {
+        [
+            Some(self.group_id.as_str()),
+            self.function_id.as_deref(),
+            self.value_str.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("/")
+        .parse()
+    }
+}
+
+impl Estimate {
+    fn latency(self) -> JsonNewMetric {
+        JsonNewMetric {
+            value: self.point_estimate.into(),
+            lower_value: Some(self.confidence_interval.lower_bound.into()),
+            upper_value: Some(self.confidence_interval.upper_bound.into()),
+            samples: None,
+        }
+    }
+
+    // The fastest time is the highest throughput, so the bounds are swapped.
+    fn throughput(self, throughput: Throughput) -> JsonNewMetric {
+        let per_second = |nanos: f64| throughput.per_iteration() * 1_000_000_000.0 / nanos;
+        JsonNewMetric {
+            value: per_second(self.point_estimate).into(),
+            lower_value: Some(per_second(self.confidence_interval.upper_bound).into()),
+            upper_value: Some(per_second(self.confidence_interval.lower_bound).into()),
+            samples: None,
+        }
+    }
+}
+
+impl Sample {
+    fn per_iteration(self) -> Vec<OrderedFloat<f64>> {
+        self.iters
+            .into_iter()
+            .zip(self.times)
+            .map(|(iters, time)| (time / iters).into())
+            .collect()
+    }
+}
+
+impl Throughput {
+    #[allow(clippy::cast_precision_loss)]
+    fn per_iteration(self) -> f64 {
+        match self {
+            Self::Bytes(count) | Self::BytesDecimal(count) | Self::Elements(count) => count as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_rust_criterion_estimates {
+    use bencher_json::project::report::JsonAverage;
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{validate_latency, validate_metric, validate_parse_line},
+        AdapterResults, Settings,
+    };
+
+    use super::{CriterionEstimates, BENCHMARK_FILE_NAME, ESTIMATES_FILE_NAME, SAMPLE_FILE_NAME};
+
+    fn convert_rust_criterion_estimates(settings: &Settings) -> AdapterResults {
+        let mut criterion_estimates = CriterionEstimates::default();
+        for directory_name in ["fib/fib_10", "fib/fib_20", "parse/bytes/1024"] {
+            let new_dir = format!("./tool_output/rust/criterion_estimates/{directory_name}/new");
+            let benchmark =
+                std::fs::read_to_string(format!("{new_dir}/{BENCHMARK_FILE_NAME}")).unwrap();
+            let estimates =
+                std::fs::read_to_string(format!("{new_dir}/{ESTIMATES_FILE_NAME}")).unwrap();
+            let sample = std::fs::read_to_string(format!("{new_dir}/{SAMPLE_FILE_NAME}")).ok();
+            criterion_estimates
+                .push(&benchmark, &estimates, sample.as_deref())
+                .unwrap();
+        }
+        criterion_estimates.convert(settings).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_estimates() {
+        let results = convert_rust_criterion_estimates(&Settings::default());
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/fib_10").unwrap();
+        validate_latency(metrics, 224.0, Some(222.0), Some(226.0));
+        // Each sample is the time per iteration
+        assert_eq!(
+            metrics.get("latency").unwrap().samples,
+            Some(vec![
+                OrderedFloat::from(220.0),
+                OrderedFloat::from(225.0),
+                OrderedFloat::from(230.0)
+            ])
+        );
+
+        // No slope without linear sampling
+        let metrics = results.get("fib/fib_20").unwrap();
+        validate_latency(metrics, 27_500.0, Some(27_000.0), Some(28_000.0));
+
+        let metrics = results.get("parse/bytes/1024").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        let latency = metrics.get("latency").unwrap();
+        assert_eq!(latency.value, OrderedFloat::from(512.0));
+        // Not every benchmark has a saved sample
+        assert_eq!(latency.samples, None);
+        let throughput = metrics.get("throughput").unwrap();
+        assert_eq!(throughput.value, OrderedFloat::from(2_000_000_000.0));
+        assert_eq!(
+            throughput.lower_value,
+            Some(OrderedFloat::from(1_280_000_000.0))
+        );
+        assert_eq!(
+            throughput.upper_value,
+            Some(OrderedFloat::from(2_560_000_000.0))
+        );
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_estimates_median() {
+        let results = convert_rust_criterion_estimates(&Settings::new(Some(JsonAverage::Median)));
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/fib_10").unwrap();
+        validate_metric(metrics, "latency", 221.0, Some(219.0), Some(223.0));
+
+        let metrics = results.get("fib/fib_20").unwrap();
+        validate_latency(metrics, 27_200.0, Some(27_100.0), Some(27_300.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_estimates_name() {
+        let estimates = std::fs::read_to_string(format!(
+            "./tool_output/rust/criterion_estimates/fib/fib_20/new/{ESTIMATES_FILE_NAME}"
+        ))
+        .unwrap();
+        let mut criterion_estimates = CriterionEstimates::default();
+        // A parameterized benchmark without a function name
+        criterion_estimates
+            .push(
+                r#"{"group_id":"parse","function_id":null,"value_str":"1024","throughput":null,"full_id":"parse/1024","directory_name":"parse/1024","title":"parse/1024"}"#,
+                &estimates,
+                None,
+            )
+            .unwrap();
+        let results = criterion_estimates
+            .convert(&Settings::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(results.inner.len(), 1);
+        let metrics = results.get("parse/1024").unwrap();
+        validate_latency(metrics, 27_500.0, Some(27_000.0), Some(28_000.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_estimates_parse_error() {
+        let new_dir = "./tool_output/rust/criterion_estimates/invalid/fib_30/new";
+        let benchmark =
+            std::fs::read_to_string(format!("{new_dir}/{BENCHMARK_FILE_NAME}")).unwrap();
+        let estimates =
+            std::fs::read_to_string(format!("{new_dir}/{ESTIMATES_FILE_NAME}")).unwrap();
+        let err = CriterionEstimates::default()
+            .push(&benchmark, &estimates, None)
+            .unwrap_err();
+        validate_parse_line(
+            &err,
+            1,
+            Some(122),
+            r#"per_bound":28000.0},"point_estimate":275O0.0,"standard_error":250.0},"median":{""#,
+        );
+        assert_eq!(err.reason.as_deref(), Some("expected `,` or `}`"));
+    }
+}
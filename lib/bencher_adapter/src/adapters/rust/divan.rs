@@ -0,0 +1,256 @@
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    JsonNewMetric,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::util::{latency_as_nanos, parse_benchmark_name, value_column, FurthestLine, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, ParseError, Settings,
+};
+
+pub struct AdapterRustDivan;
+
+pub const DIVAN_NAME_SEPARATOR: &str = "::";
+
+const COLUMN_SEPARATOR: char = '│';
+const FASTEST_HEADER: &str = "fastest";
+const BRANCH: &str = "├─ ";
+const LAST_BRANCH: &str = "╰─ ";
+const TRUNK: &str = "│  ";
+const EMPTY: &str = "   ";
+
+impl Adaptable for AdapterRustDivan {
+    const ADAPTER: Adapter = Adapter::RustDivan;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        let average = settings.average.unwrap_or_default();
+
+        let mut benchmark_metrics = Vec::new();
+        let mut furthest_line = FurthestLine::default();
+        // The path from the benchmark binary down to the current tree node
+        let mut path: Vec<&str> = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            // `fibonacci      fastest       │ slowest       │ median        │ mean          │ samples │ iters`
+            if let Some(root) = parse_divan_header(line) {
+                path = vec![root];
+                continue;
+            }
+            if path.is_empty() {
+                continue;
+            }
+            let Some((depth, row)) = parse_divan_tree(line) else {
+                continue;
+            };
+            let (name, times) = match parse_divan_row(row) {
+                Ok(Some(node)) => node,
+                Ok(None) => continue,
+                Err(cell) => {
+                    furthest_line.track_column(index, value_column(line, cell));
+                    continue;
+                },
+            };
+            path.truncate(depth);
+            path.push(name);
+
+            // Generic types and arguments are nested under their benchmark,
+            // so only the leaf nodes have times.
+            if let Some(times) = times {
+                let benchmark_name = parse_benchmark_name(&path.join(DIVAN_NAME_SEPARATOR))
+                    .map_err(|e| {
+                        ParseError::new(Self::ADAPTER).with_reason(e).with_line(
+                            input,
+                            index + 1,
+                            None,
+                        )
+                    })?;
+                benchmark_metrics.push((benchmark_name, times.into_metric(average)));
+            }
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
+    }
+}
+
+fn parse_divan_header(line: &str) -> Option<&str> {
+    let (first_column, _) = line.split_once(COLUMN_SEPARATOR)?;
+    let root = first_column.trim_end().strip_suffix(FASTEST_HEADER)?.trim();
+    (!root.is_empty() && !root.starts_with(BRANCH) && !root.starts_with(LAST_BRANCH))
+        .then_some(root)
+}
+
+// Returns the depth of the tree node and the rest of the row.
+// Rows without a branch, such as counter rows, are skipped.
+fn parse_divan_tree(mut line: &str) -> Option<(usize, &str)> {
+    let mut depth = 1;
+    loop {
+        if let Some(row) = line
+            .strip_prefix(BRANCH)
+            .or_else(|| line.strip_prefix(LAST_BRANCH))
+        {
+            return Some((depth, row));
+        }
+        line = line
+            .strip_prefix(TRUNK)
+            .or_else(|| line.strip_prefix(EMPTY))?;
+        depth += 1;
+    }
+}
+
+// `5            9.87 ns       │ 30.12 ns      │ 10.11 ns      │ 11.5 ns       │ 100     │ 51200`
+// Returns the offending cell if the row has a fastest time but not the other times.
+fn parse_divan_row(row: &str) -> Result<Option<(&str, Option<DivanTimes>)>, &str> {
+    let mut columns = row.split(COLUMN_SEPARATOR).map(str::trim);
+    let Some(first_column) = columns.next() else {
+        return Ok(None);
+    };
+    let Some((name, fastest)) = split_divan_time(first_column) else {
+        // A parent node only has a name
+        return Ok((!first_column.is_empty()).then_some((first_column, None)));
+    };
+    let mut next_time = || {
+        // A missing column is reported at the end of the row
+        let cell = columns.next().unwrap_or_default();
+        parse_divan_time(cell).ok_or(cell)
+    };
+    let slowest = next_time()?;
+    let median = next_time()?;
+    let mean = next_time()?;
+    Ok(Some((
+        name,
+        Some(DivanTimes {
+            fastest,
+            slowest,
+            median,
+            mean,
+        }),
+    )))
+}
+
+// The name is left aligned and the fastest time is the last two words
+fn split_divan_time(first_column: &str) -> Option<(&str, OrderedFloat<f64>)> {
+    let (rest, units) = first_column.rsplit_once(char::is_whitespace)?;
+    let (name, time) = rest.trim_end().rsplit_once(char::is_whitespace)?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let time = parse_divan_time(&format!("{time} {units}"))?;
+    Some((name, time))
+}
+
+// `1.113 ns`, `10.2 µs`, or `1.5 s`
+fn parse_divan_time(input: &str) -> Option<OrderedFloat<f64>> {
+    let (time, units) = input.split_once(' ')?;
+    let time = time.parse::<f64>().ok()?;
+    let units = units.trim().parse::<Units>().ok()?;
+    Some(latency_as_nanos(time, units))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DivanTimes {
+    fastest: OrderedFloat<f64>,
+    slowest: OrderedFloat<f64>,
+    median: OrderedFloat<f64>,
+    mean: OrderedFloat<f64>,
+}
+
+impl DivanTimes {
+    fn into_metric(self, average: JsonAverage) -> JsonNewMetric {
+        let value = match average {
+            JsonAverage::Mean => self.mean,
+            JsonAverage::Median => self.median,
+        };
+        JsonNewMetric {
+            value,
+            lower_value: Some(self.fastest),
+            upper_value: Some(self.slowest),
+            samples: None,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_rust_divan {
+    use bencher_json::project::report::JsonAverage;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, opt_convert_file_path, parse_error_file_path, validate_latency,
+            validate_parse_line,
+        },
+        AdapterResults, Settings,
+    };
+
+    use super::AdapterRustDivan;
+
+    fn convert_rust_divan(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/rust/divan/{suffix}.txt");
+        convert_file_path::<AdapterRustDivan>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_tree() {
+        let results = convert_rust_divan("tree");
+        validate_adapter_rust_divan(&results);
+    }
+
+    pub fn validate_adapter_rust_divan(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 7);
+
+        let metrics = results.get("example::add").unwrap();
+        validate_latency(metrics, 0.75, Some(0.5), Some(1.25));
+
+        let metrics = results.get("example::fibonacci::0").unwrap();
+        validate_latency(metrics, 1.5, Some(1.125), Some(3.25));
+
+        let metrics = results.get("example::fibonacci::5").unwrap();
+        validate_latency(metrics, 11.5, Some(9.875), Some(30.125));
+
+        let metrics = results.get("example::fibonacci::10").unwrap();
+        validate_latency(metrics, 105.5, Some(97.25), Some(250.0));
+
+        let metrics = results.get("example::sort::Vec<i32>::10").unwrap();
+        validate_latency(metrics, 52.5, Some(40.5), Some(120.25));
+
+        let metrics = results.get("example::sort::Vec<i32>::1000").unwrap();
+        validate_latency(metrics, 12_500.0, Some(10_250.0), Some(20_500.0));
+
+        let metrics = results.get("example::sort::String::10").unwrap();
+        validate_latency(metrics, 1_500_000.0, Some(1_250_000.0), Some(2_500_000.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_median() {
+        let results = opt_convert_file_path::<AdapterRustDivan>(
+            "./tool_output/rust/divan/tree.txt",
+            Settings::new(Some(JsonAverage::Median)),
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 7);
+
+        let metrics = results.get("example::add").unwrap();
+        validate_latency(metrics, 0.625, Some(0.5), Some(1.25));
+
+        let metrics = results.get("example::sort::String::10").unwrap();
+        validate_latency(metrics, 1_375_000.0, Some(1_250_000.0), Some(2_500_000.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_parse_error() {
+        let err = parse_error_file_path::<AdapterRustDivan>("./tool_output/rust/divan/invalid.txt");
+        validate_parse_line(
+            &err,
+            4,
+            Some(31),
+            "╰─ add        0.5 ns        │ 1.2.5 ns      │ 0.625 ns      │ 0.75 ns       │ 10",
+        );
+    }
+}
@@ -4,7 +4,7 @@ use bencher_json::{
             ESTIMATED_CYCLES_NAME_STR, INSTRUCTIONS_NAME_STR, L1_ACCESSES_NAME_STR,
             L2_ACCESSES_NAME_STR, RAM_ACCESSES_NAME_STR,
         },
-        report::JsonAverage,
+        report::{Adapter, JsonAverage},
     },
     BenchmarkName, JsonNewMetric,
 };
@@ -18,9 +18,9 @@ use nom::{
 };
 
 use crate::{
-    adapters::util::{parse_f64, parse_u64},
+    adapters::util::{parse_f64, parse_u64, FurthestLine},
     results::adapter_results::{AdapterResults, IaiMeasure},
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterRustIai;
@@ -28,15 +28,24 @@ pub struct AdapterRustIai;
 const IAI_METRICS_LINE_COUNT: usize = 6;
 
 impl Adaptable for AdapterRustIai {
+    const ADAPTER: Adapter = Adapter::RustIai;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             None => {},
-            Some(JsonAverage::Mean | JsonAverage::Median) => return None,
+            Some(JsonAverage::Mean | JsonAverage::Median) => {
+                return Err(ParseError::new(Self::ADAPTER))
+            },
         }
 
         let mut benchmark_metrics = Vec::new();
+        let mut furthest_line = FurthestLine::default();
         let lines = input.lines().collect::<Vec<_>>();
-        for lines in lines.windows(IAI_METRICS_LINE_COUNT) {
+        for (index, lines) in lines.windows(IAI_METRICS_LINE_COUNT).enumerate() {
             let Ok(lines) = lines.try_into() else {
                 debug_assert!(
                     false,
@@ -44,17 +53,24 @@ impl Adaptable for AdapterRustIai {
                 );
                 continue;
             };
-            if let Some((benchmark_name, metrics)) = parse_iai_lines(lines) {
+            if let Some((benchmark_name, metrics)) =
+                parse_iai_lines(index, lines, &mut furthest_line)
+            {
                 benchmark_metrics.push((benchmark_name, metrics));
             }
         }
 
         AdapterResults::new_iai(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
     }
 }
 
+// The index is that of the benchmark name line.
+// Only the first metric line that fails to parse is tracked.
 fn parse_iai_lines(
+    index: usize,
     lines: [&str; IAI_METRICS_LINE_COUNT],
+    furthest_line: &mut FurthestLine,
 ) -> Option<(BenchmarkName, Vec<IaiMeasure>)> {
     let [benchmark_name_line, instructions_line, l1_accesses_line, l2_accesses_line, ram_accesses_line, estimated_cycles_line] =
         lines;
@@ -89,8 +105,11 @@ fn parse_iai_lines(
         ),
     ]
     .into_iter()
-    .map(|(measure, input, into_variant)| {
-        parse_iai_metric(input, measure).map(|(_remainder, json_metric)| into_variant(json_metric))
+    .enumerate()
+    .map(|(offset, (measure, input, into_variant))| {
+        let result = parse_iai_metric(input, measure);
+        furthest_line.track(index + offset + 1, input, &result);
+        result.map(|(_remainder, json_metric)| into_variant(json_metric))
     })
     .collect::<Result<Vec<_>, _>>()
     .ok()?;
@@ -133,6 +152,7 @@ fn parse_iai_metric<'a>(input: &'a str, measure: &'static str) -> IResult<&'a st
             value: (metric as f64).into(),
             lower_value: None,
             upper_value: None,
+            samples: None,
         },
     )(input)
 }
@@ -141,7 +161,8 @@ fn parse_iai_metric<'a>(input: &'a str, measure: &'static str) -> IResult<&'a st
 pub(crate) mod test_rust_iai {
 
     use crate::{
-        adapters::test_util::convert_file_path, results::adapter_metrics::AdapterMetrics,
+        adapters::test_util::{convert_file_path, parse_error_file_path, validate_parse_line},
+        results::adapter_metrics::AdapterMetrics,
         Adaptable, AdapterResults,
     };
     use bencher_json::{
@@ -180,7 +201,8 @@ pub(crate) mod test_rust_iai {
                 JsonNewMetric {
                     value: 1234.0.into(),
                     upper_value: None,
-                    lower_value: None
+                    lower_value: None,
+                    samples: None,
                 }
             ))
         );
@@ -192,7 +214,8 @@ pub(crate) mod test_rust_iai {
                 JsonNewMetric {
                     value: 1234.0.into(),
                     upper_value: None,
-                    lower_value: None
+                    lower_value: None,
+                    samples: None,
                 }
             ))
         );
@@ -204,7 +227,8 @@ pub(crate) mod test_rust_iai {
                 JsonNewMetric {
                     value: 1234.0.into(),
                     upper_value: None,
-                    lower_value: None
+                    lower_value: None,
+                    samples: None,
                 }
             ))
         );
@@ -283,4 +307,10 @@ pub(crate) mod test_rust_iai {
             ],
         );
     }
+
+    #[test]
+    fn test_adapter_rust_iai_parse_error() {
+        let err = parse_error_file_path::<AdapterRustIai>("./tool_output/rust/iai/invalid.txt");
+        validate_parse_line(&err, 6, Some(35), "L2 Accesses:                   1x");
+    }
 }
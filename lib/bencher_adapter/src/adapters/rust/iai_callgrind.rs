@@ -1,7 +1,7 @@
 use bencher_json::{
     project::{
         measure::{ESTIMATED_CYCLES_NAME_STR, INSTRUCTIONS_NAME_STR},
-        report::JsonAverage,
+        report::{Adapter, JsonAverage},
     },
     BenchmarkName, JsonNewMetric,
 };
@@ -15,9 +15,12 @@ use nom::{
 };
 
 use crate::{
-    adapters::util::{parse_f64, parse_u64},
+    adapters::{
+        rust::iai_callgrind_summary::IaiCallgrindSummaries,
+        util::{parse_f64, parse_u64, FurthestLine},
+    },
     results::adapter_results::{AdapterResults, IaiCallgrindMeasure},
-    Adaptable, Settings,
+    Adaptable, ParseError, Settings,
 };
 
 pub struct AdapterRustIaiCallgrind;
@@ -29,17 +32,40 @@ const RAM_HITS_NAME_STR: &str = "RAM Hits";
 const TOTAL_READ_WRITE_NAME_STR: &str = "Total read+write";
 
 impl Adaptable for AdapterRustIaiCallgrind {
+    const ADAPTER: Adapter = Adapter::RustIaiCallgrind;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match settings.average {
             None => {},
-            Some(JsonAverage::Mean | JsonAverage::Median) => return None,
+            Some(JsonAverage::Mean | JsonAverage::Median) => {
+                return Err(ParseError::new(Self::ADAPTER))
+            },
+        }
+        // The JSON summaries from `--save-summary=json`
+        match input.parse::<IaiCallgrindSummaries>() {
+            Ok(summaries) => {
+                return summaries
+                    .convert()
+                    .map_err(|e| ParseError::new(Self::ADAPTER).with_reason(e))?
+                    .ok_or_else(|| ParseError::new(Self::ADAPTER));
+            },
+            // Only report the JSON error if the output looks like JSON
+            Err(e) if input.trim_start().starts_with('{') => {
+                return Err(ParseError::json(Self::ADAPTER, input, &e));
+            },
+            Err(_) => {},
         }
         // Clean up the input by removing ANSI escape codes.
         let input = strip_ansi_escapes::strip_str(input);
 
         let mut benchmark_metrics = Vec::new();
+        let mut furthest_line = FurthestLine::default();
         let lines = input.lines().collect::<Vec<_>>();
-        for lines in lines.windows(IAI_CALLGRIND_METRICS_LINE_COUNT) {
+        for (index, lines) in lines.windows(IAI_CALLGRIND_METRICS_LINE_COUNT).enumerate() {
             let Ok(lines) = lines.try_into() else {
                 debug_assert!(
                     false,
@@ -47,17 +73,24 @@ impl Adaptable for AdapterRustIaiCallgrind {
                 );
                 continue;
             };
-            if let Some((benchmark_name, metrics)) = parse_iai_lines(lines) {
+            if let Some((benchmark_name, metrics)) =
+                parse_iai_lines(index, lines, &mut furthest_line)
+            {
                 benchmark_metrics.push((benchmark_name, metrics));
             }
         }
 
         AdapterResults::new_iai_callgrind(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, &input))
     }
 }
 
+// The index is that of the benchmark name line.
+// Only the first metric line that fails to parse is tracked.
 fn parse_iai_lines(
+    index: usize,
     lines: [&str; IAI_CALLGRIND_METRICS_LINE_COUNT],
+    furthest_line: &mut FurthestLine,
 ) -> Option<(BenchmarkName, Vec<IaiCallgrindMeasure>)> {
     let [benchmark_name_line, instructions_line, l1_accesses_line, l2_accesses_line, ram_accesses_line, total_read_write_line, estimated_cycles_line] =
         lines;
@@ -97,9 +130,11 @@ fn parse_iai_lines(
         ),
     ]
     .into_iter()
-    .map(|(measure, input, into_variant)| {
-        parse_iai_callgrind_metric(input, measure)
-            .map(|(_remainder, json_metric)| into_variant(json_metric))
+    .enumerate()
+    .map(|(offset, (measure, input, into_variant))| {
+        let result = parse_iai_callgrind_metric(input, measure);
+        furthest_line.track(index + offset + 1, input, &result);
+        result.map(|(_remainder, json_metric)| into_variant(json_metric))
     })
     .collect::<Result<Vec<_>, _>>()
     .ok()?;
@@ -157,6 +192,7 @@ fn parse_iai_callgrind_metric<'a>(
             value: (metric as f64).into(),
             lower_value: None,
             upper_value: None,
+            samples: None,
         },
     )(input)
 }
@@ -165,7 +201,10 @@ fn parse_iai_callgrind_metric<'a>(
 pub(crate) mod test_rust_iai_callgrind {
 
     use crate::{
-        adapters::test_util::convert_file_path, results::adapter_metrics::AdapterMetrics,
+        adapters::test_util::{
+            convert_file_path, parse_error_file_path, validate_measure, validate_parse_line,
+        },
+        results::adapter_metrics::AdapterMetrics,
         Adaptable, AdapterResults,
     };
     use bencher_json::{
@@ -208,7 +247,8 @@ pub(crate) mod test_rust_iai_callgrind {
                 JsonNewMetric {
                     value: 1234.0.into(),
                     upper_value: None,
-                    lower_value: None
+                    lower_value: None,
+                    samples: None,
                 }
             ))
         );
@@ -223,7 +263,8 @@ pub(crate) mod test_rust_iai_callgrind {
                 JsonNewMetric {
                     value: 1234.0.into(),
                     upper_value: None,
-                    lower_value: None
+                    lower_value: None,
+                    samples: None,
                 }
             ))
         );
@@ -238,7 +279,8 @@ pub(crate) mod test_rust_iai_callgrind {
                 JsonNewMetric {
                     value: 1234.0.into(),
                     upper_value: None,
-                    lower_value: None
+                    lower_value: None,
+                    samples: None,
                 }
             ))
         );
@@ -331,4 +373,80 @@ pub(crate) mod test_rust_iai_callgrind {
             ],
         );
     }
+
+    #[test]
+    fn test_adapter_rust_iai_callgrind_summary() {
+        let results = convert_file_path::<AdapterRustIaiCallgrind>(
+            "./tool_output/rust/iai_callgrind/summary.json",
+        );
+        validate_adapter_rust_iai_callgrind_summary(&results);
+    }
+
+    pub fn validate_adapter_rust_iai_callgrind_summary(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results
+            .get("rust_iai_callgrind::bench_fibonacci_group::bench_fibonacci short:10")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 19);
+        for (key, value) in [
+            (INSTRUCTIONS_SLUG_STR, 1734.0),
+            (L1_ACCESSES_SLUG_STR, 2359.0),
+            (L2_ACCESSES_SLUG_STR, 0.0),
+            (RAM_ACCESSES_SLUG_STR, 3.0),
+            (TOTAL_ACCESSES_SLUG_STR, 2362.0),
+            (ESTIMATED_CYCLES_SLUG_STR, 2464.0),
+            // Every other Callgrind event
+            ("Dr", 432.0),
+            ("Dw", 196.0),
+            ("I1mr", 2.0),
+            ("D1mr", 1.0),
+            ("D1mw", 0.0),
+            ("ILmr", 2.0),
+            ("DLmr", 1.0),
+            ("DLmw", 0.0),
+            // DHAT
+            ("DHAT Total bytes", 2048.0),
+            ("DHAT Total blocks", 12.0),
+            ("DHAT At t-gmax bytes", 1024.0),
+            ("DHAT Reads bytes", 4096.0),
+            ("DHAT Writes bytes", 512.0),
+        ] {
+            validate_measure(metrics, key, value, None, None);
+        }
+
+        let metrics = results
+            .get("rust_iai_callgrind::bench_fibonacci_group::bench_fibonacci long:30")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, "Cachegrind Ir", 26_214_734.0, None, None);
+        validate_measure(metrics, "Cachegrind Dr", 8_738_240.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_rust_iai_callgrind_parse_error() {
+        let err = parse_error_file_path::<AdapterRustIaiCallgrind>(
+            "./tool_output/rust/iai_callgrind/invalid.txt",
+        );
+        validate_parse_line(
+            &err,
+            6,
+            Some(37),
+            "Total read+write:            2362|N/A             (********)",
+        );
+    }
+
+    #[test]
+    fn test_adapter_rust_iai_callgrind_summary_parse_error() {
+        let err = parse_error_file_path::<AdapterRustIaiCallgrind>(
+            "./tool_output/rust/iai_callgrind/summary_invalid.json",
+        );
+        validate_parse_line(
+            &err,
+            12,
+            Some(11),
+            r#""EstimatedCycles": { "new": 2464, "old": null, "diff": null }"#,
+        );
+        assert_eq!(err.reason.as_deref(), Some("expected `,` or `}`"));
+    }
 }
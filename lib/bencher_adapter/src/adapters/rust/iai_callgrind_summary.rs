@@ -0,0 +1,223 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use bencher_json::{BenchmarkName, JsonNewMetric, MeasureNameId};
+use serde::Deserialize;
+
+use crate::{
+    results::adapter_results::{
+        AdapterResults, ESTIMATED_CYCLES_NAME_ID, INSTRUCTIONS_NAME_ID, L1_ACCESSES_NAME_ID,
+        L2_ACCESSES_NAME_ID, RAM_ACCESSES_NAME_ID, TOTAL_ACCESSES_NAME_ID,
+    },
+    AdapterError,
+};
+
+const CACHEGRIND_PREFIX: &str = "Cachegrind";
+
+/// The machine-readable output that `iai-callgrind` saves for each benchmark
+/// with `--save-summary=json`, in `target/iai/<module_path>/<id>/summary.json`.
+/// Several summaries may be concatenated together.
+#[derive(Debug, Clone, Default)]
+pub struct IaiCallgrindSummaries {
+    pub summaries: Vec<IaiCallgrindSummary>,
+}
+
+// https://github.com/iai-callgrind/iai-callgrind/tree/main/iai-callgrind-runner/schemas
+#[derive(Debug, Clone, Deserialize)]
+pub struct IaiCallgrindSummary {
+    pub module_path: String,
+    pub id: Option<String>,
+    pub details: Option<String>,
+    pub callgrind_summary: Option<ToolRunSummary>,
+    pub cachegrind_summary: Option<ToolRunSummary>,
+    #[serde(default)]
+    pub tool_summaries: Vec<ToolSummary>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolRunSummary {
+    #[serde(alias = "callgrind_run", alias = "cachegrind_run")]
+    pub run: ToolRun,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolRun {
+    pub total: ToolTotal,
+}
+
+// The total of all segments, such as any subprocesses or threads
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolTotal {
+    #[serde(alias = "summary")]
+    pub events: BTreeMap<String, EventMetrics>,
+}
+
+// Newer versions have the `Left` side of the `metrics`,
+// while older versions have the `new` value directly.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+pub enum EventMetrics {
+    Metrics { metrics: EitherOrBoth },
+    New { new: Option<f64> },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum EitherOrBoth {
+    Left(f64),
+    Right(f64),
+    Both(f64, f64),
+}
+
+// The summaries of any other Valgrind tools, such as DHAT
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolSummary {
+    pub tool: String,
+    #[serde(default)]
+    pub summaries: Vec<ToolRunFields>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolRunFields {
+    #[serde(default)]
+    pub summary: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromStr for IaiCallgrindSummaries {
+    type Err = serde_json::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let summaries = serde_json::Deserializer::from_str(input)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { summaries })
+    }
+}
+
+impl IaiCallgrindSummaries {
+    pub fn convert(self) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::with_capacity(self.summaries.len());
+        for summary in self.summaries {
+            benchmark_metrics.push(summary.into_metrics()?);
+        }
+        Ok(AdapterResults::new_measures(benchmark_metrics))
+    }
+}
+
+impl IaiCallgrindSummary {
+    fn into_metrics(
+        self,
+    ) -> Result<(BenchmarkName, Vec<(MeasureNameId, JsonNewMetric)>), AdapterError> {
+        let Self {
+            module_path,
+            id,
+            details,
+            callgrind_summary,
+            cachegrind_summary,
+            tool_summaries,
+        } = self;
+
+        // Match the benchmark name from the terminal output, ie `module::path id:details`
+        let mut benchmark_name = module_path;
+        if let Some(id) = id {
+            benchmark_name.push(' ');
+            benchmark_name.push_str(&id);
+            if let Some(details) = details {
+                benchmark_name.push(':');
+                benchmark_name.push_str(&details);
+            }
+        }
+        let benchmark_name = benchmark_name.parse()?;
+
+        let mut metrics = Vec::new();
+        if let Some(callgrind_summary) = callgrind_summary {
+            for (event_kind, value) in callgrind_summary.events() {
+                metrics.push((callgrind_measure(&event_kind)?, extra_metric(value)));
+            }
+        }
+        if let Some(cachegrind_summary) = cachegrind_summary {
+            for (event_kind, value) in cachegrind_summary.events() {
+                let measure = format!("{CACHEGRIND_PREFIX} {event_kind}").parse()?;
+                metrics.push((measure, extra_metric(value)));
+            }
+        }
+        for tool_summary in tool_summaries {
+            for (field, value) in tool_summary.fields() {
+                let measure = format!("{} {field}", tool_summary.tool).parse()?;
+                metrics.push((measure, extra_metric(value)));
+            }
+        }
+
+        Ok((benchmark_name, metrics))
+    }
+}
+
+impl ToolRunSummary {
+    fn events(self) -> impl Iterator<Item = (String, f64)> {
+        self.run
+            .total
+            .events
+            .into_iter()
+            .filter_map(|(event_kind, metrics)| Some((event_kind, metrics.new_value()?)))
+    }
+}
+
+impl EventMetrics {
+    fn new_value(self) -> Option<f64> {
+        match self {
+            Self::New { new } => new,
+            Self::Metrics {
+                metrics: EitherOrBoth::Left(new) | EitherOrBoth::Both(new, _),
+            } => Some(new),
+            Self::Metrics {
+                metrics: EitherOrBoth::Right(_),
+            } => None,
+        }
+    }
+}
+
+impl ToolSummary {
+    // Only numeric fields can be used as measures,
+    // and they are summed across all of the processes.
+    fn fields(&self) -> BTreeMap<&str, f64> {
+        let mut fields = BTreeMap::new();
+        for run in &self.summaries {
+            for (field, value) in &run.summary {
+                let value = match value {
+                    serde_json::Value::Number(number) => number.as_f64(),
+                    // `2,048`
+                    serde_json::Value::String(string) => string.replace(',', "").parse().ok(),
+                    serde_json::Value::Null
+                    | serde_json::Value::Bool(_)
+                    | serde_json::Value::Array(_)
+                    | serde_json::Value::Object(_) => None,
+                };
+                if let Some(value) = value {
+                    *fields.entry(field.as_str()).or_default() += value;
+                }
+            }
+        }
+        fields
+    }
+}
+
+// The events that are shown in the terminal output use the built-in measures,
+// and all other events use the Callgrind event kind as the measure name.
+fn callgrind_measure(event_kind: &str) -> Result<MeasureNameId, AdapterError> {
+    Ok(match event_kind {
+        "Ir" => INSTRUCTIONS_NAME_ID.clone(),
+        "L1hits" => L1_ACCESSES_NAME_ID.clone(),
+        "LLhits" => L2_ACCESSES_NAME_ID.clone(),
+        "RamHits" => RAM_ACCESSES_NAME_ID.clone(),
+        "TotalRW" => TOTAL_ACCESSES_NAME_ID.clone(),
+        "EstimatedCycles" => ESTIMATED_CYCLES_NAME_ID.clone(),
+        _ => event_kind.parse()?,
+    })
+}
+
+fn extra_metric(value: f64) -> JsonNewMetric {
+    JsonNewMetric {
+        value: value.into(),
+        lower_value: None,
+        upper_value: None,
+        samples: None,
+    }
+}
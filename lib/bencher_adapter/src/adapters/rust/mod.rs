@@ -1,22 +1,43 @@
 pub mod bench;
 pub mod criterion;
+pub mod criterion_estimates;
+pub mod divan;
 pub mod iai;
 pub mod iai_callgrind;
+pub mod iai_callgrind_summary;
 
 use self::{
-    criterion::AdapterRustCriterion, iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind,
+    criterion::AdapterRustCriterion, divan::AdapterRustDivan, iai::AdapterRustIai,
+    iai_callgrind::AdapterRustIaiCallgrind,
+};
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
 };
-use crate::{Adaptable, AdapterResults, Settings};
 use bench::AdapterRustBench;
+use bencher_json::project::report::Adapter;
 
 pub struct AdapterRust;
 
 impl Adaptable for AdapterRust {
+    const ADAPTER: Adapter = Adapter::Rust;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterRustBench::parse(input, settings)
-            .or_else(|| AdapterRustCriterion::parse(input, settings))
-            .or_else(|| AdapterRustIai::parse(input, settings))
-            .or_else(|| AdapterRustIaiCallgrind::parse(input, settings))
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[
+                AdapterRustBench::try_parse,
+                AdapterRustCriterion::try_parse,
+                AdapterRustIai::try_parse,
+                AdapterRustIaiCallgrind::try_parse,
+                AdapterRustDivan::try_parse,
+            ],
+        )
     }
 }
 
@@ -25,8 +46,8 @@ mod test_rust {
     use super::AdapterRust;
     use crate::adapters::{
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
-            iai_callgrind::test_rust_iai_callgrind,
+            bench::test_rust_bench, criterion::test_rust_criterion, divan::test_rust_divan,
+            iai::test_rust_iai, iai_callgrind::test_rust_iai_callgrind,
         },
         test_util::convert_file_path,
     };
@@ -54,4 +75,17 @@ mod test_rust {
         let results = convert_file_path::<AdapterRust>("./tool_output/rust/iai_callgrind/two.txt");
         test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind(&results);
     }
+
+    #[test]
+    fn test_adapter_rust_iai_callgrind_summary() {
+        let results =
+            convert_file_path::<AdapterRust>("./tool_output/rust/iai_callgrind/summary.json");
+        test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind_summary(&results);
+    }
+
+    #[test]
+    fn test_adapter_rust_divan() {
+        let results = convert_file_path::<AdapterRust>("./tool_output/rust/divan/tree.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
 }
@@ -1,21 +1,33 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonNewMetric};
+use std::collections::BTreeMap;
+
+use bencher_json::{
+    project::report::{Adapter, JsonAverage},
+    BenchmarkName, JsonNewMetric,
+};
 use ordered_float::OrderedFloat;
 use serde::Deserialize;
 
 use crate::{
-    adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
-    Adaptable, AdapterError, Settings,
+    adapters::util::{benchmark_parameters, latency_as_nanos, try_parse_json, Units},
+    results::adapter_results::{
+        AdapterResults, LATENCY_NAME_ID, SYSTEM_TIME_NAME_ID, USER_TIME_NAME_ID,
+    },
+    Adaptable, AdapterError, ParseError, Settings,
 };
 
 pub struct AdapterShellHyperfine;
 
 impl Adaptable for AdapterShellHyperfine {
+    const ADAPTER: Adapter = Adapter::ShellHyperfine;
+
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        serde_json::from_str::<Hyperfine>(input)
-            .ok()?
-            .convert(settings)
-            .ok()?
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_json(Self::ADAPTER, input, |hyperfine: Hyperfine| {
+            hyperfine.convert(&settings)
+        })
     }
 }
 
@@ -38,13 +50,15 @@ pub struct HyperfineResult {
     pub min: OrderedFloat<f64>,
     pub max: OrderedFloat<f64>,
     pub times: Option<Vec<f64>>,
+    // An exit code is missing if the command was terminated by a signal
     pub exit_codes: Vec<Option<i32>>,
-    pub parameters: Option<JsonAny>,
+    // The values from `--parameter-scan` or `--parameter-list`
+    #[serde(default)]
+    pub parameters: BTreeMap<String, String>,
 }
 
 impl Hyperfine {
-    #[allow(clippy::unnecessary_wraps)]
-    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+    fn convert(self, settings: &Settings) -> Result<Option<AdapterResults>, AdapterError> {
         let results = self.results;
         let mut benchmark_metrics = Vec::with_capacity(results.len());
         for result in results {
@@ -53,11 +67,31 @@ impl Hyperfine {
                 mean,
                 stddev,
                 median,
+                user,
+                system,
                 min,
                 max,
-                ..
+                times,
+                exit_codes,
+                parameters,
             } = result;
 
+            // Runs with a failed command must not be ingested as if they had succeeded
+            if let Some(exit_code) = exit_codes
+                .into_iter()
+                .find(|exit_code| *exit_code != Some(0))
+            {
+                let exit_code =
+                    exit_code.map_or_else(|| "signal".to_owned(), |code| code.to_string());
+                return Err(AdapterError::ExitCode(command, exit_code));
+            }
+
+            let benchmark_name = if let Some(parameters) = benchmark_parameters(parameters) {
+                format!("{command} {parameters}").parse()?
+            } else {
+                command
+            };
+
             // JSON output is always in seconds
             let units = Units::Sec;
             let (average, spread) = match settings.average.unwrap_or_default() {
@@ -71,16 +105,36 @@ impl Hyperfine {
                     Some(latency_as_nanos(upper, units)),
                 )
             });
+            let samples = times.map(|times| {
+                times
+                    .into_iter()
+                    .map(|time| latency_as_nanos(time, units))
+                    .collect()
+            });
             let json_metric = JsonNewMetric {
                 value,
                 lower_value,
                 upper_value,
+                samples,
+            };
+
+            // The user and system CPU times are always the mean
+            let cpu_time_metric = |time: f64| JsonNewMetric {
+                value: latency_as_nanos(time, units),
+                lower_value: None,
+                upper_value: None,
+                samples: None,
             };
+            let metrics = vec![
+                (LATENCY_NAME_ID.clone(), json_metric),
+                (USER_TIME_NAME_ID.clone(), cpu_time_metric(user)),
+                (SYSTEM_TIME_NAME_ID.clone(), cpu_time_metric(system)),
+            ];
 
-            benchmark_metrics.push((command, json_metric));
+            benchmark_metrics.push((benchmark_name, metrics));
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        Ok(AdapterResults::new_measures(benchmark_metrics))
     }
 }
 
@@ -91,12 +145,27 @@ pub(crate) mod test_shell_hyperfine {
 
     use crate::{
         adapters::test_util::{
-            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_measure,
         },
-        AdapterResults, Settings,
+        results::adapter_metrics::AdapterMetrics,
+        Adaptable, AdapterError, AdapterResults, Settings,
     };
 
-    use super::AdapterShellHyperfine;
+    use super::{AdapterShellHyperfine, Hyperfine};
+
+    // The user and system CPU times are in nanoseconds
+    fn validate_hyperfine(
+        metrics: &AdapterMetrics,
+        latency: (f64, Option<f64>, Option<f64>),
+        user_time: f64,
+        system_time: f64,
+    ) {
+        assert_eq!(metrics.inner.len(), 3);
+        let (value, lower_value, upper_value) = latency;
+        validate_measure(metrics, "latency", value, lower_value, upper_value);
+        validate_measure(metrics, "user-time", user_time, None, None);
+        validate_measure(metrics, "system-time", system_time, None, None);
+    }
 
     fn convert_shell_hyperfine(suffix: &str) -> AdapterResults {
         let file_path = file_path(suffix);
@@ -122,9 +191,7 @@ pub(crate) mod test_shell_hyperfine {
 
         let results = opt_convert_file_path::<AdapterShellHyperfine>(
             &file_path,
-            Settings {
-                average: Some(JsonAverage::Mean),
-            },
+            Settings::new(Some(JsonAverage::Mean)),
         )
         .unwrap();
         validate_adapter_shell_hyperfine(&results);
@@ -137,19 +204,27 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("sleep 0.1").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            107_534_464.423_703_72,
-            Some(104_316_587.308_651_45),
-            Some(110_752_341.538_755_98),
+            (
+                107_534_464.423_703_72,
+                Some(104_316_587.308_651_45),
+                Some(110_752_341.538_755_98),
+            ),
+            717_496.296_296_296_4,
+            1_350_884.444_444_444_5,
         );
 
         let metrics = results.get("sleep 0.2").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            208_513_999.104_615_43,
-            Some(204_785_557.656_151_62),
-            Some(212_242_440.553_079_25),
+            (
+                208_513_999.104_615_43,
+                Some(204_785_557.656_151_62),
+                Some(212_242_440.553_079_25),
+            ),
+            1_013_969.230_769_231,
+            2_086_132.307_692_307_5,
         );
     }
 
@@ -157,19 +232,19 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("sleep 0.1").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            106_525_351.72,
-            Some(102_474_685.72),
-            Some(115_336_892.72),
+            (106_525_351.72, Some(102_474_685.72), Some(115_336_892.72)),
+            717_496.296_296_296_4,
+            1_350_884.444_444_444_5,
         );
 
         let metrics = results.get("sleep 0.2").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            208_661_518.72,
-            Some(201_824_142.72),
-            Some(214_128_684.72),
+            (208_661_518.72, Some(201_824_142.72), Some(214_128_684.72)),
+            1_013_969.230_769_231,
+            2_086_132.307_692_307_5,
         );
     }
 
@@ -179,11 +254,15 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 1);
 
         let metrics = results.get("sleep 0.01").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            13_317_239.025_420_565,
-            Some(12_317_546.734_914_13),
-            Some(14_316_931.315_926_999),
+            (
+                13_317_239.025_420_565,
+                Some(12_317_546.734_914_13),
+                Some(14_316_931.315_926_999),
+            ),
+            343_610.467_289_719_73,
+            601_688.598_130_841_9,
         );
     }
 
@@ -193,11 +272,63 @@ pub(crate) mod test_shell_hyperfine {
         assert_eq!(results.inner.len(), 1);
 
         let metrics = results.get("sleep 0.01").unwrap();
-        validate_latency(
+        validate_hyperfine(
             metrics,
-            13_251_329.96,
-            Some(10_165_892.459_999_999),
-            Some(21_347_058.459_999_997),
+            (
+                13_251_329.96,
+                Some(10_165_892.459_999_999),
+                Some(21_347_058.459_999_997),
+            ),
+            343_610.467_289_719_73,
+            601_688.598_130_841_9,
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_hyperfine_parameters() {
+        let results = convert_shell_hyperfine("parameters");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("sleep 0.1 (delay: 0.1)").unwrap();
+        validate_hyperfine(
+            metrics,
+            (125_000_000.0, Some(117_187_500.0), Some(132_812_500.0)),
+            625_000.0,
+            1_250_000.0,
+        );
+
+        let metrics = results.get("sleep 0.2 (delay: 0.2)").unwrap();
+        validate_hyperfine(
+            metrics,
+            (250_000_000.0, Some(234_375_000.0), Some(265_625_000.0)),
+            750_000.0,
+            1_500_000.0,
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_hyperfine_failed() {
+        let contents = std::fs::read_to_string(file_path("failed")).unwrap();
+        let hyperfine = serde_json::from_str::<Hyperfine>(&contents).unwrap();
+        let err = hyperfine.convert(&Settings::default()).unwrap_err();
+        assert!(
+            matches!(&err, AdapterError::ExitCode(command, exit_code) if command.as_ref() == "./flaky.sh" && exit_code == "1"),
+            "{err}"
+        );
+
+        assert_eq!(
+            None,
+            opt_convert_file_path::<AdapterShellHyperfine>(
+                &file_path("failed"),
+                Settings::default()
+            )
+        );
+
+        // The failed exit code is reported instead of silently ingesting the results
+        let err = AdapterShellHyperfine::try_parse(&contents, Settings::default()).unwrap_err();
+        assert_eq!(
+            err.reason.as_deref(),
+            Some("Benchmark (./flaky.sh) failed with exit code: 1")
         );
     }
 }
@@ -0,0 +1,436 @@
+use bencher_json::{project::report::Adapter, BenchmarkName, JsonNewMetric, MeasureNameId};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1, take_until},
+    character::complete::{space0, space1},
+    combinator::{map, opt},
+    sequence::{delimited, tuple},
+    IResult,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::util::{
+        latency_as_nanos, parse_benchmark_name, parse_number_as_f64, value_column, FurthestLine,
+        Units,
+    },
+    results::adapter_results::{AdapterResults, INSTRUCTIONS_NAME_ID, LATENCY_NAME_ID},
+    Adaptable, ParseError, Settings,
+};
+
+pub struct AdapterShellPerfStat;
+
+// The CSV output (`-x,`) does not include the command,
+// so all of its events are reported under this benchmark name.
+pub const PERF_STAT_BENCHMARK_NAME: &str = "perf stat";
+
+const NOT_COUNTED: &str = "<not counted>";
+const NOT_SUPPORTED: &str = "<not supported>";
+const INSTRUCTIONS_EVENT: &str = "instructions";
+
+impl Adaptable for AdapterShellPerfStat {
+    const ADAPTER: Adapter = Adapter::ShellPerfStat;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        parse_perf_stat_human(input).or_else(|human_error| {
+            parse_perf_stat_csv(input).map_err(|csv_error| {
+                // Only report the CSV error if the output is not in the human-readable format
+                if input
+                    .lines()
+                    .any(|line| parse_perf_stat_header(line).is_ok())
+                {
+                    human_error
+                } else {
+                    csv_error
+                }
+            })
+        })
+    }
+}
+
+type PerfStatMetrics = Vec<(MeasureNameId, JsonNewMetric)>;
+
+// https://man7.org/linux/man-pages/man1/perf-stat.1.html
+fn parse_perf_stat_human(input: &str) -> Result<AdapterResults, ParseError> {
+    let mut benchmark_metrics: Vec<(BenchmarkName, PerfStatMetrics)> = Vec::new();
+    let mut furthest_line = FurthestLine::default();
+    // The first command that is not a valid benchmark name, in case no other block is valid
+    let mut invalid_header = None;
+    let mut skip_block = false;
+    for (index, line) in input.lines().enumerate() {
+        if let Ok((_, command)) = parse_perf_stat_header(line) {
+            // Skip the whole block, so its events are not added to the previous block
+            match parse_benchmark_name(command) {
+                Ok(benchmark_name) => {
+                    benchmark_metrics.push((benchmark_name, Vec::new()));
+                    skip_block = false;
+                },
+                Err(e) => {
+                    invalid_header.get_or_insert_with(|| {
+                        ParseError::new(AdapterShellPerfStat::ADAPTER)
+                            .with_reason(e)
+                            .with_line(input, index + 1, None)
+                    });
+                    skip_block = true;
+                },
+            }
+            continue;
+        }
+        if skip_block {
+            continue;
+        }
+        let Some((_, metrics)) = benchmark_metrics.last_mut() else {
+            continue;
+        };
+        let elapsed = parse_perf_stat_elapsed(line);
+        let event = parse_perf_stat_event(line);
+        if let Ok((_, metric)) = elapsed {
+            metrics.push((LATENCY_NAME_ID.clone(), metric));
+        } else if let Ok((_, Some(metric))) = event {
+            metrics.push(metric);
+        } else if event.is_err() {
+            furthest_line.track(index, line, &elapsed);
+            furthest_line.track(index, line, &event);
+        }
+    }
+    benchmark_metrics.retain(|(_, metrics)| !metrics.is_empty());
+
+    AdapterResults::new_measures(benchmark_metrics).ok_or_else(|| {
+        invalid_header
+            .unwrap_or_else(|| furthest_line.into_error(AdapterShellPerfStat::ADAPTER, input))
+    })
+}
+
+fn parse_perf_stat_header(input: &str) -> IResult<&str, &str> {
+    map(
+        tuple((
+            space0,
+            tag("Performance counter stats for "),
+            delimited(tag("'"), take_until("'"), tag("'")),
+        )),
+        |(_, _, command)| command,
+    )(input)
+}
+
+// `1.25000 +- 0.00250 seconds time elapsed  ( +-  0.20% )`
+fn parse_perf_stat_elapsed(input: &str) -> IResult<&str, JsonNewMetric> {
+    map(
+        tuple((
+            space0,
+            parse_number_as_f64,
+            opt(tuple((space1, tag("+-"), space1, parse_number_as_f64))),
+            space1,
+            tag("seconds time elapsed"),
+        )),
+        |(_, value, spread, _, _)| {
+            let units = Units::Sec;
+            let spread = spread.map(|(_, _, _, spread)| spread);
+            JsonNewMetric {
+                value: latency_as_nanos(value, units),
+                lower_value: spread.map(|spread| latency_as_nanos(value - spread, units)),
+                upper_value: spread.map(|spread| latency_as_nanos(value + spread, units)),
+                samples: None,
+            }
+        },
+    )(input)
+}
+
+// `4,000,000,000      cycles      #    3.240 GHz      ( +-  0.05% )`
+fn parse_perf_stat_event(input: &str) -> IResult<&str, Option<(MeasureNameId, JsonNewMetric)>> {
+    map(
+        tuple((
+            space0,
+            alt((
+                map(parse_number_as_f64, Some),
+                map(alt((tag(NOT_COUNTED), tag(NOT_SUPPORTED))), |_| None),
+            )),
+            opt(tuple((space1, parse_time_units))),
+            space1,
+            take_till1(char::is_whitespace),
+        )),
+        |(_, value, units, _, event)| {
+            let value = value?;
+            let variance = input
+                .rsplit_once("+-")
+                .and_then(|(_, variance)| variance.trim().strip_suffix(')'))
+                .and_then(|variance| variance.trim().strip_suffix('%'))
+                .and_then(|variance| variance.parse().ok());
+            perf_stat_metric(event, value, units.map(|(_, units)| units), variance)
+        },
+    )(input)
+}
+
+fn parse_time_units(input: &str) -> IResult<&str, Units> {
+    alt((
+        map(tag("nsec"), |_| Units::Nano),
+        map(tag("usec"), |_| Units::Micro),
+        map(tag("msec"), |_| Units::Milli),
+        map(tag("sec"), |_| Units::Sec),
+    ))(input)
+}
+
+// https://man7.org/linux/man-pages/man1/perf-stat.1.html#CSV_FORMAT
+// counter-value,unit,event-name,[variance,]counter-run-time,percentage-of-measurement-time,...
+fn parse_perf_stat_csv(input: &str) -> Result<AdapterResults, ParseError> {
+    let csv_error = |index: usize, column: Option<usize>, reason: &str| {
+        ParseError::new(AdapterShellPerfStat::ADAPTER)
+            .with_reason(reason)
+            .with_line(input, index + 1, column)
+    };
+
+    let mut metrics = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let fields = trimmed.split(',').collect::<Vec<_>>();
+        let [value, units, event, variance_or_run_time, ..] = fields.as_slice() else {
+            return Err(csv_error(index, None, "Expected at least five fields"));
+        };
+        // Every line must have at least the counter run time and percentage fields
+        if fields.len() < 5 {
+            return Err(csv_error(index, None, "Expected at least five fields"));
+        } else if event.is_empty() {
+            return Err(csv_error(index, None, "Missing event name"));
+        }
+        let value = match *value {
+            NOT_COUNTED | NOT_SUPPORTED => continue,
+            value => value.parse::<f64>().map_err(|e| {
+                csv_error(
+                    index,
+                    Some(value_column(line, value) + 1),
+                    &format!("Invalid counter value: {e}"),
+                )
+            })?,
+        };
+        // Only time units need to be converted, all other units are kept as is
+        let units = parse_time_units(units).ok().map(|(_, units)| units);
+        let variance = variance_or_run_time
+            .strip_suffix('%')
+            .and_then(|variance| variance.parse().ok());
+        if let Some(metric) = perf_stat_metric(event, value, units, variance) {
+            metrics.push(metric);
+        }
+    }
+
+    if metrics.is_empty() {
+        return Err(ParseError::new(AdapterShellPerfStat::ADAPTER));
+    }
+
+    PERF_STAT_BENCHMARK_NAME
+        .parse()
+        .ok()
+        .and_then(|benchmark_name| AdapterResults::new_measures(vec![(benchmark_name, metrics)]))
+        .ok_or_else(|| ParseError::new(AdapterShellPerfStat::ADAPTER))
+}
+
+fn perf_stat_metric(
+    event: &str,
+    value: f64,
+    units: Option<Units>,
+    variance: Option<f64>,
+) -> Option<(MeasureNameId, JsonNewMetric)> {
+    let measure = if event == INSTRUCTIONS_EVENT {
+        INSTRUCTIONS_NAME_ID.clone()
+    } else {
+        event.parse().ok()?
+    };
+    let as_value = |value: f64| -> OrderedFloat<f64> {
+        if let Some(units) = units {
+            latency_as_nanos(value, units)
+        } else {
+            value.into()
+        }
+    };
+    // The variance is the relative standard deviation as a percentage
+    let spread = variance.map(|variance| value * variance / 100.0);
+    let json_metric = JsonNewMetric {
+        value: as_value(value),
+        lower_value: spread.map(|spread| as_value(value - spread)),
+        upper_value: spread.map(|spread| as_value(value + spread)),
+        samples: None,
+    };
+    Some((measure, json_metric))
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_perf_stat {
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, parse_error_file_path, validate_parse_line},
+        results::adapter_metrics::AdapterMetrics,
+        AdapterResults,
+    };
+
+    use super::{AdapterShellPerfStat, PERF_STAT_BENCHMARK_NAME};
+
+    fn convert_shell_perf_stat(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/shell/perf_stat/{suffix}.txt");
+        convert_file_path::<AdapterShellPerfStat>(&file_path)
+    }
+
+    fn validate_perf_stat_metric(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        bounds: Option<(f64, f64)>,
+    ) {
+        let metric = metrics.get(key).unwrap();
+        assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+        assert_eq!(
+            metric.lower_value,
+            bounds.map(|(lower, _)| OrderedFloat::from(lower)),
+            "{key}"
+        );
+        assert_eq!(
+            metric.upper_value,
+            bounds.map(|(_, upper)| OrderedFloat::from(upper)),
+            "{key}"
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_repeat() {
+        let results = convert_shell_perf_stat("repeat");
+        validate_adapter_shell_perf_stat(&results);
+    }
+
+    pub fn validate_adapter_shell_perf_stat(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("./fib 30").unwrap();
+        assert_eq!(metrics.inner.len(), 10);
+        validate_perf_stat_metric(
+            metrics,
+            "latency",
+            1_250_000_000.0,
+            Some((1_247_500_000.0, 1_252_500_000.0)),
+        );
+        validate_perf_stat_metric(
+            metrics,
+            "task-clock",
+            1_234_500_000.0,
+            Some((1_232_031_000.0, 1_236_969_000.0)),
+        );
+        validate_perf_stat_metric(metrics, "context-switches", 12.0, Some((10.8, 13.2)));
+        validate_perf_stat_metric(metrics, "cpu-migrations", 0.0, None);
+        validate_perf_stat_metric(
+            metrics,
+            "cycles",
+            4_000_000_000.0,
+            Some((3_998_000_000.0, 4_002_000_000.0)),
+        );
+        validate_perf_stat_metric(
+            metrics,
+            "instructions",
+            8_000_000_000.0,
+            Some((7_999_200_000.0, 8_000_800_000.0)),
+        );
+        validate_perf_stat_metric(
+            metrics,
+            "branch-misses",
+            2_000_000.0,
+            Some((1_980_000.0, 2_020_000.0)),
+        );
+        validate_perf_stat_metric(
+            metrics,
+            "cache-misses",
+            400_000.0,
+            Some((390_000.0, 410_000.0)),
+        );
+        assert_eq!(metrics.get("L1-dcache-prefetches"), None);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_single() {
+        let results = convert_shell_perf_stat("single");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("sleep 0.1").unwrap();
+        assert_eq!(metrics.inner.len(), 6);
+        validate_perf_stat_metric(metrics, "latency", 101_234_567.0, None);
+        validate_perf_stat_metric(metrics, "task-clock", 520_000.0, None);
+        validate_perf_stat_metric(metrics, "cycles", 1_234_567.0, None);
+        assert_eq!(metrics.get("instructions"), None);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_oversized() {
+        let results = convert_shell_perf_stat("oversized");
+        assert_eq!(results.inner.len(), 1);
+
+        // The events of the oversized command are not added to any other block
+        let metrics = results.get("sleep 0.1").unwrap();
+        assert_eq!(metrics.inner.len(), 6);
+        validate_perf_stat_metric(metrics, "latency", 101_234_567.0, None);
+        validate_perf_stat_metric(metrics, "task-clock", 520_000.0, None);
+        validate_perf_stat_metric(metrics, "cycles", 1_234_567.0, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_csv() {
+        let results = convert_shell_perf_stat("csv");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get(PERF_STAT_BENCHMARK_NAME).unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_perf_stat_metric(metrics, "task-clock", 1_234_500_000.0, None);
+        validate_perf_stat_metric(metrics, "context-switches", 12.0, None);
+        validate_perf_stat_metric(metrics, "cycles", 4_000_000_000.0, None);
+        validate_perf_stat_metric(metrics, "instructions", 8_000_000_000.0, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_csv_repeat() {
+        let results = convert_shell_perf_stat("csv_repeat");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get(PERF_STAT_BENCHMARK_NAME).unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_perf_stat_metric(
+            metrics,
+            "cycles",
+            4_000_000_000.0,
+            Some((3_998_000_000.0, 4_002_000_000.0)),
+        );
+        validate_perf_stat_metric(
+            metrics,
+            "cache-misses",
+            400_000.0,
+            Some((390_000.0, 410_000.0)),
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_parse_error() {
+        let err = parse_error_file_path::<AdapterShellPerfStat>(
+            "./tool_output/shell/perf_stat/invalid.txt",
+        );
+        validate_parse_line(
+            &err,
+            7,
+            Some(11),
+            "1.2x5 +- 0.00250 seconds time elapsed  ( +-  0.20% )",
+        );
+    }
+
+    #[test]
+    fn test_adapter_shell_perf_stat_csv_parse_error() {
+        let err = parse_error_file_path::<AdapterShellPerfStat>(
+            "./tool_output/shell/perf_stat/csv_invalid.txt",
+        );
+        validate_parse_line(
+            &err,
+            2,
+            Some(1),
+            "4000000x000,,cycles,1234560000,100.00,3.240,GHz",
+        );
+    }
+}
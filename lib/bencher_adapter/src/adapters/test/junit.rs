@@ -0,0 +1,184 @@
+use bencher_json::{project::report::Adapter, BenchmarkName, JsonNewMetric};
+use serde::{de::IgnoredAny, Deserialize};
+
+use crate::{
+    adapters::util::{latency_as_nanos, try_parse_xml, Units},
+    results::adapter_results::AdapterResults,
+    Adaptable, AdapterError, ParseError, Settings,
+};
+
+pub struct AdapterTestJunit;
+
+impl Adaptable for AdapterTestJunit {
+    const ADAPTER: Adapter = Adapter::TestJunit;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_xml(Self::ADAPTER, input, TestSuite::convert)
+    }
+}
+
+pub const CLASSNAME_SEPARATOR: &str = "::";
+
+// The root element may be either `<testsuites>` or a single `<testsuite>`,
+// and test suites may be nested.
+// https://github.com/testmoapp/junitxml
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestSuite {
+    #[serde(rename = "testsuite", default)]
+    pub test_suites: Vec<TestSuite>,
+    #[serde(rename = "testcase", default)]
+    pub test_cases: Vec<TestCase>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestCase {
+    #[serde(rename = "@classname")]
+    pub classname: Option<String>,
+    #[serde(rename = "@name")]
+    pub name: String,
+    // The duration of the test case in seconds
+    #[serde(rename = "@time")]
+    pub time: Option<f64>,
+    pub failure: Option<IgnoredAny>,
+    pub error: Option<IgnoredAny>,
+    pub skipped: Option<IgnoredAny>,
+}
+
+impl TestSuite {
+    fn convert(self) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::new();
+        self.push_metrics(&mut benchmark_metrics)?;
+        Ok(AdapterResults::new_latency(benchmark_metrics))
+    }
+
+    fn push_metrics(
+        self,
+        benchmark_metrics: &mut Vec<(BenchmarkName, JsonNewMetric)>,
+    ) -> Result<(), AdapterError> {
+        let Self {
+            test_suites,
+            test_cases,
+        } = self;
+        for test_case in test_cases {
+            if let Some(benchmark_metric) = test_case.into_metric()? {
+                benchmark_metrics.push(benchmark_metric);
+            }
+        }
+        for test_suite in test_suites {
+            test_suite.push_metrics(benchmark_metrics)?;
+        }
+        Ok(())
+    }
+}
+
+impl TestCase {
+    // Failed, errored, and skipped test cases are excluded from the results.
+    fn into_metric(self) -> Result<Option<(BenchmarkName, JsonNewMetric)>, AdapterError> {
+        let Self {
+            classname,
+            name,
+            time,
+            failure,
+            error,
+            skipped,
+        } = self;
+        let Some(time) = time else {
+            return Ok(None);
+        };
+        if failure.is_some() || error.is_some() || skipped.is_some() {
+            return Ok(None);
+        }
+
+        let benchmark_name = match classname {
+            Some(classname) if !classname.is_empty() => {
+                format!("{classname}{CLASSNAME_SEPARATOR}{name}")
+            },
+            _ => name,
+        }
+        .parse()?;
+        let json_metric = JsonNewMetric {
+            value: latency_as_nanos(time, Units::Sec),
+            lower_value: None,
+            upper_value: None,
+            samples: None,
+        };
+        Ok(Some((benchmark_name, json_metric)))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_test_junit {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, parse_error_file_path, validate_latency, validate_parse_line,
+        },
+        AdapterResults,
+    };
+
+    use super::AdapterTestJunit;
+
+    fn convert_test_junit(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/test/junit/{suffix}.xml");
+        convert_file_path::<AdapterTestJunit>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_test_junit_pytest() {
+        let results = convert_test_junit("pytest");
+        validate_adapter_test_junit(&results);
+    }
+
+    pub fn validate_adapter_test_junit(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("tests.test_math::test_add").unwrap();
+        validate_latency(metrics, 12_000_000.0, None, None);
+
+        let metrics = results.get("tests.test_math::test_fib[10]").unwrap();
+        validate_latency(metrics, 250_000_000.0, None, None);
+
+        let metrics = results.get("tests.test_math::test_fib[20]").unwrap();
+        validate_latency(metrics, 1_250_000_000.0, None, None);
+
+        assert_eq!(results.get("tests.test_math::test_div"), None);
+        assert_eq!(results.get("tests.test_io::test_read"), None);
+        assert_eq!(results.get("tests.test_io::test_write"), None);
+    }
+
+    #[test]
+    fn test_adapter_test_junit_surefire() {
+        let results = convert_test_junit("surefire");
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results
+            .get("com.example.ParserTest::parsesEmptyInput")
+            .unwrap();
+        validate_latency(metrics, 5_000_000.0, None, None);
+
+        let metrics = results
+            .get("com.example.ParserTest::parsesLargeInput")
+            .unwrap();
+        validate_latency(metrics, 2_400_000_000.0, None, None);
+
+        let metrics = results
+            .get("com.example.ParserTest::parsesUnicode")
+            .unwrap();
+        validate_latency(metrics, 95_000_000.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_test_junit_parse_error() {
+        let err = parse_error_file_path::<AdapterTestJunit>("./tool_output/test/junit/invalid.xml");
+        validate_parse_line(&err, 6, Some(3), "</testsuite>");
+        assert_eq!(
+            err.to_string(),
+            "test_junit: Expecting </testcase> found </testsuite> (line 6, column 3: `</testsuite>`)"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+pub mod junit;
+pub mod tap;
+
+use crate::{
+    adapters::util::try_parse_candidates, Adaptable, AdapterResults, ParseError, Settings,
+};
+use bencher_json::project::report::Adapter;
+use junit::AdapterTestJunit;
+use tap::AdapterTestTap;
+
+pub struct AdapterTest;
+
+impl Adaptable for AdapterTest {
+    const ADAPTER: Adapter = Adapter::Test;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        try_parse_candidates(
+            Self::ADAPTER,
+            input,
+            &settings,
+            &[AdapterTestJunit::try_parse, AdapterTestTap::try_parse],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_test {
+    use super::AdapterTest;
+    use crate::adapters::{
+        test::{junit::test_test_junit, tap::test_test_tap},
+        test_util::convert_file_path,
+    };
+
+    #[test]
+    fn test_adapter_test_junit() {
+        let results = convert_file_path::<AdapterTest>("./tool_output/test/junit/pytest.xml");
+        test_test_junit::validate_adapter_test_junit(&results);
+    }
+
+    #[test]
+    fn test_adapter_test_tap() {
+        let results = convert_file_path::<AdapterTest>("./tool_output/test/tap/node.txt");
+        test_test_tap::validate_adapter_test_tap(&results);
+    }
+}
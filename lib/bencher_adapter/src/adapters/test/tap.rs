@@ -0,0 +1,200 @@
+use bencher_json::{project::report::Adapter, JsonNewMetric};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, space0, space1},
+    combinator::{map, opt, rest},
+    sequence::tuple,
+    IResult,
+};
+use serde::Deserialize;
+
+use crate::{
+    adapters::{
+        test::junit::CLASSNAME_SEPARATOR,
+        util::{latency_as_nanos, parse_benchmark_name, FurthestLine, Units},
+    },
+    results::adapter_results::AdapterResults,
+    Adaptable, ParseError, Settings,
+};
+
+pub struct AdapterTestTap;
+
+impl Adaptable for AdapterTestTap {
+    const ADAPTER: Adapter = Adapter::TestTap;
+
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        Self::try_parse(input, settings).ok()
+    }
+
+    fn try_parse(input: &str, _settings: Settings) -> Result<AdapterResults, ParseError> {
+        let mut benchmark_metrics = Vec::new();
+        let mut furthest_line = FurthestLine::default();
+        // The names of the enclosing subtests, along with their indentation
+        let mut subtests: Vec<(usize, &str)> = Vec::new();
+        let mut lines = input.lines().enumerate().peekable();
+        while let Some((index, line)) = lines.next() {
+            let indent = line.len() - line.trim_start().len();
+
+            if let Some(subtest) = line.trim().strip_prefix("# Subtest: ") {
+                subtests.retain(|(subtest_indent, _)| *subtest_indent < indent);
+                subtests.push((indent, subtest.trim()));
+                continue;
+            }
+
+            let result = parse_tap_test_point(line.trim_start());
+            furthest_line.track(index, line, &result);
+            let Ok((_, test_point)) = result else {
+                continue;
+            };
+            // A test point closes its own subtest and any nested subtests
+            subtests.retain(|(subtest_indent, _)| *subtest_indent < indent);
+
+            // https://testanything.org/tap-version-13-specification.html#yaml-blocks
+            let mut yaml_block = None;
+            if lines.peek().map(|(_, line)| line.trim()) == Some("---") {
+                lines.next();
+                let mut yaml_lines = Vec::new();
+                for (_, line) in lines.by_ref() {
+                    if line.trim() == "..." {
+                        break;
+                    }
+                    yaml_lines.push(line.get(indent..).unwrap_or(line.trim_start()));
+                }
+                yaml_block = Some(yaml_lines.join("\n"));
+            }
+
+            // Failed and skipped test points are excluded from the results.
+            if !test_point.ok || test_point.skip {
+                continue;
+            }
+            let yaml_block = match yaml_block
+                .map(|yaml_block| serde_yaml::from_str::<TapYamlBlock>(&yaml_block))
+                .transpose()
+            {
+                Ok(yaml_block) => yaml_block,
+                Err(e) => {
+                    // The YAML block starts on the line after both the test point and its `---` marker
+                    if let Some(location) = e.location() {
+                        furthest_line.track_column(
+                            index + 1 + location.line(),
+                            indent + location.column().saturating_sub(1),
+                        );
+                    }
+                    continue;
+                },
+            };
+            let Some(duration_ms) = yaml_block.and_then(|yaml_block| yaml_block.duration_ms) else {
+                continue;
+            };
+
+            let benchmark_name = subtests
+                .iter()
+                .map(|(_, subtest)| *subtest)
+                .chain(std::iter::once(test_point.description))
+                .collect::<Vec<_>>()
+                .join(CLASSNAME_SEPARATOR);
+            let benchmark_name = parse_benchmark_name(&benchmark_name).map_err(|e| {
+                ParseError::new(Self::ADAPTER)
+                    .with_reason(e)
+                    .with_line(input, index + 1, None)
+            })?;
+            let json_metric = JsonNewMetric {
+                value: latency_as_nanos(duration_ms, Units::Milli),
+                lower_value: None,
+                upper_value: None,
+                samples: None,
+            };
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+            .ok_or_else(|| furthest_line.into_error(Self::ADAPTER, input))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TapYamlBlock {
+    duration_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TapTestPoint<'a> {
+    ok: bool,
+    description: &'a str,
+    skip: bool,
+}
+
+// `ok 1 - description # SKIP reason`
+fn parse_tap_test_point(input: &str) -> IResult<&str, TapTestPoint> {
+    map(
+        tuple((
+            alt((map(tag("ok"), |_| true), map(tag("not ok"), |_| false))),
+            space1,
+            digit1,
+            opt(tuple((space1, tag("-")))),
+            space0,
+            rest,
+        )),
+        |(ok, _, _, _, _, description): (bool, _, _, _, _, &str)| {
+            let (description, directive) = description
+                .split_once(" # ")
+                .map_or((description, None), |(description, directive)| {
+                    (description, Some(directive))
+                });
+            let skip = directive.is_some_and(|directive| {
+                directive
+                    .get(..4)
+                    .is_some_and(|d| d.eq_ignore_ascii_case("skip"))
+            });
+            TapTestPoint {
+                ok,
+                description: description.trim(),
+                skip,
+            }
+        },
+    )(input)
+}
+
+#[cfg(test)]
+pub(crate) mod test_test_tap {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, parse_error_file_path, validate_latency, validate_parse_line,
+        },
+        AdapterResults,
+    };
+
+    use super::AdapterTestTap;
+
+    #[test]
+    fn test_adapter_test_tap_node() {
+        let results = convert_file_path::<AdapterTestTap>("./tool_output/test/tap/node.txt");
+        validate_adapter_test_tap(&results);
+    }
+
+    pub fn validate_adapter_test_tap(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("math::add").unwrap();
+        validate_latency(metrics, 500_000.0, None, None);
+
+        let metrics = results.get("math::fib").unwrap();
+        validate_latency(metrics, 12_250_000.0, None, None);
+
+        let metrics = results.get("parse").unwrap();
+        validate_latency(metrics, 3_125_000.0, None, None);
+
+        assert_eq!(results.get("math::div"), None);
+        assert_eq!(results.get("math::todo"), None);
+        assert_eq!(results.get("math"), None);
+    }
+
+    #[test]
+    fn test_adapter_test_tap_parse_error() {
+        let err = parse_error_file_path::<AdapterTestTap>("./tool_output/test/tap/invalid.txt");
+        validate_parse_line(&err, 6, Some(20), "duration_ms: 0.5ms");
+    }
+}
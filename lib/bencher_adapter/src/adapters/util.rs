@@ -1,6 +1,6 @@
 use std::{fmt, str::FromStr};
 
-use bencher_json::BenchmarkName;
+use bencher_json::{project::report::Adapter, BenchmarkName};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -14,11 +14,13 @@ use ordered_float::OrderedFloat;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{
-    de::{self, Visitor},
+    de::{self, DeserializeOwned, Visitor},
     Deserialize, Deserializer,
 };
 
-use crate::AdapterError;
+use crate::{AdapterError, AdapterResults, ParseError, Settings};
+
+pub type TryParse = fn(&str, Settings) -> Result<AdapterResults, ParseError>;
 
 pub type NomError = nom::Err<nom::error::Error<String>>;
 
@@ -29,6 +31,108 @@ where
     nom::Err::Error(nom::error::make_error(input.into(), NomErrorKind::Tag))
 }
 
+// Try each candidate adapter in order, keeping track of why every one of them failed
+pub fn try_parse_candidates(
+    adapter: Adapter,
+    input: &str,
+    settings: &Settings,
+    candidates: &[TryParse],
+) -> Result<AdapterResults, ParseError> {
+    let mut errors = Vec::with_capacity(candidates.len());
+    for try_parse in candidates {
+        match try_parse(input, settings.clone()) {
+            Ok(results) => return Ok(results),
+            Err(e) => errors.push(e),
+        }
+    }
+    Err(ParseError::candidates(adapter, errors))
+}
+
+pub fn try_parse_json<T, F>(
+    adapter: Adapter,
+    input: &str,
+    convert: F,
+) -> Result<AdapterResults, ParseError>
+where
+    T: DeserializeOwned,
+    F: FnOnce(T) -> Result<Option<AdapterResults>, AdapterError>,
+{
+    let json =
+        serde_json::from_str::<T>(input).map_err(|e| ParseError::json(adapter, input, &e))?;
+    convert(json)
+        .map_err(|e| ParseError::new(adapter).with_reason(e))?
+        .ok_or_else(|| ParseError::new(adapter))
+}
+
+pub fn try_parse_xml<T, F>(
+    adapter: Adapter,
+    input: &str,
+    convert: F,
+) -> Result<AdapterResults, ParseError>
+where
+    T: DeserializeOwned,
+    F: FnOnce(T) -> Result<Option<AdapterResults>, AdapterError>,
+{
+    let xml =
+        quick_xml::de::from_str::<T>(input).map_err(|e| ParseError::xml(adapter, input, &e))?;
+    convert(xml)
+        .map_err(|e| ParseError::new(adapter).with_reason(e))?
+        .ok_or_else(|| ParseError::new(adapter))
+}
+
+// The line where a line-by-line parser made the most progress before failing.
+// If no benchmark results are found, this is most likely the offending line.
+#[derive(Debug, Default)]
+pub struct FurthestLine {
+    // The zero-based line index and the number of characters parsed
+    furthest: Option<(usize, usize)>,
+}
+
+impl FurthestLine {
+    pub fn track<T>(&mut self, index: usize, line: &str, result: &IResult<&str, T>) {
+        let remainder = match result {
+            Ok(("", _)) => return,
+            Ok((remainder, _)) => *remainder,
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e.input,
+            Err(nom::Err::Incomplete(_)) => line,
+        };
+        if line.trim().is_empty() {
+            return;
+        }
+        // The remainder is always a suffix of the line
+        let parsed = line
+            .get(..line.len().saturating_sub(remainder.len()))
+            .map_or(0, |parsed| parsed.chars().count());
+        self.track_column(index, parsed);
+    }
+
+    // Track a line that was not parsed with `nom`, given the number of characters parsed
+    pub fn track_column(&mut self, index: usize, parsed: usize) {
+        if self
+            .furthest
+            .map_or(true, |(_, furthest)| parsed > furthest)
+        {
+            self.furthest = Some((index, parsed));
+        }
+    }
+
+    pub fn into_error(self, adapter: Adapter, input: &str) -> ParseError {
+        let error = ParseError::new(adapter);
+        if let Some((index, parsed)) = self.furthest {
+            error.with_line(input, index + 1, Some(parsed + 1))
+        } else {
+            error
+        }
+    }
+}
+
+// The number of characters before the last occurrence of the value in the line
+pub fn value_column(line: &str, value: &str) -> usize {
+    line.rfind(value)
+        .and_then(|position| line.get(..position))
+        .map_or(0, |before| before.chars().count())
+}
+
 pub fn latency_as_nanos<T>(time: T, units: Units) -> OrderedFloat<f64>
 where
     T: Into<Time>,
@@ -226,6 +330,24 @@ where
         .map_err(|_e| nom::Err::Error(nom::error::make_error("\0", NomErrorKind::Tag)))
 }
 
+// Sort the parameters by name so the benchmark name is stable, ie `(Count: 2, Size: 10)`.
+pub fn benchmark_parameters<I, K, V>(parameters: I) -> Option<String>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut parameters = parameters
+        .into_iter()
+        .map(|(name, value)| format!("{}: {}", name.as_ref(), value.as_ref()))
+        .collect::<Vec<_>>();
+    if parameters.is_empty() {
+        return None;
+    }
+    parameters.sort_unstable();
+    Some(format!("({})", parameters.join(", ")))
+}
+
 pub fn parse_benchmark_name_chars(name_chars: &[char]) -> Result<BenchmarkName, NomError> {
     let name: String = name_chars.iter().collect();
     parse_benchmark_name(&name)
@@ -1,3 +1,8 @@
+use std::fmt;
+
+use bencher_json::{project::report::Adapter, BenchmarkName};
+use quick_xml::events::Event;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,6 +11,185 @@ pub enum AdapterError {
     Valid(#[from] bencher_json::ValidError),
     #[error("Failed to parse benchmark units: {0}")]
     BenchmarkUnits(String),
-    #[error("Failed to convert results: {0}")]
-    Convert(String),
+    #[error("Failed to deserialize results: {0}")]
+    Deserialize(serde_json::Error),
+    #[error("Missing score percentiles for the median")]
+    ScorePercentiles,
+    #[error("Benchmark ({0}) failed with exit code: {1}")]
+    ExitCode(BenchmarkName, String),
+}
+
+// The maximum number of characters of the offending line to display
+const LINE_SNIPPET_LEN: usize = 80;
+// The number of characters to display before the offending column
+const LINE_SNIPPET_LEAD: usize = 40;
+
+/// Why an adapter failed to parse the harness output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseError {
+    pub adapter: Adapter,
+    pub reason: Option<String>,
+    pub line: Option<ParseLine>,
+    // Every leaf adapter that was attempted, if this adapter tries multiple candidates
+    pub candidates: Vec<ParseError>,
+}
+
+/// The location in the harness output where parsing stopped
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseLine {
+    // One-based line number
+    pub number: usize,
+    // One-based column number
+    pub column: Option<usize>,
+    pub content: String,
+}
+
+impl ParseError {
+    /// The error code used when returning a parse error from the API
+    pub const ERROR_CODE: &'static str = "adapter_parse_error";
+
+    pub fn new(adapter: Adapter) -> Self {
+        Self {
+            adapter,
+            reason: None,
+            line: None,
+            candidates: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_reason<R>(mut self, reason: R) -> Self
+    where
+        R: fmt::Display,
+    {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_line(mut self, input: &str, number: usize, column: Option<usize>) -> Self {
+        self.line = ParseLine::new(input, number, column);
+        self
+    }
+
+    pub fn json(adapter: Adapter, input: &str, err: &serde_json::Error) -> Self {
+        let column = (err.column() > 0).then_some(err.column());
+        // The location is displayed along with the offending line
+        let reason = err.to_string();
+        let location = format!(" at line {} column {}", err.line(), err.column());
+        Self::new(adapter)
+            .with_reason(reason.strip_suffix(&location).unwrap_or(&reason))
+            .with_line(input, err.line(), column)
+    }
+
+    pub fn xml(adapter: Adapter, input: &str, err: &quick_xml::DeError) -> Self {
+        let error = Self::new(adapter).with_reason(err);
+        // Only syntax errors have a location, which is found by reading the raw events
+        if let Some((number, column)) = xml_error_location(input) {
+            error.with_line(input, number, Some(column))
+        } else {
+            error
+        }
+    }
+
+    pub fn candidates<I>(adapter: Adapter, candidates: I) -> Self
+    where
+        I: IntoIterator<Item = ParseError>,
+    {
+        let mut error = Self::new(adapter);
+        for candidate in candidates {
+            // Flatten nested candidates so only the leaf adapters are listed
+            if candidate.candidates.is_empty() {
+                error.candidates.push(candidate);
+            } else {
+                error.candidates.extend(candidate.candidates);
+            }
+        }
+        error
+    }
+
+    fn fmt_attempt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.adapter)?;
+        if let Some(reason) = &self.reason {
+            write!(f, "{reason}")?;
+        } else {
+            write!(f, "No benchmark results found")?;
+        }
+        if let Some(line) = &self.line {
+            write!(f, " ({line})")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.candidates.is_empty() {
+            self.fmt_attempt(f)
+        } else {
+            write!(f, "None of the candidate adapters could parse the results:")?;
+            for candidate in &self.candidates {
+                write!(f, "\n  - ")?;
+                candidate.fmt_attempt(f)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// The one-based line and column of the first XML syntax error, if any
+fn xml_error_location(input: &str) -> Option<(usize, usize)> {
+    let mut reader = quick_xml::Reader::from_str(input);
+    let position = loop {
+        match reader.read_event() {
+            Ok(Event::Eof) => return None,
+            Ok(_) => {},
+            Err(_) => break reader.buffer_position(),
+        }
+    };
+    // Point to the start of the offending tag, as the reader stops partway through it
+    let position = input
+        .get(..position)
+        .and_then(|before| before.rfind('<'))
+        .unwrap_or(position);
+    let mut location = (1, 1);
+    for (index, c) in input.char_indices() {
+        if index >= position {
+            break;
+        }
+        location = if c == '\n' {
+            (location.0 + 1, 1)
+        } else {
+            (location.0, location.1 + 1)
+        };
+    }
+    Some(location)
+}
+
+impl ParseLine {
+    pub fn new(input: &str, number: usize, column: Option<usize>) -> Option<Self> {
+        let line = input.lines().nth(number.checked_sub(1)?)?;
+        // Keep the snippet centered on the offending column for long lines, such as minified JSON
+        let skip = column
+            .unwrap_or_default()
+            .saturating_sub(LINE_SNIPPET_LEAD + 1);
+        let content = line.chars().skip(skip).take(LINE_SNIPPET_LEN).collect();
+        Some(Self {
+            number,
+            column,
+            content,
+        })
+    }
+}
+
+impl fmt::Display for ParseLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}", self.number)?;
+        if let Some(column) = self.column {
+            write!(f, ", column {column}")?;
+        }
+        write!(f, ": `{}`", self.content.trim())
+    }
 }
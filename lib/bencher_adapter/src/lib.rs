@@ -5,76 +5,115 @@ pub mod results;
 use adapters::{
     c_sharp::{dot_net::AdapterCSharpDotNet, AdapterCSharp},
     cpp::{catch2::AdapterCppCatch2, google::AdapterCppGoogle, AdapterCpp},
+    csv::AdapterCsv,
     go::{bench::AdapterGoBench, AdapterGo},
     java::{jmh::AdapterJavaJmh, AdapterJava},
     js::{benchmark::AdapterJsBenchmark, time::AdapterJsTime, AdapterJs},
     json::AdapterJson,
+    load::{k6::AdapterLoadK6, vegeta::AdapterLoadVegeta, wrk::AdapterLoadWrk, AdapterLoad},
     magic::AdapterMagic,
+    open_metrics::AdapterOpenMetrics,
     python::{asv::AdapterPythonAsv, pytest::AdapterPythonPytest, AdapterPython},
     ruby::{benchmark::AdapterRubyBenchmark, AdapterRuby},
     rust::{
-        bench::AdapterRustBench, criterion::AdapterRustCriterion, iai::AdapterRustIai,
-        iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
+        bench::AdapterRustBench, criterion::AdapterRustCriterion, divan::AdapterRustDivan,
+        iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
     },
-    shell::{hyperfine::AdapterShellHyperfine, AdapterShell},
+    shell::{hyperfine::AdapterShellHyperfine, perf_stat::AdapterShellPerfStat, AdapterShell},
+    test::{junit::AdapterTestJunit, tap::AdapterTestTap, AdapterTest},
 };
-use bencher_json::project::report::{Adapter, JsonAverage};
+use bencher_json::project::report::{Adapter, JsonAverage, JsonCsvSettings};
 pub use bencher_json::{BenchmarkName, JsonNewMetric};
-pub use error::AdapterError;
+pub use error::{AdapterError, ParseError, ParseLine};
 pub use results::{adapter_results::AdapterResults, AdapterResultsArray};
 
 pub trait Adaptable {
-    fn convert(&self, input: &str, settings: Settings) -> Option<AdapterResults> {
-        Self::parse(input, settings)
+    const ADAPTER: Adapter;
+
+    /// Parse the input, reporting why it could not be parsed on failure
+    fn try_parse(input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
+        Self::parse(input, settings).ok_or_else(|| ParseError::new(Self::ADAPTER))
     }
 
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults>;
 }
 
-impl Adaptable for Adapter {
-    fn convert(&self, input: &str, settings: Settings) -> Option<AdapterResults> {
+/// Convert the harness output using the adapter it selects
+pub trait AdapterConvert {
+    fn convert(&self, input: &str, settings: Settings) -> Result<AdapterResults, ParseError>;
+}
+
+impl AdapterConvert for Adapter {
+    fn convert(&self, input: &str, settings: Settings) -> Result<AdapterResults, ParseError> {
         match self {
-            Adapter::Magic => AdapterMagic::parse(input, settings),
-            Adapter::Json => AdapterJson::parse(input, settings),
-            Adapter::CSharp => AdapterCSharp::parse(input, settings),
-            Adapter::CSharpDotNet => AdapterCSharpDotNet::parse(input, settings),
-            Adapter::Cpp => AdapterCpp::parse(input, settings),
-            Adapter::CppCatch2 => AdapterCppCatch2::parse(input, settings),
-            Adapter::CppGoogle => AdapterCppGoogle::parse(input, settings),
-            Adapter::Go => AdapterGo::parse(input, settings),
-            Adapter::GoBench => AdapterGoBench::parse(input, settings),
-            Adapter::Java => AdapterJava::parse(input, settings),
-            Adapter::JavaJmh => AdapterJavaJmh::parse(input, settings),
-            Adapter::Js => AdapterJs::parse(input, settings),
-            Adapter::JsBenchmark => AdapterJsBenchmark::parse(input, settings),
-            Adapter::JsTime => AdapterJsTime::parse(input, settings),
-            Adapter::Python => AdapterPython::parse(input, settings),
-            Adapter::PythonAsv => AdapterPythonAsv::parse(input, settings),
-            Adapter::PythonPytest => AdapterPythonPytest::parse(input, settings),
-            Adapter::Ruby => AdapterRuby::parse(input, settings),
-            Adapter::RubyBenchmark => AdapterRubyBenchmark::parse(input, settings),
-            Adapter::Rust => AdapterRust::parse(input, settings),
-            Adapter::RustBench => AdapterRustBench::parse(input, settings),
-            Adapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
-            Adapter::RustIai => AdapterRustIai::parse(input, settings),
-            Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::parse(input, settings),
-            Adapter::Shell => AdapterShell::parse(input, settings),
-            Adapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
+            Adapter::Magic => AdapterMagic::try_parse(input, settings),
+            Adapter::Json => AdapterJson::try_parse(input, settings),
+            Adapter::CSharp => AdapterCSharp::try_parse(input, settings),
+            Adapter::CSharpDotNet => AdapterCSharpDotNet::try_parse(input, settings),
+            Adapter::Cpp => AdapterCpp::try_parse(input, settings),
+            Adapter::CppCatch2 => AdapterCppCatch2::try_parse(input, settings),
+            Adapter::CppGoogle | Adapter::CppGoogleJson => {
+                AdapterCppGoogle::try_parse(input, settings)
+            },
+            Adapter::Go => AdapterGo::try_parse(input, settings),
+            Adapter::GoBench => AdapterGoBench::try_parse(input, settings),
+            Adapter::Java => AdapterJava::try_parse(input, settings),
+            Adapter::JavaJmh => AdapterJavaJmh::try_parse(input, settings),
+            Adapter::Js => AdapterJs::try_parse(input, settings),
+            Adapter::JsBenchmark => AdapterJsBenchmark::try_parse(input, settings),
+            Adapter::JsTime => AdapterJsTime::try_parse(input, settings),
+            Adapter::Python => AdapterPython::try_parse(input, settings),
+            Adapter::PythonAsv => AdapterPythonAsv::try_parse(input, settings),
+            Adapter::PythonPytest => AdapterPythonPytest::try_parse(input, settings),
+            Adapter::Ruby => AdapterRuby::try_parse(input, settings),
+            Adapter::RubyBenchmark => AdapterRubyBenchmark::try_parse(input, settings),
+            Adapter::Rust => AdapterRust::try_parse(input, settings),
+            Adapter::RustBench => AdapterRustBench::try_parse(input, settings),
+            Adapter::RustCriterion => AdapterRustCriterion::try_parse(input, settings),
+            Adapter::RustIai => AdapterRustIai::try_parse(input, settings),
+            Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::try_parse(input, settings),
+            Adapter::RustDivan => AdapterRustDivan::try_parse(input, settings),
+            Adapter::Shell => AdapterShell::try_parse(input, settings),
+            Adapter::ShellHyperfine => AdapterShellHyperfine::try_parse(input, settings),
+            Adapter::ShellPerfStat => AdapterShellPerfStat::try_parse(input, settings),
+            Adapter::Test => AdapterTest::try_parse(input, settings),
+            Adapter::TestJunit => AdapterTestJunit::try_parse(input, settings),
+            Adapter::TestTap => AdapterTestTap::try_parse(input, settings),
+            Adapter::Csv => AdapterCsv::try_parse(input, settings),
+            Adapter::OpenMetrics => AdapterOpenMetrics::try_parse(input, settings),
+            Adapter::Load => AdapterLoad::try_parse(input, settings),
+            Adapter::LoadK6 => AdapterLoadK6::try_parse(input, settings),
+            Adapter::LoadWrk => AdapterLoadWrk::try_parse(input, settings),
+            Adapter::LoadVegeta => AdapterLoadVegeta::try_parse(input, settings),
         }
     }
-
-    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        AdapterMagic::parse(input, settings)
-    }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Settings {
     pub average: Option<JsonAverage>,
+    pub csv: Option<JsonCsvSettings>,
+    pub go_strip_cpu: Option<bool>,
 }
 
 impl Settings {
     pub fn new(average: Option<JsonAverage>) -> Self {
-        Self { average }
+        Self {
+            average,
+            csv: None,
+            go_strip_cpu: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_csv(mut self, csv: Option<JsonCsvSettings>) -> Self {
+        self.csv = csv;
+        self
+    }
+
+    #[must_use]
+    pub fn with_go_strip_cpu(mut self, go_strip_cpu: Option<bool>) -> Self {
+        self.go_strip_cpu = go_strip_cpu;
+        self
     }
 }
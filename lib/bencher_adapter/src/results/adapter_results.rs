@@ -3,13 +3,17 @@ use std::{collections::HashMap, str::FromStr};
 use bencher_json::{
     project::{
         measure::{
-            ESTIMATED_CYCLES_SLUG_STR, INSTRUCTIONS_SLUG_STR, L1_ACCESSES_SLUG_STR,
-            L2_ACCESSES_SLUG_STR, LATENCY_SLUG_STR, RAM_ACCESSES_SLUG_STR, THROUGHPUT_SLUG_STR,
-            TOTAL_ACCESSES_SLUG_STR,
+            ALLOCATED_BYTES_SLUG_STR, ALLOCATIONS_SLUG_STR, ALLOCATION_RATE_SLUG_STR,
+            ERROR_RATE_SLUG_STR, ESTIMATED_CYCLES_SLUG_STR, GC_COUNT_SLUG_STR, GC_TIME_SLUG_STR,
+            GEN0_COLLECTIONS_SLUG_STR, GEN1_COLLECTIONS_SLUG_STR, GEN2_COLLECTIONS_SLUG_STR,
+            INSTRUCTIONS_SLUG_STR, L1_ACCESSES_SLUG_STR, L2_ACCESSES_SLUG_STR,
+            LATENCY_P50_SLUG_STR, LATENCY_P90_SLUG_STR, LATENCY_P99_SLUG_STR, LATENCY_SLUG_STR,
+            RAM_ACCESSES_SLUG_STR, SYSTEM_TIME_SLUG_STR, THROUGHPUT_SLUG_STR,
+            TOTAL_ACCESSES_SLUG_STR, USER_TIME_SLUG_STR,
         },
         metric::Mean,
     },
-    BenchmarkName, JsonNewMetric, NameId,
+    BenchmarkName, JsonNewMetric, MeasureNameId, NameId,
 };
 use literally::hmap;
 use once_cell::sync::Lazy;
@@ -51,6 +55,62 @@ pub static TOTAL_ACCESSES_NAME_ID: Lazy<NameId> =
 pub static ESTIMATED_CYCLES_NAME_ID: Lazy<NameId> =
     Lazy::new(|| ESTIMATED_CYCLES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
 
+#[allow(clippy::expect_used)]
+pub static ALLOCATIONS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ALLOCATED_BYTES_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATED_BYTES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static GEN0_COLLECTIONS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| GEN0_COLLECTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static GEN1_COLLECTIONS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| GEN1_COLLECTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static GEN2_COLLECTIONS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| GEN2_COLLECTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ALLOCATION_RATE_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATION_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static GC_COUNT_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| GC_COUNT_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static GC_TIME_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| GC_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static LATENCY_P50_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| LATENCY_P50_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static LATENCY_P90_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| LATENCY_P90_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static LATENCY_P99_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| LATENCY_P99_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ERROR_RATE_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ERROR_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static USER_TIME_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| USER_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static SYSTEM_TIME_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| SYSTEM_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AdapterResults {
     #[serde(flatten)]
@@ -140,6 +200,26 @@ impl AdapterResults {
         )
     }
 
+    pub fn new_measures(
+        benchmark_metrics: Vec<(BenchmarkName, Vec<(MeasureNameId, JsonNewMetric)>)>,
+    ) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for (measure, metric) in metrics {
+                metrics_value.inner.insert(measure, metric);
+            }
+        }
+
+        Some(results_map.into())
+    }
+
     pub fn new_iai(benchmark_metrics: Vec<(BenchmarkName, Vec<IaiMeasure>)>) -> Option<Self> {
         if benchmark_metrics.is_empty() {
             return None;
@@ -5,7 +5,7 @@ use bencher_json::project::{
     report::{Adapter, JsonFold},
 };
 
-use crate::{Adaptable, AdapterError, Settings};
+use crate::{AdapterConvert, ParseError, Settings};
 
 pub mod adapter_metrics;
 pub mod adapter_results;
@@ -31,13 +31,11 @@ impl AdapterResultsArray {
     pub fn new(
         results_array: &[&str],
         adapter: Adapter,
-        settings: Settings,
-    ) -> Result<Self, AdapterError> {
+        settings: &Settings,
+    ) -> Result<Self, ParseError> {
         let mut parsed_results_array = Vec::new();
         for &results in results_array {
-            let parsed_results = adapter
-                .convert(results, settings)
-                .ok_or_else(|| AdapterError::Convert((results).to_owned()))?;
+            let parsed_results = adapter.convert(results, settings.clone())?;
             parsed_results_array.push(parsed_results);
         }
         Ok(parsed_results_array.into())
@@ -0,0 +1,154 @@
+use std::cmp;
+
+use rand::{distributions::Uniform, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::quartiles::Quartiles;
+
+// The number of times to resample the historical data
+const RESAMPLES: usize = 1_000;
+// A fixed seed, so the same historical data always produces the same limits
+const SEED: u64 = 0x0062_656e_6368_6572;
+
+/// Bootstrap estimates of the percentiles of the historical data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bootstrap {
+    pub median: f64,
+    pub lower: Option<f64>,
+    pub upper: Option<f64>,
+}
+
+impl Bootstrap {
+    pub fn new(
+        data: &[f64],
+        lower_percentile: Option<f64>,
+        upper_percentile: Option<f64>,
+    ) -> Option<Self> {
+        Self::new_seeded(data, lower_percentile, upper_percentile, SEED)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn new_seeded(
+        data: &[f64],
+        lower_percentile: Option<f64>,
+        upper_percentile: Option<f64>,
+        seed: u64,
+    ) -> Option<Self> {
+        if data.is_empty() {
+            return None;
+        }
+
+        let mut sorted = data.to_vec();
+        sorted.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal));
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let uniform = Uniform::from(0..sorted.len());
+        let mut indexes = vec![0; sorted.len()];
+        let mut resample = Vec::with_capacity(sorted.len());
+        let mut median = 0.0;
+        let mut lower = lower_percentile.map(|_| 0.0);
+        let mut upper = upper_percentile.map(|_| 0.0);
+        for _ in 0..RESAMPLES {
+            for index in &mut indexes {
+                *index = rng.sample(uniform);
+            }
+            // Sorting the indexes into the sorted data also sorts the resample
+            indexes.sort_unstable();
+            resample.clear();
+            resample.extend(indexes.iter().filter_map(|index| sorted.get(*index)));
+
+            median += Quartiles::percentile_of_sorted(&resample, 0.5)?;
+            for (sum, percentile) in [
+                (&mut lower, lower_percentile),
+                (&mut upper, upper_percentile),
+            ] {
+                if let (Some(sum), Some(percentile)) = (sum, percentile) {
+                    *sum += Quartiles::percentile_of_sorted(&resample, percentile)?;
+                }
+            }
+        }
+
+        let resamples = RESAMPLES as f64;
+        let bootstrap = Self {
+            median: median / resamples,
+            lower: lower.map(|sum| sum / resamples),
+            upper: upper.map(|sum| sum / resamples),
+        };
+        [Some(bootstrap.median), bootstrap.lower, bootstrap.upper]
+            .into_iter()
+            .flatten()
+            .all(f64::is_finite)
+            .then_some(bootstrap)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::Bootstrap;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_BIMODAL: &[f64] = &[
+        10.0, 10.1, 9.9, 10.2, 9.8, 10.0, 10.1, 9.9, 20.0, 20.1, 19.9, 20.2, 19.8, 20.0, 20.1, 19.9,
+    ];
+
+    #[test]
+    fn test_bootstrap_zero() {
+        let bootstrap = Bootstrap::new(DATA_ZERO, Some(0.05), Some(0.95));
+        assert_eq!(bootstrap, None);
+    }
+
+    #[test]
+    fn test_bootstrap_one() {
+        let bootstrap = Bootstrap::new(DATA_ONE, Some(0.05), None).unwrap();
+        assert_eq!(
+            bootstrap,
+            Bootstrap {
+                median: 1.0,
+                lower: Some(1.0),
+                upper: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_const() {
+        let bootstrap = Bootstrap::new(DATA_FIVE_CONST, Some(0.05), Some(0.95)).unwrap();
+        assert_eq!(
+            bootstrap,
+            Bootstrap {
+                median: 1.0,
+                lower: Some(1.0),
+                upper: Some(1.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_reproducible() {
+        let bootstrap = Bootstrap::new(DATA_BIMODAL, Some(0.05), Some(0.95)).unwrap();
+        assert_eq!(
+            bootstrap,
+            Bootstrap::new(DATA_BIMODAL, Some(0.05), Some(0.95)).unwrap()
+        );
+
+        let seeded = Bootstrap::new_seeded(DATA_BIMODAL, Some(0.05), Some(0.95), 1).unwrap();
+        assert!(seeded != bootstrap);
+        assert!((seeded.median - bootstrap.median).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_bootstrap_bimodal() {
+        let bootstrap = Bootstrap::new(DATA_BIMODAL, Some(0.05), Some(0.95)).unwrap();
+        // The limits fall within each of the modes
+        let lower = bootstrap.lower.unwrap();
+        assert!((9.8..10.2).contains(&lower), "{lower}");
+        let upper = bootstrap.upper.unwrap();
+        assert!((19.8..20.2).contains(&upper), "{upper}");
+        assert!((10.0..20.0).contains(&bootstrap.median));
+    }
+}
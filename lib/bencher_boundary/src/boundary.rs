@@ -1,19 +1,27 @@
-use bencher_json::{project::boundary::BoundaryLimit, Boundary, ModelTest, SampleSize};
+use bencher_json::{
+    project::boundary::BoundaryLimit, Boundary, CdfBoundary, ModelTest, SampleSize, Smoothing,
+};
 use slog::Logger;
 
+use crate::change_point::change_points;
+use crate::holt::Holt;
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
 use crate::mean::Mean;
 use crate::quartiles::Quartiles;
+use crate::two_sample::{MannWhitney, PValues, Welch};
 use crate::{BoundaryError, MetricsData};
 
 #[derive(Debug, Default)]
 pub struct MetricsBoundary {
     pub limits: MetricsLimits,
     pub outlier: Option<BoundaryLimit>,
+    // The p-value for two-sample tests
+    pub p_value: Option<f64>,
 }
 
 impl MetricsBoundary {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         log: &Logger,
         datum: f64,
@@ -22,6 +30,8 @@ impl MetricsBoundary {
         min_sample_size: Option<SampleSize>,
         lower_boundary: Option<Boundary>,
         upper_boundary: Option<Boundary>,
+        level_smoothing: Option<Smoothing>,
+        trend_smoothing: Option<Smoothing>,
     ) -> Result<Self, BoundaryError> {
         Self::new_inner(
             log,
@@ -31,10 +41,13 @@ impl MetricsBoundary {
             min_sample_size,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         )
         .map(Option::unwrap_or_default)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn new_inner(
         log: &Logger,
         datum: f64,
@@ -43,6 +56,8 @@ impl MetricsBoundary {
         min_sample_size: Option<SampleSize>,
         lower_boundary: Option<Boundary>,
         upper_boundary: Option<Boundary>,
+        level_smoothing: Option<Smoothing>,
+        trend_smoothing: Option<Smoothing>,
     ) -> Result<Option<Self>, BoundaryError> {
         // If there is no boundary, then simply return.
         if lower_boundary.is_none() && upper_boundary.is_none() {
@@ -106,6 +121,34 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::Welch => Self::new_welch(log, metrics_data, lower_boundary, upper_boundary),
+            ModelTest::MannWhitney => {
+                Self::new_mann_whitney(log, metrics_data, lower_boundary, upper_boundary)
+            },
+            ModelTest::ChangePoint => {
+                Self::new_change_point(log, datum, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::Bootstrap => {
+                Self::new_bootstrap(log, datum, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::Ewma => Self::new_holt(
+                log,
+                datum,
+                data,
+                level_smoothing.unwrap_or(Smoothing::DEFAULT_LEVEL),
+                None,
+                lower_boundary,
+                upper_boundary,
+            ),
+            ModelTest::Holt => Self::new_holt(
+                log,
+                datum,
+                data,
+                level_smoothing.unwrap_or(Smoothing::DEFAULT_LEVEL),
+                Some(trend_smoothing.unwrap_or(Smoothing::DEFAULT_TREND)),
+                lower_boundary,
+                upper_boundary,
+            ),
         }
     }
 
@@ -117,7 +160,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_static(lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Self { limits, outlier }
+        Self {
+            limits,
+            outlier,
+            p_value: None,
+        }
     }
 
     fn new_percentage(
@@ -144,7 +191,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_percentage(log, mean, lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_normal(
@@ -183,7 +234,11 @@ impl MetricsBoundary {
         )?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_log_normal(
@@ -209,7 +264,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_log_normal(log, ln, lower_boundary, upper_boundary)?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_iqr(
@@ -251,6 +310,264 @@ impl MetricsBoundary {
         );
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
+    }
+
+    fn new_bootstrap(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(limits) = MetricsLimits::new_bootstrap(log, data, lower_boundary, upper_boundary)
+        else {
+            return Ok(None);
+        };
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
+    }
+
+    fn new_holt(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        level_smoothing: Smoothing,
+        trend_smoothing: Option<Smoothing>,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        // Forecast the next value from the historical data.
+        let Some(holt) = Holt::new(
+            data,
+            level_smoothing.into(),
+            trend_smoothing.map(Into::into),
+        ) else {
+            return Ok(None);
+        };
+        slog::debug!(log, "Holt: {holt:?}");
+
+        // The limits are the prediction interval around the forecast
+        let limits = MetricsLimits::new_normal(
+            log,
+            holt.forecast,
+            holt.std_err,
+            NormalTestKind::Z,
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
+    }
+
+    fn new_welch(
+        log: &Logger,
+        metrics_data: &MetricsData,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(welch) = Welch::new(metrics_data.baseline(), &metrics_data.candidate) else {
+            return Ok(None);
+        };
+
+        // The limits are for the mean of the candidate sample
+        let limits = MetricsLimits::new_normal(
+            log,
+            welch.baseline_mean,
+            welch.std_err,
+            NormalTestKind::T {
+                freedom: welch.freedom,
+            },
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let p_values = welch.p_values()?;
+
+        Ok(Some(Self::new_two_sample(
+            limits,
+            p_values,
+            lower_boundary,
+            upper_boundary,
+        )))
+    }
+
+    fn new_mann_whitney(
+        log: &Logger,
+        metrics_data: &MetricsData,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let baseline = metrics_data.baseline();
+        let Some(mann_whitney) = MannWhitney::new(baseline, &metrics_data.candidate) else {
+            return Ok(None);
+        };
+
+        let Some(limits) =
+            MetricsLimits::new_percentile(log, baseline, lower_boundary, upper_boundary)
+        else {
+            return Ok(None);
+        };
+        let p_values = mann_whitney.p_values()?;
+
+        Ok(Some(Self::new_two_sample(
+            limits,
+            p_values,
+            lower_boundary,
+            upper_boundary,
+        )))
+    }
+
+    fn new_change_point(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary: Option<CdfBoundary> = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary: Option<CdfBoundary> = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        // Detect change points using the least strict boundary,
+        // and then check the most recent change point against the boundary for its side.
+        let Some(confidence) = lower_boundary
+            .into_iter()
+            .chain(upper_boundary)
+            .min_by(|l, r| f64::from(*l).total_cmp(&f64::from(*r)))
+        else {
+            return Ok(None);
+        };
+
+        // The historical data is ordered from newest to oldest
+        let history = data.iter().rev().copied().collect::<Vec<_>>();
+        let history_change_points = change_points(&history, confidence)?;
+        let mut series = history;
+        series.push(datum);
+        let series_change_points = change_points(&series, confidence)?;
+
+        // The limits are a t-test for the current segment of the historical data,
+        // since the most recent change point.
+        let start = history_change_points
+            .last()
+            .map(|change_point| change_point.index)
+            .unwrap_or_default();
+        let segment = series.get(start..series.len() - 1).unwrap_or_default();
+        let limits = if let Some(mean) = Mean::new(segment) {
+            if let Some(std_dev) = mean.std_deviation(segment) {
+                #[allow(clippy::cast_precision_loss)]
+                MetricsLimits::new_normal(
+                    log,
+                    mean.mean,
+                    std_dev,
+                    NormalTestKind::T {
+                        freedom: (segment.len() - 1) as f64,
+                    },
+                    lower_boundary,
+                    upper_boundary,
+                )?
+            } else {
+                MetricsLimits {
+                    baseline: Some(mean.mean),
+                    ..Default::default()
+                }
+            }
+        } else {
+            MetricsLimits::default()
+        };
+
+        let Some(change_point) = series_change_points.last() else {
+            return Ok(Some(Self {
+                limits,
+                outlier: None,
+                p_value: None,
+            }));
+        };
+        slog::debug!(log, "Change point: {change_point:?}");
+        // Only alert on a change point the first time that it is detected
+        let is_new = !history_change_points.iter().any(|history_change_point| {
+            history_change_point.index == change_point.index
+                && history_change_point.direction() == change_point.direction()
+        });
+        let boundary = match change_point.direction() {
+            BoundaryLimit::Lower => lower_boundary,
+            BoundaryLimit::Upper => upper_boundary,
+        };
+        let outlier = boundary
+            .filter(|boundary| is_new && change_point.p_value < 1.0 - f64::from(*boundary))
+            .map(|_| change_point.direction());
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: Some(change_point.p_value),
+        }))
+    }
+
+    // For two-sample tests, the outlier is determined by the p-value and not the limits.
+    fn new_two_sample(
+        limits: MetricsLimits,
+        p_values: PValues,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Self {
+        Self {
+            limits,
+            outlier: p_values.outlier(lower_boundary, upper_boundary),
+            p_value: p_values.p_value(lower_boundary, upper_boundary),
+        }
     }
 }
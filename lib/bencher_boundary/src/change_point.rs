@@ -0,0 +1,317 @@
+use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+use crate::{mean::mean, BoundaryError};
+
+// The minimum number of data points on either side of a change point
+const MIN_SEGMENT_LEN: usize = 2;
+
+/// A shift in the mean of a series of metrics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangePoint {
+    // The index of the first datum after the change
+    pub index: usize,
+    // The mean of the segment before the change
+    pub before: f64,
+    // The mean of the segment after the change
+    pub after: f64,
+    // The two-sided p-value for the change,
+    // adjusted for the number of possible change points in the segment
+    pub p_value: f64,
+}
+
+impl ChangePoint {
+    pub fn direction(&self) -> BoundaryLimit {
+        if self.after < self.before {
+            BoundaryLimit::Lower
+        } else {
+            BoundaryLimit::Upper
+        }
+    }
+}
+
+/// Detect change points in a chronologically ordered series using circular binary segmentation,
+/// a variant of CUSUM binary segmentation that also finds changes that later revert.
+/// Each segment is split at the point with the largest standardized difference in means.
+/// If that split is not significant at the given confidence,
+/// then the interval with the largest standardized difference from the rest of the segment is tried.
+/// The change points are returned in chronological order.
+pub fn change_points(
+    series: &[f64],
+    confidence: CdfBoundary,
+) -> Result<Vec<ChangePoint>, BoundaryError> {
+    let mut detected = Vec::new();
+    segment(series, 0, 1.0 - f64::from(confidence), &mut detected)?;
+    detected.sort_unstable_by_key(|(index, _)| *index);
+
+    // The means on either side of each change point are for the final segments
+    let mut change_points = Vec::with_capacity(detected.len());
+    for (i, &(index, p_value)) in detected.iter().enumerate() {
+        let start = i
+            .checked_sub(1)
+            .and_then(|prev| detected.get(prev))
+            .map_or(0, |(prev, _)| *prev);
+        let end = detected.get(i + 1).map_or(series.len(), |(next, _)| *next);
+        let before = series.get(start..index).and_then(mean);
+        let after = series.get(index..end).and_then(mean);
+        if let (Some(before), Some(after)) = (before, after) {
+            change_points.push(ChangePoint {
+                index,
+                before,
+                after,
+                p_value,
+            });
+        }
+    }
+    Ok(change_points)
+}
+
+fn segment(
+    series: &[f64],
+    offset: usize,
+    alpha: f64,
+    detected: &mut Vec<(usize, f64)>,
+) -> Result<(), BoundaryError> {
+    let Some(prefix) = Prefix::new(series) else {
+        return Ok(());
+    };
+    let mut split = None;
+    for candidate in [prefix.single_split(), prefix.interval_split()]
+        .into_iter()
+        .flatten()
+    {
+        let p_value = candidate.p_value()?;
+        if p_value < alpha {
+            split = Some((candidate, p_value));
+            break;
+        }
+    }
+    let Some((Split { start, end, .. }, p_value)) = split else {
+        return Ok(());
+    };
+
+    let len = series.len();
+    for index in [start, end] {
+        if index > 0 && index < len {
+            detected.push((offset + index, p_value));
+        }
+    }
+    for (from, to) in [(0, start), (start, end), (end, len)] {
+        if let Some(piece) = series.get(from..to) {
+            segment(piece, offset + from, alpha, detected)?;
+        }
+    }
+    Ok(())
+}
+
+// The prefix sums of a centered segment
+struct Prefix {
+    sums: Vec<f64>,
+    squares: Vec<f64>,
+}
+
+// The most likely change within a segment, with the interval `start..end` differing from the rest
+#[derive(Debug, Clone, Copy)]
+struct Split {
+    start: usize,
+    end: usize,
+    // The Welch's t statistic for the split
+    t: f64,
+    // The Welch-Satterthwaite degrees of freedom for the split
+    freedom: f64,
+    // The number of possible splits that were considered
+    tests: usize,
+}
+
+impl Prefix {
+    fn new(series: &[f64]) -> Option<Self> {
+        if series.len() < MIN_SEGMENT_LEN * 2 {
+            return None;
+        }
+        // Center the series to avoid a loss of precision when calculating the variance
+        let center = mean(series)?;
+        let mut sums = Vec::with_capacity(series.len() + 1);
+        let mut squares = Vec::with_capacity(series.len() + 1);
+        let (mut sum, mut square) = (0.0, 0.0);
+        sums.push(sum);
+        squares.push(square);
+        for value in series {
+            let value = value - center;
+            sum += value;
+            square += value * value;
+            sums.push(sum);
+            squares.push(square);
+        }
+        Some(Self { sums, squares })
+    }
+
+    fn len(&self) -> usize {
+        self.sums.len() - 1
+    }
+
+    // Split the segment into a before and after
+    fn single_split(&self) -> Option<Split> {
+        let len = self.len();
+        let tests = len + 1 - MIN_SEGMENT_LEN * 2;
+        (MIN_SEGMENT_LEN..=len - MIN_SEGMENT_LEN)
+            .filter_map(|start| self.split(start, len, tests))
+            .reduce(|best, split| if split.t > best.t { split } else { best })
+    }
+
+    // Split out an interval in the middle of the segment
+    fn interval_split(&self) -> Option<Split> {
+        let len = self.len();
+        let starts = MIN_SEGMENT_LEN..=len.checked_sub(MIN_SEGMENT_LEN * 2)?;
+        let tests = starts
+            .clone()
+            .map(|start| len + 1 - MIN_SEGMENT_LEN - (start + MIN_SEGMENT_LEN))
+            .sum();
+        starts
+            .flat_map(|start| {
+                (start + MIN_SEGMENT_LEN..=len - MIN_SEGMENT_LEN).map(move |end| (start, end))
+            })
+            .filter_map(|(start, end)| self.split(start, end, tests))
+            .reduce(|best, split| if split.t > best.t { split } else { best })
+    }
+
+    #[allow(clippy::cast_precision_loss, clippy::indexing_slicing)]
+    fn split(&self, start: usize, end: usize, tests: usize) -> Option<Split> {
+        let len = self.len();
+        let inside_sum = self.sums[end] - self.sums[start];
+        let inside_square = self.squares[end] - self.squares[start];
+        let inside_len = end - start;
+        let outside_len = len - inside_len;
+        let (inside, inside_err) = stats(inside_len, inside_sum, inside_square);
+        let (outside, outside_err) = stats(
+            outside_len,
+            self.sums[len] - inside_sum,
+            self.squares[len] - inside_square,
+        );
+
+        let std_err_squared = inside_err + outside_err;
+        let difference = (inside - outside).abs();
+        let (t, freedom) = if std_err_squared > 0.0 {
+            let freedom = std_err_squared.powi(2)
+                / (inside_err.powi(2) / (inside_len - 1) as f64
+                    + outside_err.powi(2) / (outside_len - 1) as f64);
+            (difference / std_err_squared.sqrt(), freedom)
+        } else if difference > 0.0 {
+            // Neither side varies, so any difference is a change
+            (f64::INFINITY, f64::INFINITY)
+        } else {
+            return None;
+        };
+
+        Some(Split {
+            start,
+            end,
+            t,
+            freedom,
+            tests,
+        })
+    }
+}
+
+// The mean and squared standard error of a centered sample
+#[allow(clippy::cast_precision_loss)]
+fn stats(len: usize, sum: f64, square: f64) -> (f64, f64) {
+    let len = len as f64;
+    let mean = sum / len;
+    let variance = ((square - len * mean * mean) / (len - 1.0)).max(0.0);
+    (mean, variance / len)
+}
+
+impl Split {
+    #[allow(clippy::cast_precision_loss)]
+    fn p_value(self) -> Result<f64, BoundaryError> {
+        if self.t.is_infinite() {
+            return Ok(0.0);
+        }
+        let students_t =
+            StudentsT::new(0.0, 1.0, self.freedom).map_err(|error| BoundaryError::StudentsT {
+                mean: 0.0,
+                std_dev: 1.0,
+                freedom: self.freedom,
+                error,
+            })?;
+        // Use a Bonferroni correction for testing every possible split
+        let p_value = 2.0 * students_t.sf(self.t) * self.tests as f64;
+        Ok(p_value.min(1.0))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+    use once_cell::sync::Lazy;
+    use pretty_assertions::assert_eq;
+
+    use super::change_points;
+
+    const DATA_FLAT: &[f64] = &[
+        10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 10.3, 9.7, 10.1, 9.9, 10.0, 10.2,
+    ];
+    const DATA_STEP: &[f64] = &[
+        10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 12.0, 12.2, 11.8, 12.1, 11.9, 12.0,
+    ];
+    const DATA_STEP_REVERT: &[f64] = &[
+        10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 12.0, 12.2, 11.8, 12.1, 11.9, 12.0, 10.1, 9.9, 10.0,
+        10.2, 9.8, 10.0,
+    ];
+    const DATA_CONST_STEP: &[f64] = &[5.0, 5.0, 5.0, 5.0, 4.0, 4.0, 4.0];
+
+    static CONFIDENCE: Lazy<CdfBoundary> = Lazy::new(|| {
+        0.95.try_into()
+            .expect("Failed to parse statistical boundary.")
+    });
+
+    #[test]
+    fn test_change_points_too_short() {
+        let points = change_points(&[], *CONFIDENCE).unwrap();
+        assert_eq!(points, Vec::new());
+        let points = change_points(&[1.0, 100.0, 1.0], *CONFIDENCE).unwrap();
+        assert_eq!(points, Vec::new());
+    }
+
+    #[test]
+    fn test_change_points_flat() {
+        let points = change_points(DATA_FLAT, *CONFIDENCE).unwrap();
+        assert_eq!(points, Vec::new());
+    }
+
+    #[test]
+    fn test_change_points_step() {
+        let points = change_points(DATA_STEP, *CONFIDENCE).unwrap();
+        assert_eq!(points.len(), 1);
+        let change_point = points.first().unwrap();
+        assert_eq!(change_point.index, 6);
+        assert_eq!(change_point.direction(), BoundaryLimit::Upper);
+        assert!((change_point.before - 10.0).abs() < 1e-9);
+        assert!((change_point.after - 12.0).abs() < 1e-9);
+        assert!(change_point.p_value < 0.001);
+    }
+
+    #[test]
+    fn test_change_points_step_revert() {
+        let points = change_points(DATA_STEP_REVERT, *CONFIDENCE).unwrap();
+        let indexes = points
+            .iter()
+            .map(|change_point| (change_point.index, change_point.direction()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            indexes,
+            vec![(6, BoundaryLimit::Upper), (12, BoundaryLimit::Lower)]
+        );
+    }
+
+    #[test]
+    fn test_change_points_const_step() {
+        let points = change_points(DATA_CONST_STEP, *CONFIDENCE).unwrap();
+        assert_eq!(points.len(), 1);
+        let change_point = points.first().unwrap();
+        assert_eq!(change_point.index, 4);
+        assert_eq!(change_point.direction(), BoundaryLimit::Lower);
+        assert_eq!(change_point.p_value, 0.0);
+    }
+}
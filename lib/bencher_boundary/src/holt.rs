@@ -0,0 +1,138 @@
+/// The one-step-ahead forecast of the historical data using exponential smoothing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Holt {
+    pub forecast: f64,
+    // The root mean squared error of the one-step-ahead forecasts of the historical data
+    pub std_err: f64,
+}
+
+impl Holt {
+    // The data is expected to be ordered from newest to oldest.
+    // Without a trend smoothing factor, this is an exponentially weighted moving average (EWMA).
+    // With a trend smoothing factor, this is Holt's linear trend method.
+    pub fn new(data: &[f64], level_smoothing: f64, trend_smoothing: Option<f64>) -> Option<Self> {
+        let mut history = data.iter().rev().copied();
+        let mut level = history.next()?;
+        let mut trend = 0.0;
+        // Initialize the trend with the difference between the two oldest values
+        if trend_smoothing.is_some() {
+            let value = history.next()?;
+            trend = value - level;
+            level = value;
+        }
+
+        let mut sum_squared_err = 0.0;
+        let mut count = 0u32;
+        for value in history {
+            let err = value - (level + trend);
+            sum_squared_err += err.powi(2);
+            count += 1;
+
+            let prev_level = level;
+            level = level_smoothing * value + (1.0 - level_smoothing) * (level + trend);
+            if let Some(trend_smoothing) = trend_smoothing {
+                trend = trend_smoothing * (level - prev_level) + (1.0 - trend_smoothing) * trend;
+            }
+        }
+
+        if count == 0 {
+            return None;
+        }
+        let forecast = level + trend;
+        let std_err = (sum_squared_err / f64::from(count)).sqrt();
+        // If the standard error is zero then the prediction interval is not going to work with `statrs`
+        (forecast.is_finite() && std_err.is_finite() && std_err > 0.0)
+            .then_some(Self { forecast, std_err })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::Holt;
+
+    const LEVEL: f64 = 0.5;
+    const TREND: f64 = 0.5;
+
+    // Ordered from newest to oldest
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_TWO: &[f64] = &[2.0, 1.0];
+    const DATA_THREE: &[f64] = &[3.0, 2.0, 1.0];
+    const DATA_THREE_TREND: &[f64] = &[4.0, 2.0, 1.0];
+    const DATA_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_DRIFT: &[f64] = &[
+        20.2, 18.9, 18.1, 17.0, 16.1, 14.8, 14.1, 12.9, 12.2, 10.8, 10.1, 9.0, 7.9, 7.1, 5.8, 5.2,
+        3.9, 3.1, 1.8, 1.0,
+    ];
+
+    #[test]
+    fn test_ewma_empty() {
+        assert_eq!(Holt::new(&[], LEVEL, None), None);
+        assert_eq!(Holt::new(DATA_ONE, LEVEL, None), None);
+    }
+
+    #[test]
+    fn test_ewma_const() {
+        assert_eq!(Holt::new(DATA_CONST, LEVEL, None), None);
+    }
+
+    #[test]
+    fn test_ewma() {
+        let holt = Holt::new(DATA_TWO, LEVEL, None).unwrap();
+        assert_eq!(
+            holt,
+            Holt {
+                forecast: 1.5,
+                std_err: 1.0,
+            }
+        );
+
+        let holt = Holt::new(DATA_THREE, LEVEL, None).unwrap();
+        assert_eq!(
+            holt,
+            Holt {
+                forecast: 2.25,
+                std_err: 1.2747548783981961,
+            }
+        );
+    }
+
+    #[test]
+    fn test_holt_empty() {
+        assert_eq!(Holt::new(&[], LEVEL, Some(TREND)), None);
+        assert_eq!(Holt::new(DATA_ONE, LEVEL, Some(TREND)), None);
+        assert_eq!(Holt::new(DATA_TWO, LEVEL, Some(TREND)), None);
+    }
+
+    #[test]
+    fn test_holt_linear() {
+        // A perfectly linear trend is predicted without any error
+        assert_eq!(Holt::new(DATA_THREE, LEVEL, Some(TREND)), None);
+    }
+
+    #[test]
+    fn test_holt() {
+        let holt = Holt::new(DATA_THREE_TREND, LEVEL, Some(TREND)).unwrap();
+        assert_eq!(
+            holt,
+            Holt {
+                forecast: 4.75,
+                std_err: 1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_holt_drift() {
+        // The trend is followed, unlike the lagging moving average
+        let holt = Holt::new(DATA_DRIFT, LEVEL, Some(TREND)).unwrap();
+        assert!((20.5..21.5).contains(&holt.forecast), "{holt:?}");
+        assert!(holt.std_err < 1.0, "{holt:?}");
+
+        let ewma = Holt::new(DATA_DRIFT, LEVEL, None).unwrap();
+        assert!(ewma.forecast < holt.forecast, "{ewma:?}");
+        assert!(ewma.std_err > holt.std_err, "{ewma:?}");
+    }
+}
@@ -1,14 +1,34 @@
+mod bootstrap;
 pub mod boundary;
+pub mod change_point;
 mod error;
+mod holt;
 pub mod limits;
 mod ln;
 mod mean;
 mod quartiles;
+mod two_sample;
 
 pub use boundary::MetricsBoundary;
 pub use error::BoundaryError;
 
 #[derive(Debug, Clone)]
 pub struct MetricsData {
+    // The historical metrics, ordered from newest to oldest
     pub data: Vec<f64>,
+    // The raw samples for the historical metrics, if any were reported
+    pub samples: Vec<f64>,
+    // The metrics from the candidate report, only used by two-sample tests
+    pub candidate: Vec<f64>,
+}
+
+impl MetricsData {
+    // Prefer the raw samples over the metric values when comparing two samples
+    pub fn baseline(&self) -> &[f64] {
+        if self.samples.is_empty() {
+            &self.data
+        } else {
+            &self.samples
+        }
+    }
 }
@@ -35,6 +35,10 @@ impl MetricsLimit {
         Self { value: abs_limit }
     }
 
+    pub fn percentile(value: f64) -> Self {
+        Self { value }
+    }
+
     pub fn iqr_lower(quartiles: Quartiles, boundary: IqrBoundary) -> Self {
         Self {
             value: quartiles.q2 - quartiles.iqr(boundary),
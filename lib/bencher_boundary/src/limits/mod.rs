@@ -4,7 +4,7 @@ use bencher_json::{
 use slog::{debug, Logger};
 use statrs::distribution::{ContinuousCDF, LogNormal, Normal, StudentsT};
 
-use crate::{ln::Ln, quartiles::Quartiles, BoundaryError};
+use crate::{bootstrap::Bootstrap, ln::Ln, quartiles::Quartiles, BoundaryError};
 
 mod limit;
 
@@ -156,6 +156,58 @@ impl MetricsLimits {
         })
     }
 
+    // The limits are the percentiles of the baseline sample at each boundary.
+    pub fn new_percentile(
+        log: &Logger,
+        baseline: &[f64],
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<Self> {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Some(Self::default());
+        }
+
+        let median = Quartiles::percentile(baseline, 0.5)?;
+        debug!(log, "Percentile: median={median}");
+        let lower = lower_boundary
+            .and_then(|boundary| Quartiles::percentile(baseline, 1.0 - f64::from(boundary)))
+            .map(MetricsLimit::percentile);
+        let upper = upper_boundary
+            .and_then(|boundary| Quartiles::percentile(baseline, f64::from(boundary)))
+            .map(MetricsLimit::percentile);
+
+        Some(Self {
+            baseline: Some(median),
+            lower,
+            upper,
+        })
+    }
+
+    // The limits are the bootstrap estimates of the percentiles of the data at each boundary.
+    pub fn new_bootstrap(
+        log: &Logger,
+        data: &[f64],
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<Self> {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Some(Self::default());
+        }
+
+        let bootstrap = Bootstrap::new(
+            data,
+            lower_boundary.map(|boundary| 1.0 - f64::from(boundary)),
+            upper_boundary.map(f64::from),
+        )?;
+        debug!(log, "Bootstrap: {bootstrap:?}");
+
+        Some(Self {
+            baseline: Some(bootstrap.median),
+            lower: bootstrap.lower.map(MetricsLimit::percentile),
+            upper: bootstrap.upper.map(MetricsLimit::percentile),
+        })
+    }
+
     pub fn new_iqr(
         log: &Logger,
         quartiles: Quartiles,
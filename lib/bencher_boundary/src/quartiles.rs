@@ -41,6 +41,12 @@ impl Quartiles {
         })
     }
 
+    pub fn percentile(data: &[f64], percentile: f64) -> Option<f64> {
+        let mut data = data.to_vec();
+        data.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal));
+        Self::percentile_of_sorted(&data, percentile).and_then(|p| p.is_finite().then_some(p))
+    }
+
     pub fn iqr(self, boundary: IqrBoundary) -> f64 {
         (self.q3 - self.q1) * f64::from(boundary)
     }
@@ -64,7 +70,7 @@ impl Quartiles {
         clippy::indexing_slicing
     )]
     // https://doc.rust-lang.org/1.75.0/src/test/stats.rs.html#260
-    fn percentile_of_sorted(sorted_data: &[f64], percentile: f64) -> Option<f64> {
+    pub fn percentile_of_sorted(sorted_data: &[f64], percentile: f64) -> Option<f64> {
         if sorted_data.is_empty() || !(0.0..=1.0).contains(&percentile) {
             None
         } else if sorted_data.len() == 1 {
@@ -0,0 +1,335 @@
+use std::cmp;
+
+use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+use crate::{mean::mean, BoundaryError};
+
+// Apply a continuity correction to the normal approximation of the U statistic
+const CONTINUITY_CORRECTION: f64 = 0.5;
+
+/// The one-sided p-values for the candidate sample being below or above the baseline sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PValues {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl PValues {
+    // A boundary of `0.95` is significant at a p-value less than `0.05`
+    pub fn outlier(
+        self,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<BoundaryLimit> {
+        let is_significant =
+            |p_value: f64, boundary: CdfBoundary| p_value < 1.0 - f64::from(boundary);
+        match (lower_boundary, upper_boundary) {
+            (Some(lower), Some(upper)) => {
+                if self.lower <= self.upper {
+                    is_significant(self.lower, lower).then_some(BoundaryLimit::Lower)
+                } else {
+                    is_significant(self.upper, upper).then_some(BoundaryLimit::Upper)
+                }
+            },
+            (Some(lower), None) => {
+                is_significant(self.lower, lower).then_some(BoundaryLimit::Lower)
+            },
+            (None, Some(upper)) => {
+                is_significant(self.upper, upper).then_some(BoundaryLimit::Upper)
+            },
+            (None, None) => None,
+        }
+    }
+
+    // Report the p-value for the side(s) being tested
+    pub fn p_value(
+        self,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<f64> {
+        match (lower_boundary, upper_boundary) {
+            (Some(_), Some(_)) => Some(self.lower.min(self.upper)),
+            (Some(_), None) => Some(self.lower),
+            (None, Some(_)) => Some(self.upper),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Welch's unequal variances t-test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welch {
+    pub baseline_mean: f64,
+    pub candidate_mean: f64,
+    // The standard error of the difference between the two means
+    pub std_err: f64,
+    // The Welch-Satterthwaite approximation of the degrees of freedom
+    pub freedom: f64,
+}
+
+impl Welch {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(baseline: &[f64], candidate: &[f64]) -> Option<Self> {
+        let (baseline_mean, baseline_variance) = sample_variance(baseline)?;
+        let (candidate_mean, candidate_variance) = sample_variance(candidate)?;
+
+        let baseline_len = baseline.len() as f64;
+        let candidate_len = candidate.len() as f64;
+        let baseline_err = baseline_variance / baseline_len;
+        let candidate_err = candidate_variance / candidate_len;
+        let std_err_squared = baseline_err + candidate_err;
+        // If neither sample varies then there is no distribution to test against
+        if std_err_squared <= 0.0 {
+            return None;
+        }
+
+        let freedom = std_err_squared.powi(2)
+            / (baseline_err.powi(2) / (baseline_len - 1.0)
+                + candidate_err.powi(2) / (candidate_len - 1.0));
+        let std_err = std_err_squared.sqrt();
+
+        (std_err.is_finite() && freedom.is_finite()).then_some(Self {
+            baseline_mean,
+            candidate_mean,
+            std_err,
+            freedom,
+        })
+    }
+
+    pub fn p_values(self) -> Result<PValues, BoundaryError> {
+        let Self {
+            baseline_mean,
+            candidate_mean,
+            std_err,
+            freedom,
+        } = self;
+        let students_t =
+            StudentsT::new(0.0, 1.0, freedom).map_err(|error| BoundaryError::StudentsT {
+                mean: 0.0,
+                std_dev: 1.0,
+                freedom,
+                error,
+            })?;
+        let t = (candidate_mean - baseline_mean) / std_err;
+        Ok(PValues {
+            lower: students_t.cdf(t),
+            upper: students_t.sf(t),
+        })
+    }
+}
+
+/// Mann-Whitney U test, using the normal approximation with a correction for ties
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MannWhitney {
+    // The U statistic for the candidate sample
+    pub u: f64,
+    // The mean of the U statistic under the null hypothesis
+    pub mean: f64,
+    // The standard deviation of the U statistic under the null hypothesis
+    pub std_dev: f64,
+}
+
+impl MannWhitney {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(baseline: &[f64], candidate: &[f64]) -> Option<Self> {
+        if baseline.is_empty() || candidate.is_empty() {
+            return None;
+        }
+
+        // Rank the combined samples, keeping track of which values are from the candidate
+        let mut combined = baseline
+            .iter()
+            .map(|&value| (value, false))
+            .chain(candidate.iter().map(|&value| (value, true)))
+            .collect::<Vec<_>>();
+        combined
+            .sort_unstable_by(|(l, _), (r, _)| l.partial_cmp(r).unwrap_or(cmp::Ordering::Equal));
+
+        let mut candidate_rank_sum = 0.0;
+        let mut tie_sum = 0.0;
+        let mut start = 0;
+        for group in
+            combined.chunk_by(|(l, _), (r, _)| l.partial_cmp(r) == Some(cmp::Ordering::Equal))
+        {
+            let ties = group.len() as f64;
+            // Tied values all get the average of the ranks that they span
+            let rank = start as f64 + (ties + 1.0) / 2.0;
+            let candidates = group
+                .iter()
+                .filter(|(_, is_candidate)| *is_candidate)
+                .count();
+            candidate_rank_sum += rank * candidates as f64;
+            tie_sum += ties.powi(3) - ties;
+            start += group.len();
+        }
+
+        let baseline_len = baseline.len() as f64;
+        let candidate_len = candidate.len() as f64;
+        let total_len = baseline_len + candidate_len;
+        let u = candidate_rank_sum - candidate_len * (candidate_len + 1.0) / 2.0;
+        let mean = baseline_len * candidate_len / 2.0;
+        let variance = baseline_len * candidate_len / 12.0
+            * ((total_len + 1.0) - tie_sum / (total_len * (total_len - 1.0)));
+        // If every value is tied then there is no distribution to test against
+        if variance <= 0.0 {
+            return None;
+        }
+        let std_dev = variance.sqrt();
+
+        std_dev.is_finite().then_some(Self { u, mean, std_dev })
+    }
+
+    pub fn p_values(self) -> Result<PValues, BoundaryError> {
+        let Self { u, mean, std_dev } = self;
+        let normal = Normal::new(0.0, 1.0).map_err(|error| BoundaryError::Normal {
+            mean: 0.0,
+            std_dev: 1.0,
+            error,
+        })?;
+        let lower_z = (u - mean + CONTINUITY_CORRECTION) / std_dev;
+        let upper_z = (u - mean - CONTINUITY_CORRECTION) / std_dev;
+        Ok(PValues {
+            lower: normal.cdf(lower_z),
+            upper: normal.sf(upper_z),
+        })
+    }
+}
+
+// The mean and unbiased sample variance
+fn sample_variance(data: &[f64]) -> Option<(f64, f64)> {
+    // Do not calculate variance if there are less than 2 data points
+    if data.len() < 2 {
+        return None;
+    }
+    let mean = mean(data)?;
+    #[allow(clippy::cast_precision_loss)]
+    let variance = data
+        .iter()
+        .map(|&value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (data.len() - 1) as f64;
+    variance.is_finite().then_some((mean, variance))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unreadable_literal, clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+    use once_cell::sync::Lazy;
+    use pretty_assertions::assert_eq;
+
+    use super::{MannWhitney, PValues, Welch};
+
+    const BASELINE: &[f64] = &[
+        10.0, 11.0, 9.0, 10.5, 9.5, 10.0, 10.2, 9.8, 10.1, 9.9, 10.3, 9.7,
+    ];
+    const CANDIDATE_SAME: &[f64] = &[10.1, 9.9, 10.0, 10.2, 9.8];
+    const CANDIDATE_SLOWER: &[f64] = &[11.5, 11.8, 12.0, 11.6, 11.9];
+    const CANDIDATE_FASTER: &[f64] = &[8.0, 8.2, 7.9, 8.1, 8.3];
+    const DATA_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+
+    static BOUNDARY: Lazy<CdfBoundary> = Lazy::new(|| {
+        0.95.try_into()
+            .expect("Failed to parse statistical boundary.")
+    });
+
+    #[test]
+    fn test_welch_too_small() {
+        assert_eq!(Welch::new(BASELINE, &[1.0]), None);
+        assert_eq!(Welch::new(&[1.0], CANDIDATE_SAME), None);
+        assert_eq!(Welch::new(DATA_CONST, DATA_CONST), None);
+    }
+
+    #[test]
+    fn test_welch_same() {
+        let welch = Welch::new(BASELINE, CANDIDATE_SAME).unwrap();
+        assert_eq!(welch.baseline_mean, 10.0);
+        assert_eq!(welch.candidate_mean, 10.0);
+        let p_values = welch.p_values().unwrap();
+        assert_eq!(
+            p_values,
+            PValues {
+                lower: 0.5,
+                upper: 0.5
+            }
+        );
+        assert_eq!(p_values.outlier(Some(*BOUNDARY), Some(*BOUNDARY)), None);
+        assert_eq!(
+            p_values.p_value(Some(*BOUNDARY), Some(*BOUNDARY)),
+            Some(0.5)
+        );
+    }
+
+    #[test]
+    fn test_welch_slower() {
+        let welch = Welch::new(BASELINE, CANDIDATE_SLOWER).unwrap();
+        let p_values = welch.p_values().unwrap();
+        assert!(p_values.upper < 0.001);
+        assert_eq!(
+            p_values.outlier(Some(*BOUNDARY), Some(*BOUNDARY)),
+            Some(BoundaryLimit::Upper)
+        );
+        assert_eq!(p_values.outlier(Some(*BOUNDARY), None), None);
+        assert_eq!(
+            p_values.p_value(None, Some(*BOUNDARY)),
+            Some(p_values.upper)
+        );
+    }
+
+    #[test]
+    fn test_welch_faster() {
+        let welch = Welch::new(BASELINE, CANDIDATE_FASTER).unwrap();
+        let p_values = welch.p_values().unwrap();
+        assert!(p_values.lower < 0.001);
+        assert_eq!(
+            p_values.outlier(Some(*BOUNDARY), Some(*BOUNDARY)),
+            Some(BoundaryLimit::Lower)
+        );
+        assert_eq!(p_values.outlier(None, Some(*BOUNDARY)), None);
+    }
+
+    #[test]
+    fn test_mann_whitney_too_small() {
+        assert_eq!(MannWhitney::new(BASELINE, &[]), None);
+        assert_eq!(MannWhitney::new(&[], CANDIDATE_SAME), None);
+        assert_eq!(MannWhitney::new(DATA_CONST, DATA_CONST), None);
+    }
+
+    #[test]
+    fn test_mann_whitney_same() {
+        let mann_whitney = MannWhitney::new(BASELINE, CANDIDATE_SAME).unwrap();
+        assert_eq!(mann_whitney.u, 30.0);
+        assert_eq!(mann_whitney.mean, 30.0);
+        let p_values = mann_whitney.p_values().unwrap();
+        assert!(p_values.lower > 0.5);
+        assert!(p_values.upper > 0.5);
+        assert_eq!(p_values.outlier(Some(*BOUNDARY), Some(*BOUNDARY)), None);
+    }
+
+    #[test]
+    fn test_mann_whitney_slower() {
+        let mann_whitney = MannWhitney::new(BASELINE, CANDIDATE_SLOWER).unwrap();
+        // Every candidate value is greater than every baseline value
+        assert_eq!(mann_whitney.u, 60.0);
+        let p_values = mann_whitney.p_values().unwrap();
+        assert!(p_values.upper < 0.01);
+        assert_eq!(
+            p_values.outlier(Some(*BOUNDARY), Some(*BOUNDARY)),
+            Some(BoundaryLimit::Upper)
+        );
+    }
+
+    #[test]
+    fn test_mann_whitney_faster() {
+        let mann_whitney = MannWhitney::new(BASELINE, CANDIDATE_FASTER).unwrap();
+        // Every candidate value is less than every baseline value
+        assert_eq!(mann_whitney.u, 0.0);
+        let p_values = mann_whitney.p_values().unwrap();
+        assert!(p_values.lower < 0.01);
+        assert_eq!(
+            p_values.outlier(Some(*BOUNDARY), Some(*BOUNDARY)),
+            Some(BoundaryLimit::Lower)
+        );
+    }
+}
@@ -29,6 +29,7 @@ macro_rules! from_client {
 
 from_client!(
     BenchmarkName,
+    BenchmarkSelector,
     Boundary,
     BranchName,
     Email,
@@ -40,7 +41,9 @@ from_client!(
     ResourceId,
     ResourceName,
     SampleSize,
+    Secret,
     Slug,
+    Smoothing,
     Url,
     UserName,
     Window
@@ -60,7 +63,8 @@ from_client!(
     ModelUuid,
     AlertUuid,
     UserUuid,
-    TokenUuid
+    TokenUuid,
+    WebhookUuid
 );
 
 #[cfg(feature = "plus")]
@@ -127,14 +131,23 @@ try_from_client!(
     JsonOneMetric,
     JsonThresholds,
     JsonThreshold,
+    JsonCreatedThreshold,
     JsonModel,
     JsonAlerts,
     JsonAlertStats,
     JsonAlert,
+    JsonAlertEvents,
+    JsonAlertEvent,
+    JsonWebhooks,
+    JsonWebhook,
+    JsonWebhookDeliveries,
+    JsonWebhookDelivery,
     JsonUsers,
     JsonUser,
     JsonTokens,
     JsonToken,
+    JsonNotifications,
+    JsonNotification,
     JsonSignup,
     JsonLogin,
     JsonConfirm,
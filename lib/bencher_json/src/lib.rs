@@ -1,8 +1,8 @@
 pub use bencher_valid::{
-    BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash,
-    Index, IqrBoundary, Jwt, Model, ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary,
-    ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret, Slug, Url, UserName,
-    ValidError, Window,
+    BenchmarkMatcher, BenchmarkName, BenchmarkSelector, BenchmarkSelectorKind, Boundary,
+    BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash, Index, IqrBoundary, Jwt,
+    Model, ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary, ResourceId, ResourceIdKind,
+    ResourceName, SampleSize, Sanitize, Secret, Slug, Smoothing, Url, UserName, ValidError, Window,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -33,10 +33,18 @@ pub use organization::{
 };
 pub use pagination::{JsonDirection, JsonPagination};
 pub use project::{
-    alert::{AlertUuid, JsonAlert, JsonAlertStats, JsonAlerts},
+    alert::{
+        AlertEventUuid, AlertUuid, JsonAlert, JsonAlertEvent, JsonAlertEvents, JsonAlertStats,
+        JsonAlerts,
+    },
     benchmark::{BenchmarkUuid, JsonBenchmark, JsonBenchmarks},
     boundary::{BoundaryUuid, JsonBoundaries, JsonBoundary},
     branch::{BranchUuid, JsonBranch, JsonBranches, JsonNewBranch, JsonStartPoint, VersionUuid},
+    change_point::{JsonChangePoint, JsonChangePoints},
+    composite_threshold::{
+        CompositeThresholdUuid, JsonCompositeThreshold, JsonCompositeThresholds,
+        JsonNewCompositeThreshold,
+    },
     measure::{JsonMeasure, JsonMeasures, JsonNewMeasure, MeasureUuid},
     metric::{
         JsonMetric, JsonMetricsMap, JsonNewMetric, JsonOneMetric, JsonResultsMap, MeasureNameId,
@@ -47,7 +55,14 @@ pub use project::{
     plot::{JsonNewPlot, JsonPlot, JsonPlots, PlotUuid},
     report::{JsonNewReport, JsonReport, JsonReports, ReportUuid},
     testbed::{JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedUuid},
-    threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    threshold::{
+        JsonCreatedThreshold, JsonNewSingleThreshold, JsonNewThreshold, JsonThreshold,
+        JsonThresholds, ThresholdUuid,
+    },
+    webhook::{
+        JsonNewWebhook, JsonWebhook, JsonWebhookDeliveries, JsonWebhookDelivery, JsonWebhooks,
+        WebhookDeliveryUuid, WebhookUuid,
+    },
     JsonNewProject, JsonProject, JsonProjects, ProjectUuid,
 };
 #[cfg(feature = "plus")]
@@ -67,6 +82,7 @@ pub use system::{
     version::JsonApiVersion,
 };
 pub use user::{
+    notification::{JsonNotification, JsonNotifications},
     token::{JsonNewToken, JsonToken, JsonTokens, TokenUuid},
     JsonUpdateUser, JsonUser, JsonUsers, UserUuid,
 };
@@ -2,13 +2,15 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{BigInt, DateTime, JsonThreshold};
+use crate::{BigInt, DateTime, JsonThreshold, JsonUser, ResourceId};
 
 use super::{
-    benchmark::JsonBenchmarkMetric, boundary::BoundaryLimit, report::Iteration, report::ReportUuid,
+    benchmark::JsonBenchmarkMetric, boundary::BoundaryLimit, branch::VersionNumber,
+    composite_threshold::CompositeThresholdUuid, report::Iteration, report::ReportUuid,
 };
 
 crate::typed_uuid::typed_uuid!(AlertUuid);
+crate::typed_uuid::typed_uuid!(AlertEventUuid);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -26,16 +28,34 @@ pub struct JsonAlert {
     pub threshold: JsonThreshold,
     pub benchmark: JsonBenchmarkMetric,
     pub limit: BoundaryLimit,
+    /// The composite threshold that generated the alert, if any.
+    /// The threshold and benchmark are for the first member that detected an outlier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub composite_threshold: Option<CompositeThresholdUuid>,
     pub status: AlertStatus,
+    /// The user that the alert is assigned to, if it is acknowledged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<JsonUser>,
+    /// The alert is silenced until this date time, if it is silenced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub silenced_until: Option<DateTime>,
+    /// The alert is silenced until the branch reaches this version number, if it is silenced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub silenced_version: Option<VersionNumber>,
     pub created: DateTime,
     pub modified: DateTime,
 }
 
 const ACTIVE_INT: i32 = 0;
 const DISMISSED_INT: i32 = 1;
+const ACKNOWLEDGED_INT: i32 = 2;
+const SILENCED_INT: i32 = 3;
+const RESOLVED_INT: i32 = 4;
 
 #[typeshare::typeshare]
-#[derive(Debug, Clone, Copy, Default, derive_more::Display, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
 #[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
@@ -45,11 +65,27 @@ pub enum AlertStatus {
     #[default]
     Active = ACTIVE_INT,
     Dismissed = DISMISSED_INT,
+    /// The alert has been acknowledged and assigned to a user.
+    Acknowledged = ACKNOWLEDGED_INT,
+    /// The alert is silenced until a date time and/or a branch version number.
+    Silenced = SILENCED_INT,
+    /// A later report for the same branch, testbed, benchmark, and measure was within the boundary limits.
+    Resolved = RESOLVED_INT,
+}
+
+impl AlertStatus {
+    /// Only an active alert still needs attention.
+    /// Acknowledged, silenced, dismissed, and resolved alerts do not.
+    pub fn is_active(self) -> bool {
+        matches!(self, Self::Active)
+    }
 }
 
 #[cfg(feature = "db")]
 mod alert_status {
-    use super::{AlertStatus, ACTIVE_INT, DISMISSED_INT};
+    use super::{
+        AlertStatus, ACKNOWLEDGED_INT, ACTIVE_INT, DISMISSED_INT, RESOLVED_INT, SILENCED_INT,
+    };
 
     #[derive(Debug, thiserror::Error)]
     pub enum AlertStatusError {
@@ -69,6 +105,9 @@ mod alert_status {
             match self {
                 Self::Active => ACTIVE_INT.to_sql(out),
                 Self::Dismissed => DISMISSED_INT.to_sql(out),
+                Self::Acknowledged => ACKNOWLEDGED_INT.to_sql(out),
+                Self::Silenced => SILENCED_INT.to_sql(out),
+                Self::Resolved => RESOLVED_INT.to_sql(out),
             }
         }
     }
@@ -82,6 +121,9 @@ mod alert_status {
             match i32::from_sql(bytes)? {
                 ACTIVE_INT => Ok(Self::Active),
                 DISMISSED_INT => Ok(Self::Dismissed),
+                ACKNOWLEDGED_INT => Ok(Self::Acknowledged),
+                SILENCED_INT => Ok(Self::Silenced),
+                RESOLVED_INT => Ok(Self::Resolved),
                 value => Err(Box::new(AlertStatusError::Invalid(value))),
             }
         }
@@ -101,6 +143,46 @@ pub struct JsonAlertStats {
 pub struct JsonUpdateAlert {
     /// The new status of the alert.
     pub status: Option<AlertStatus>,
+    /// The slug or UUID of the user to assign an `acknowledged` alert to.
+    /// The user must be a member of the project's organization.
+    /// If not set when acknowledging an alert, then the alert is assigned to the authenticated user.
+    pub assignee: Option<ResourceId>,
+    /// Silence the alert until this date time.
+    /// Only valid for a `silenced` alert.
+    pub silenced_until: Option<DateTime>,
+    /// Silence the alert until the branch reaches this version number.
+    /// Only valid for a `silenced` alert.
+    pub silenced_version: Option<VersionNumber>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertEvents(pub Vec<JsonAlertEvent>);
+
+crate::from_vec!(JsonAlertEvents[JsonAlertEvent]);
+
+/// A change to the status of an alert
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertEvent {
+    pub uuid: AlertEventUuid,
+    pub alert: AlertUuid,
+    pub status: AlertStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<JsonUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub silenced_until: Option<DateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub silenced_version: Option<VersionNumber>,
+    /// The user that made the change.
+    /// Not set for changes made automatically by Bencher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<JsonUser>,
+    /// The report that caused the change, for changes made automatically by Bencher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report: Option<ReportUuid>,
+    pub created: DateTime,
 }
 
 #[typeshare::typeshare]
@@ -112,3 +194,17 @@ pub struct JsonPerfAlert {
     pub status: AlertStatus,
     pub modified: DateTime,
 }
+
+#[cfg(test)]
+mod test {
+    use super::AlertStatus;
+
+    #[test]
+    fn test_alert_status_is_active() {
+        assert!(AlertStatus::Active.is_active());
+        assert!(!AlertStatus::Dismissed.is_active());
+        assert!(!AlertStatus::Acknowledged.is_active());
+        assert!(!AlertStatus::Silenced.is_active());
+        assert!(!AlertStatus::Resolved.is_active());
+    }
+}
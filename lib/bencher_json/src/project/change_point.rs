@@ -0,0 +1,62 @@
+use ordered_float::OrderedFloat;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BenchmarkUuid, Boundary, BranchUuid, DateTime, DateTimeMillis, MeasureUuid, MetricUuid,
+    ReportUuid, TestbedUuid,
+};
+
+use super::{boundary::BoundaryLimit, branch::JsonVersion, report::Iteration};
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangePointQuery {
+    /// The UUID of the branch to search.
+    pub branch: BranchUuid,
+    /// The UUID of the testbed to search.
+    pub testbed: TestbedUuid,
+    /// The UUID of the benchmark to search.
+    pub benchmark: BenchmarkUuid,
+    /// The UUID of the measure to search.
+    pub measure: MeasureUuid,
+    /// The confidence required to detect a change point.
+    /// This can be any decimal between `0.5` and `1.0`.
+    /// Defaults to `0.95`.
+    pub confidence: Option<Boundary>,
+    /// Search for metrics after the given date time in milliseconds.
+    pub start_time: Option<DateTimeMillis>,
+    /// Search for metrics before the given date time in milliseconds.
+    pub end_time: Option<DateTimeMillis>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangePoints(pub Vec<JsonChangePoint>);
+
+crate::from_vec!(JsonChangePoints[JsonChangePoint]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangePoint {
+    /// The first metric after the change point.
+    pub metric: MetricUuid,
+    /// The report for the first metric after the change point.
+    pub report: ReportUuid,
+    /// The report iteration for the first metric after the change point.
+    pub iteration: Iteration,
+    /// The start time of the report for the first metric after the change point.
+    pub start_time: DateTime,
+    /// The branch version, including the git hash, where the change point occurred.
+    pub version: JsonVersion,
+    /// Whether the metrics decreased (`lower`) or increased (`upper`) after the change point.
+    pub direction: BoundaryLimit,
+    /// The mean of the metrics before the change point.
+    pub before: OrderedFloat<f64>,
+    /// The mean of the metrics after the change point.
+    pub after: OrderedFloat<f64>,
+    /// The p-value of the change point.
+    pub p_value: OrderedFloat<f64>,
+}
@@ -0,0 +1,160 @@
+use bencher_valid::DateTime;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonBranch, JsonMeasure, JsonTestbed, ProjectUuid, ThresholdUuid};
+
+use super::boundary::BoundaryLimit;
+
+crate::typed_uuid::typed_uuid!(CompositeThresholdUuid);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewCompositeThreshold {
+    /// How the member threshold boundaries are combined.
+    /// With `and`, every member must detect an outlier to generate an alert.
+    /// With `or`, any member detecting an outlier generates an alert.
+    pub operator: CompositeOperator,
+    /// The member thresholds to combine.
+    /// There must be at least two members,
+    /// and all of them must share the same branch and testbed.
+    /// Member thresholds no longer generate alerts on their own.
+    pub members: Vec<JsonNewCompositeMember>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewCompositeMember {
+    /// The UUID of the member threshold.
+    pub threshold: ThresholdUuid,
+    /// Only count an outlier on this side of the member threshold boundary.
+    /// If not set, an outlier on either side counts.
+    pub limit: Option<BoundaryLimit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompositeThresholds(pub Vec<JsonCompositeThreshold>);
+
+crate::from_vec!(JsonCompositeThresholds[JsonCompositeThreshold]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompositeThreshold {
+    pub uuid: CompositeThresholdUuid,
+    pub project: ProjectUuid,
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub operator: CompositeOperator,
+    pub members: Vec<JsonCompositeMember>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompositeMember {
+    pub threshold: ThresholdUuid,
+    pub measure: JsonMeasure,
+    pub limit: Option<BoundaryLimit>,
+}
+
+const AND_INT: i32 = 0;
+const OR_INT: i32 = 1;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum CompositeOperator {
+    And = AND_INT,
+    Or = OR_INT,
+}
+
+impl CompositeOperator {
+    /// Combine whether each of the members detected an outlier.
+    pub fn combine<I>(self, outliers: I) -> bool
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut outliers = outliers.into_iter().peekable();
+        // A composite without any members never generates an alert
+        if outliers.peek().is_none() {
+            return false;
+        }
+        match self {
+            Self::And => outliers.all(|outlier| outlier),
+            Self::Or => outliers.any(|outlier| outlier),
+        }
+    }
+}
+
+#[cfg(feature = "db")]
+mod composite_operator {
+    use super::{CompositeOperator, AND_INT, OR_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum CompositeOperatorError {
+        #[error("Invalid composite operator value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for CompositeOperator
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::And => AND_INT.to_sql(out),
+                Self::Or => OR_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for CompositeOperator
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                AND_INT => Ok(Self::And),
+                OR_INT => Ok(Self::Or),
+                value => Err(Box::new(CompositeOperatorError::Invalid(value))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CompositeOperator;
+
+    #[test]
+    fn test_composite_operator_and() {
+        assert!(CompositeOperator::And.combine([true, true]));
+        assert!(!CompositeOperator::And.combine([true, false]));
+        assert!(!CompositeOperator::And.combine([false, false]));
+        assert!(!CompositeOperator::And.combine([]));
+    }
+
+    #[test]
+    fn test_composite_operator_or() {
+        assert!(CompositeOperator::Or.combine([true, true]));
+        assert!(CompositeOperator::Or.combine([false, true]));
+        assert!(!CompositeOperator::Or.combine([false, false]));
+        assert!(!CompositeOperator::Or.combine([]));
+    }
+}
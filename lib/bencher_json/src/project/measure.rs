@@ -110,6 +110,182 @@ static ESTIMATED_CYCLES_UNITS: Lazy<ResourceName> = Lazy::new(|| {
         .expect(MEASURE_UNITS_ERROR)
 });
 
+// Allocation measures
+
+pub const ALLOCATIONS_NAME_STR: &str = "Allocations";
+pub const ALLOCATIONS_SLUG_STR: &str = "allocations";
+pub const ALLOCATIONS_UNITS_STR: &str = "allocations / operation (allocs/op)";
+
+static ALLOCATIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATIONS_SLUG: Lazy<Slug> =
+    Lazy::new(|| ALLOCATIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ALLOCATIONS_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATIONS_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const ALLOCATED_BYTES_NAME_STR: &str = "Allocated Bytes";
+pub const ALLOCATED_BYTES_SLUG_STR: &str = "allocated-bytes";
+pub const ALLOCATED_BYTES_UNITS_STR: &str = "bytes / operation (B/op)";
+
+static ALLOCATED_BYTES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATED_BYTES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATED_BYTES_SLUG: Lazy<Slug> =
+    Lazy::new(|| ALLOCATED_BYTES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ALLOCATED_BYTES_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    ALLOCATED_BYTES_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+// Garbage collection measures
+
+pub const GEN0_COLLECTIONS_NAME_STR: &str = "Gen 0 Collections";
+pub const GEN0_COLLECTIONS_SLUG_STR: &str = "gen0-collections";
+pub const GEN0_COLLECTIONS_UNITS_STR: &str = "collections / 1,000 operations";
+
+static GEN0_COLLECTIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| GEN0_COLLECTIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static GEN0_COLLECTIONS_SLUG: Lazy<Slug> =
+    Lazy::new(|| GEN0_COLLECTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static GEN0_COLLECTIONS_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    GEN0_COLLECTIONS_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+pub const GEN1_COLLECTIONS_NAME_STR: &str = "Gen 1 Collections";
+pub const GEN1_COLLECTIONS_SLUG_STR: &str = "gen1-collections";
+pub const GEN1_COLLECTIONS_UNITS_STR: &str = "collections / 1,000 operations";
+
+static GEN1_COLLECTIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| GEN1_COLLECTIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static GEN1_COLLECTIONS_SLUG: Lazy<Slug> =
+    Lazy::new(|| GEN1_COLLECTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static GEN1_COLLECTIONS_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    GEN1_COLLECTIONS_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+pub const GEN2_COLLECTIONS_NAME_STR: &str = "Gen 2 Collections";
+pub const GEN2_COLLECTIONS_SLUG_STR: &str = "gen2-collections";
+pub const GEN2_COLLECTIONS_UNITS_STR: &str = "collections / 1,000 operations";
+
+static GEN2_COLLECTIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| GEN2_COLLECTIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static GEN2_COLLECTIONS_SLUG: Lazy<Slug> =
+    Lazy::new(|| GEN2_COLLECTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static GEN2_COLLECTIONS_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    GEN2_COLLECTIONS_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+pub const ALLOCATION_RATE_NAME_STR: &str = "Allocation Rate";
+pub const ALLOCATION_RATE_SLUG_STR: &str = "allocation-rate";
+pub const ALLOCATION_RATE_UNITS_STR: &str = "megabytes / second (MB/s)";
+
+static ALLOCATION_RATE_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATION_RATE_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATION_RATE_SLUG: Lazy<Slug> =
+    Lazy::new(|| ALLOCATION_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ALLOCATION_RATE_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    ALLOCATION_RATE_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+pub const GC_COUNT_NAME_STR: &str = "GC Count";
+pub const GC_COUNT_SLUG_STR: &str = "gc-count";
+pub const GC_COUNT_UNITS_STR: &str = "collections (count)";
+
+static GC_COUNT_NAME: Lazy<ResourceName> =
+    Lazy::new(|| GC_COUNT_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static GC_COUNT_SLUG: Lazy<Slug> =
+    Lazy::new(|| GC_COUNT_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static GC_COUNT_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| GC_COUNT_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const GC_TIME_NAME_STR: &str = "GC Time";
+pub const GC_TIME_SLUG_STR: &str = "gc-time";
+pub const GC_TIME_UNITS_STR: &str = "milliseconds (ms)";
+
+static GC_TIME_NAME: Lazy<ResourceName> =
+    Lazy::new(|| GC_TIME_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static GC_TIME_SLUG: Lazy<Slug> = Lazy::new(|| GC_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static GC_TIME_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| GC_TIME_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+// Load test measures
+
+pub const LATENCY_P50_NAME_STR: &str = "Latency p50";
+pub const LATENCY_P50_SLUG_STR: &str = "latency-p50";
+pub const LATENCY_P50_UNITS_STR: &str = "nanoseconds (ns)";
+
+static LATENCY_P50_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P50_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_P50_SLUG: Lazy<Slug> =
+    Lazy::new(|| LATENCY_P50_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static LATENCY_P50_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P50_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const LATENCY_P90_NAME_STR: &str = "Latency p90";
+pub const LATENCY_P90_SLUG_STR: &str = "latency-p90";
+pub const LATENCY_P90_UNITS_STR: &str = "nanoseconds (ns)";
+
+static LATENCY_P90_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P90_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_P90_SLUG: Lazy<Slug> =
+    Lazy::new(|| LATENCY_P90_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static LATENCY_P90_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P90_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const LATENCY_P99_NAME_STR: &str = "Latency p99";
+pub const LATENCY_P99_SLUG_STR: &str = "latency-p99";
+pub const LATENCY_P99_UNITS_STR: &str = "nanoseconds (ns)";
+
+static LATENCY_P99_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P99_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_P99_SLUG: Lazy<Slug> =
+    Lazy::new(|| LATENCY_P99_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static LATENCY_P99_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_P99_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const ERROR_RATE_NAME_STR: &str = "Error Rate";
+pub const ERROR_RATE_SLUG_STR: &str = "error-rate";
+pub const ERROR_RATE_UNITS_STR: &str = "percent (%)";
+
+static ERROR_RATE_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ERROR_RATE_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ERROR_RATE_SLUG: Lazy<Slug> =
+    Lazy::new(|| ERROR_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static ERROR_RATE_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| ERROR_RATE_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+// CPU time measures
+
+pub const USER_TIME_NAME_STR: &str = "User Time";
+pub const USER_TIME_SLUG_STR: &str = "user-time";
+pub const USER_TIME_UNITS_STR: &str = "nanoseconds (ns)";
+
+static USER_TIME_NAME: Lazy<ResourceName> =
+    Lazy::new(|| USER_TIME_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static USER_TIME_SLUG: Lazy<Slug> =
+    Lazy::new(|| USER_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static USER_TIME_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| USER_TIME_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const SYSTEM_TIME_NAME_STR: &str = "System Time";
+pub const SYSTEM_TIME_SLUG_STR: &str = "system-time";
+pub const SYSTEM_TIME_UNITS_STR: &str = "nanoseconds (ns)";
+
+static SYSTEM_TIME_NAME: Lazy<ResourceName> =
+    Lazy::new(|| SYSTEM_TIME_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static SYSTEM_TIME_SLUG: Lazy<Slug> =
+    Lazy::new(|| SYSTEM_TIME_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+static SYSTEM_TIME_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| SYSTEM_TIME_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
 // File size measures
 
 pub const FILE_SIZE_NAME_STR: &str = "File Size";
@@ -206,6 +382,118 @@ impl JsonNewMeasure {
         }
     }
 
+    pub fn allocations() -> Self {
+        Self {
+            name: ALLOCATIONS_NAME.clone(),
+            slug: Some(ALLOCATIONS_SLUG.clone()),
+            units: ALLOCATIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn allocated_bytes() -> Self {
+        Self {
+            name: ALLOCATED_BYTES_NAME.clone(),
+            slug: Some(ALLOCATED_BYTES_SLUG.clone()),
+            units: ALLOCATED_BYTES_UNITS.clone(),
+        }
+    }
+
+    pub fn gen0_collections() -> Self {
+        Self {
+            name: GEN0_COLLECTIONS_NAME.clone(),
+            slug: Some(GEN0_COLLECTIONS_SLUG.clone()),
+            units: GEN0_COLLECTIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn gen1_collections() -> Self {
+        Self {
+            name: GEN1_COLLECTIONS_NAME.clone(),
+            slug: Some(GEN1_COLLECTIONS_SLUG.clone()),
+            units: GEN1_COLLECTIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn gen2_collections() -> Self {
+        Self {
+            name: GEN2_COLLECTIONS_NAME.clone(),
+            slug: Some(GEN2_COLLECTIONS_SLUG.clone()),
+            units: GEN2_COLLECTIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn allocation_rate() -> Self {
+        Self {
+            name: ALLOCATION_RATE_NAME.clone(),
+            slug: Some(ALLOCATION_RATE_SLUG.clone()),
+            units: ALLOCATION_RATE_UNITS.clone(),
+        }
+    }
+
+    pub fn gc_count() -> Self {
+        Self {
+            name: GC_COUNT_NAME.clone(),
+            slug: Some(GC_COUNT_SLUG.clone()),
+            units: GC_COUNT_UNITS.clone(),
+        }
+    }
+
+    pub fn gc_time() -> Self {
+        Self {
+            name: GC_TIME_NAME.clone(),
+            slug: Some(GC_TIME_SLUG.clone()),
+            units: GC_TIME_UNITS.clone(),
+        }
+    }
+
+    pub fn latency_p50() -> Self {
+        Self {
+            name: LATENCY_P50_NAME.clone(),
+            slug: Some(LATENCY_P50_SLUG.clone()),
+            units: LATENCY_P50_UNITS.clone(),
+        }
+    }
+
+    pub fn latency_p90() -> Self {
+        Self {
+            name: LATENCY_P90_NAME.clone(),
+            slug: Some(LATENCY_P90_SLUG.clone()),
+            units: LATENCY_P90_UNITS.clone(),
+        }
+    }
+
+    pub fn latency_p99() -> Self {
+        Self {
+            name: LATENCY_P99_NAME.clone(),
+            slug: Some(LATENCY_P99_SLUG.clone()),
+            units: LATENCY_P99_UNITS.clone(),
+        }
+    }
+
+    pub fn error_rate() -> Self {
+        Self {
+            name: ERROR_RATE_NAME.clone(),
+            slug: Some(ERROR_RATE_SLUG.clone()),
+            units: ERROR_RATE_UNITS.clone(),
+        }
+    }
+
+    pub fn user_time() -> Self {
+        Self {
+            name: USER_TIME_NAME.clone(),
+            slug: Some(USER_TIME_SLUG.clone()),
+            units: USER_TIME_UNITS.clone(),
+        }
+    }
+
+    pub fn system_time() -> Self {
+        Self {
+            name: SYSTEM_TIME_NAME.clone(),
+            slug: Some(SYSTEM_TIME_SLUG.clone()),
+            units: SYSTEM_TIME_UNITS.clone(),
+        }
+    }
+
     pub fn file_size() -> Self {
         Self {
             name: FILE_SIZE_NAME.clone(),
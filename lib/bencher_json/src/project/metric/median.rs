@@ -2,8 +2,7 @@ pub trait Median {
     #[allow(clippy::indexing_slicing, clippy::integer_division)]
     fn median(mut array: Vec<Self>) -> Option<Self>
     where
-        Self:
-            Copy + Clone + Ord + std::ops::Add<Output = Self> + std::ops::Div<usize, Output = Self>,
+        Self: Clone + Ord + std::ops::Add<Output = Self> + std::ops::Div<usize, Output = Self>,
     {
         if array.is_empty() {
             return None;
@@ -15,9 +14,9 @@ pub trait Median {
         if (size % 2) == 0 {
             let left = size / 2 - 1;
             let right = size / 2;
-            Some((array[left] + array[right]) / 2)
+            Some((array[left].clone() + array[right].clone()) / 2)
         } else {
-            Some(array[size / 2])
+            Some(array.swap_remove(size / 2))
         }
     }
 }
@@ -31,12 +31,15 @@ pub type JsonMetricsMap = HashMap<MeasureNameId, JsonNewMetric>;
 pub type MeasureNameId = NameId;
 
 #[typeshare::typeshare]
-#[derive(Debug, Copy, Clone, Default, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonNewMetric {
     pub value: OrderedFloat<f64>,
     pub lower_value: Option<OrderedFloat<f64>>,
     pub upper_value: Option<OrderedFloat<f64>>,
+    /// The raw samples that the value was computed from, if the harness reports them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samples: Option<Vec<OrderedFloat<f64>>>,
 }
 
 impl JsonNewMetric {
@@ -50,6 +53,7 @@ impl JsonNewMetric {
     }
 }
 
+// The raw samples are not considered when comparing metrics
 impl PartialEq for JsonNewMetric {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
@@ -102,10 +106,19 @@ impl Add for JsonNewMetric {
         let value = self.value + other.value;
         let lower_value = option_add(self.lower_value, self.value, other.lower_value, other.value);
         let upper_value = option_add(self.upper_value, self.value, other.upper_value, other.value);
+        // Pool the raw samples from both metrics
+        let samples = match (self.samples, other.samples) {
+            (Some(mut samples), Some(other_samples)) => {
+                samples.extend(other_samples);
+                Some(samples)
+            },
+            (samples, other_samples) => samples.or(other_samples),
+        };
         Self {
             value,
             lower_value,
             upper_value,
+            samples,
         }
     }
 }
@@ -148,6 +161,7 @@ impl std::ops::Div<usize> for JsonNewMetric {
             value: self.value / rhs as f64,
             lower_value: self.lower_value.map(|b| b / rhs as f64),
             upper_value: self.upper_value.map(|b| b / rhs as f64),
+            samples: self.samples,
         }
     }
 }
@@ -186,6 +200,8 @@ pub struct JsonOneMetric {
     pub benchmark: JsonBenchmark,
     pub measure: JsonMeasure,
     pub metric: JsonMetric,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samples: Option<Vec<OrderedFloat<f64>>>,
     pub threshold: Option<JsonThresholdModel>,
     pub boundary: Option<JsonBoundary>,
     pub alert: Option<JsonPerfAlert>,
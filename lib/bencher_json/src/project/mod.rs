@@ -15,6 +15,8 @@ pub mod alert;
 pub mod benchmark;
 pub mod boundary;
 pub mod branch;
+pub mod change_point;
+pub mod composite_threshold;
 pub mod measure;
 pub mod metric;
 pub mod model;
@@ -23,6 +25,7 @@ pub mod plot;
 pub mod report;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::typed_uuid::typed_uuid!(ProjectUuid);
 
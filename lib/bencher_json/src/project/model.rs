@@ -1,4 +1,4 @@
-use bencher_valid::{Boundary, DateTime, ModelTest, SampleSize, Window};
+use bencher_valid::{Boundary, DateTime, ModelTest, SampleSize, Smoothing, Window};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -19,6 +19,8 @@ pub struct JsonModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub level_smoothing: Option<Smoothing>,
+    pub trend_smoothing: Option<Smoothing>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
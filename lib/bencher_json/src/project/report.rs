@@ -85,7 +85,7 @@ impl JsonReportStartPoint {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReportSettings {
     /// The benchmark harness adapter for parsing the benchmark results.
@@ -97,6 +97,63 @@ pub struct JsonReportSettings {
     /// Fold multiple results into a single result using the selected operation.
     /// This can be useful for taking the min, max, mean, or median of the benchmark results.
     pub fold: Option<JsonFold>,
+    /// Settings for the CSV adapter.
+    /// This is only used if the adapter is set to `csv`.
+    pub csv: Option<JsonCsvSettings>,
+    /// Strip the `-cpu` suffix (ie `BenchmarkFoo-8`) from Go benchmark names.
+    /// This is only used by the `go_bench` adapter.
+    /// By default, the suffix is kept as part of the benchmark name.
+    pub go_strip_cpu: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCsvSettings {
+    /// The delimiter between columns.
+    /// If not specified, then it will be detected from the first row.
+    pub delimiter: Option<JsonCsvDelimiter>,
+    /// Whether the first row is a header.
+    /// If not specified, then the first row is a header if it does not contain any numbers
+    /// or if any column is referenced by name.
+    pub header: Option<bool>,
+    /// The column with the benchmark name, either as a header name or a zero-based index.
+    /// If not specified, then the first column will be used.
+    pub benchmark: Option<String>,
+    /// The columns to map to measures.
+    /// If not specified, then every other column will be used as a measure named after its header.
+    /// Without a header, the second column will be used as the Latency measure.
+    pub measures: Option<Vec<JsonCsvMeasure>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonCsvDelimiter {
+    Comma,
+    Tab,
+}
+
+impl JsonCsvDelimiter {
+    pub fn as_byte(self) -> u8 {
+        match self {
+            Self::Comma => b',',
+            Self::Tab => b'\t',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCsvMeasure {
+    /// Measure UUID, slug, or name.
+    /// If the measure does not exist, it will be created.
+    pub measure: NameId,
+    /// The column with the metric value, either as a header name or a zero-based index.
+    pub value: String,
+    /// The column with the metric lower value, either as a header name or a zero-based index.
+    pub lower_value: Option<String>,
+    /// The column with the metric upper value, either as a header name or a zero-based index.
+    pub upper_value: Option<String>,
 }
 
 const MAGIC_INT: i32 = 0;
@@ -106,9 +163,11 @@ const RUST_BENCH_INT: i32 = 21;
 const RUST_CRITERION_INT: i32 = 22;
 const RUST_IAI_INT: i32 = 23;
 const RUST_IAI_CALLGRIND_INT: i32 = 24;
+const RUST_DIVAN_INT: i32 = 25;
 const CPP_INT: i32 = 30;
 const CPP_GOOGLE_INT: i32 = 31;
 const CPP_CATCH2_INT: i32 = 32;
+const CPP_GOOGLE_JSON_INT: i32 = 33;
 const GO_INT: i32 = 40;
 const GO_BENCH_INT: i32 = 41;
 const JAVA_INT: i32 = 50;
@@ -125,6 +184,16 @@ const RUBY_INT: i32 = 90;
 const RUBY_BENCHMARK_INT: i32 = 91;
 const SHELL_INT: i32 = 100;
 const SHELL_HYPERFINE_INT: i32 = 101;
+const SHELL_PERF_STAT_INT: i32 = 102;
+const TEST_INT: i32 = 110;
+const TEST_JUNIT_INT: i32 = 111;
+const TEST_TAP_INT: i32 = 112;
+const CSV_INT: i32 = 120;
+const OPEN_METRICS_INT: i32 = 130;
+const LOAD_INT: i32 = 140;
+const LOAD_K6_INT: i32 = 141;
+const LOAD_WRK_INT: i32 = 142;
+const LOAD_VEGETA_INT: i32 = 143;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -142,9 +211,11 @@ pub enum Adapter {
     RustCriterion = RUST_CRITERION_INT,
     RustIai = RUST_IAI_INT,
     RustIaiCallgrind = RUST_IAI_CALLGRIND_INT,
+    RustDivan = RUST_DIVAN_INT,
     Cpp = CPP_INT,
     CppGoogle = CPP_GOOGLE_INT,
     CppCatch2 = CPP_CATCH2_INT,
+    CppGoogleJson = CPP_GOOGLE_JSON_INT,
     Go = GO_INT,
     GoBench = GO_BENCH_INT,
     Java = JAVA_INT,
@@ -161,6 +232,16 @@ pub enum Adapter {
     RubyBenchmark = RUBY_BENCHMARK_INT,
     Shell = SHELL_INT,
     ShellHyperfine = SHELL_HYPERFINE_INT,
+    ShellPerfStat = SHELL_PERF_STAT_INT,
+    Test = TEST_INT,
+    TestJunit = TEST_JUNIT_INT,
+    TestTap = TEST_TAP_INT,
+    Csv = CSV_INT,
+    OpenMetrics = OPEN_METRICS_INT,
+    Load = LOAD_INT,
+    LoadK6 = LOAD_K6_INT,
+    LoadWrk = LOAD_WRK_INT,
+    LoadVegeta = LOAD_VEGETA_INT,
 }
 
 impl fmt::Display for Adapter {
@@ -173,9 +254,11 @@ impl fmt::Display for Adapter {
             Self::RustCriterion => write!(f, "rust_criterion"),
             Self::RustIai => write!(f, "rust_iai"),
             Self::RustIaiCallgrind => write!(f, "rust_iai_callgrind"),
+            Self::RustDivan => write!(f, "rust_divan"),
             Self::Cpp => write!(f, "cpp"),
             Self::CppGoogle => write!(f, "cpp_google"),
             Self::CppCatch2 => write!(f, "cpp_catch2"),
+            Self::CppGoogleJson => write!(f, "cpp_google_json"),
             Self::Go => write!(f, "go"),
             Self::GoBench => write!(f, "go_bench"),
             Self::Java => write!(f, "java"),
@@ -192,6 +275,16 @@ impl fmt::Display for Adapter {
             Self::RubyBenchmark => write!(f, "ruby_benchmark"),
             Self::Shell => write!(f, "shell"),
             Self::ShellHyperfine => write!(f, "shell_hyperfine"),
+            Self::ShellPerfStat => write!(f, "shell_perf_stat"),
+            Self::Test => write!(f, "test"),
+            Self::TestJunit => write!(f, "test_junit"),
+            Self::TestTap => write!(f, "test_tap"),
+            Self::Csv => write!(f, "csv"),
+            Self::OpenMetrics => write!(f, "open_metrics"),
+            Self::Load => write!(f, "load"),
+            Self::LoadK6 => write!(f, "load_k6"),
+            Self::LoadWrk => write!(f, "load_wrk"),
+            Self::LoadVegeta => write!(f, "load_vegeta"),
         }
     }
 }
@@ -199,11 +292,13 @@ impl fmt::Display for Adapter {
 #[cfg(feature = "db")]
 mod adapter {
     use super::{
-        Adapter, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_INT, C_SHARP_DOT_NET_INT, C_SHARP_INT,
-        GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT, JS_BENCHMARK_INT, JS_INT,
-        JS_TIME_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
-        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_IAI_CALLGRIND_INT, RUST_IAI_INT,
-        RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        Adapter, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_GOOGLE_JSON_INT, CPP_INT, CSV_INT,
+        C_SHARP_DOT_NET_INT, C_SHARP_INT, GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT,
+        JS_BENCHMARK_INT, JS_INT, JS_TIME_INT, LOAD_INT, LOAD_K6_INT, LOAD_VEGETA_INT,
+        LOAD_WRK_INT, MAGIC_INT, OPEN_METRICS_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT,
+        RUBY_BENCHMARK_INT, RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_DIVAN_INT,
+        RUST_IAI_CALLGRIND_INT, RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        SHELL_PERF_STAT_INT, TEST_INT, TEST_JUNIT_INT, TEST_TAP_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -229,9 +324,11 @@ mod adapter {
                 Self::RustCriterion => RUST_CRITERION_INT.to_sql(out),
                 Self::RustIai => RUST_IAI_INT.to_sql(out),
                 Self::RustIaiCallgrind => RUST_IAI_CALLGRIND_INT.to_sql(out),
+                Self::RustDivan => RUST_DIVAN_INT.to_sql(out),
                 Self::Cpp => CPP_INT.to_sql(out),
                 Self::CppGoogle => CPP_GOOGLE_INT.to_sql(out),
                 Self::CppCatch2 => CPP_CATCH2_INT.to_sql(out),
+                Self::CppGoogleJson => CPP_GOOGLE_JSON_INT.to_sql(out),
                 Self::Go => GO_INT.to_sql(out),
                 Self::GoBench => GO_BENCH_INT.to_sql(out),
                 Self::Java => JAVA_INT.to_sql(out),
@@ -248,6 +345,16 @@ mod adapter {
                 Self::RubyBenchmark => RUBY_BENCHMARK_INT.to_sql(out),
                 Self::Shell => SHELL_INT.to_sql(out),
                 Self::ShellHyperfine => SHELL_HYPERFINE_INT.to_sql(out),
+                Self::ShellPerfStat => SHELL_PERF_STAT_INT.to_sql(out),
+                Self::Test => TEST_INT.to_sql(out),
+                Self::TestJunit => TEST_JUNIT_INT.to_sql(out),
+                Self::TestTap => TEST_TAP_INT.to_sql(out),
+                Self::Csv => CSV_INT.to_sql(out),
+                Self::OpenMetrics => OPEN_METRICS_INT.to_sql(out),
+                Self::Load => LOAD_INT.to_sql(out),
+                Self::LoadK6 => LOAD_K6_INT.to_sql(out),
+                Self::LoadWrk => LOAD_WRK_INT.to_sql(out),
+                Self::LoadVegeta => LOAD_VEGETA_INT.to_sql(out),
             }
         }
     }
@@ -266,9 +373,11 @@ mod adapter {
                 RUST_CRITERION_INT => Ok(Self::RustCriterion),
                 RUST_IAI_INT => Ok(Self::RustIai),
                 RUST_IAI_CALLGRIND_INT => Ok(Self::RustIaiCallgrind),
+                RUST_DIVAN_INT => Ok(Self::RustDivan),
                 CPP_INT => Ok(Self::Cpp),
                 CPP_GOOGLE_INT => Ok(Self::CppGoogle),
                 CPP_CATCH2_INT => Ok(Self::CppCatch2),
+                CPP_GOOGLE_JSON_INT => Ok(Self::CppGoogleJson),
                 GO_INT => Ok(Self::Go),
                 GO_BENCH_INT => Ok(Self::GoBench),
                 JAVA_INT => Ok(Self::Java),
@@ -285,6 +394,16 @@ mod adapter {
                 RUBY_BENCHMARK_INT => Ok(Self::RubyBenchmark),
                 SHELL_INT => Ok(Self::Shell),
                 SHELL_HYPERFINE_INT => Ok(Self::ShellHyperfine),
+                SHELL_PERF_STAT_INT => Ok(Self::ShellPerfStat),
+                TEST_INT => Ok(Self::Test),
+                TEST_JUNIT_INT => Ok(Self::TestJunit),
+                TEST_TAP_INT => Ok(Self::TestTap),
+                CSV_INT => Ok(Self::Csv),
+                OPEN_METRICS_INT => Ok(Self::OpenMetrics),
+                LOAD_INT => Ok(Self::Load),
+                LOAD_K6_INT => Ok(Self::LoadK6),
+                LOAD_WRK_INT => Ok(Self::LoadWrk),
+                LOAD_VEGETA_INT => Ok(Self::LoadVegeta),
                 value => Err(Box::new(AdapterError::Invalid(value))),
             }
         }
@@ -1,28 +1,56 @@
-use bencher_valid::{DateTime, Model, NameId};
+use bencher_valid::{BenchmarkSelector, DateTime, Model, NameId};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     urlencoded::{from_urlencoded, to_urlencoded, UrlEncodedError},
-    JsonBranch, JsonMeasure, JsonModel, JsonTestbed, ProjectUuid,
+    JsonBranch, JsonCompositeThreshold, JsonMeasure, JsonModel, JsonNewCompositeThreshold,
+    JsonTestbed, ProjectUuid,
 };
 
 crate::typed_uuid::typed_uuid!(ThresholdUuid);
 
+/// A new threshold is either for a single measure
+/// or a composite of existing single-measure thresholds.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
-pub struct JsonNewThreshold {
+#[serde(untagged)]
+pub enum JsonNewThreshold {
+    Single(JsonNewSingleThreshold),
+    Composite(JsonNewCompositeThreshold),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewSingleThreshold {
     /// The UUID, slug, or name of the threshold branch.
     pub branch: NameId,
     /// The UUID, slug, or name of the threshold testbed.
     pub testbed: NameId,
     /// The UUID, slug, or name of the threshold measure.
     pub measure: NameId,
+    /// Only apply the threshold to benchmarks matching this selector.
+    /// The selector can be an exact benchmark name,
+    /// a glob containing `*` or `?`,
+    /// or a regular expression wrapped in slashes (`/.../`).
+    /// If there are multiple thresholds for the same branch, testbed, and measure,
+    /// then the most specific matching threshold is used:
+    /// exact name, then glob, then regular expression, and finally a threshold without a selector.
+    pub benchmark: Option<BenchmarkSelector>,
     #[serde(flatten)]
     pub model: Model,
 }
 
+/// The created threshold, matching the kind of new threshold.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum JsonCreatedThreshold {
+    Single(Box<JsonThreshold>),
+    Composite(Box<JsonCompositeThreshold>),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonThresholds(pub Vec<JsonThreshold>);
@@ -38,6 +66,7 @@ pub struct JsonThreshold {
     pub branch: JsonBranch,
     pub testbed: JsonTestbed,
     pub measure: JsonMeasure,
+    pub benchmark: Option<BenchmarkSelector>,
     // TODO remove in due time
     pub statistic: Option<JsonModel>,
     pub model: JsonModel,
@@ -129,3 +158,34 @@ pub struct JsonUpdateThreshold {
     #[serde(flatten)]
     pub model: Model,
 }
+
+#[cfg(test)]
+mod test {
+    use super::JsonNewThreshold;
+
+    #[test]
+    fn test_new_threshold_single() {
+        let json_threshold: JsonNewThreshold = serde_json::from_value(serde_json::json!({
+            "branch": "main",
+            "testbed": "localhost",
+            "measure": "latency",
+            "test": "t_test",
+            "upper_boundary": 0.99,
+        }))
+        .unwrap();
+        assert!(matches!(json_threshold, JsonNewThreshold::Single(_)));
+    }
+
+    #[test]
+    fn test_new_threshold_composite() {
+        let json_threshold: JsonNewThreshold = serde_json::from_value(serde_json::json!({
+            "operator": "and",
+            "members": [
+                { "threshold": "4cb5d5a6-8a34-4e0a-9f4c-7b3e0e3f2a01" },
+                { "threshold": "7d0a4e21-2b5c-4b1f-8f6d-3c9a1e5b7d02", "limit": "upper" },
+            ],
+        }))
+        .unwrap();
+        assert!(matches!(json_threshold, JsonNewThreshold::Composite(_)));
+    }
+}
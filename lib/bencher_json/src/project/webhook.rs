@@ -0,0 +1,261 @@
+use bencher_valid::{DateTime, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonAlert, JsonReport, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(WebhookUuid);
+crate::typed_uuid::typed_uuid!(WebhookDeliveryUuid);
+
+/// The HTTP header with the HMAC-SHA256 signature of the webhook request body.
+/// The signature is the lowercase hex encoded digest, prefixed with `sha256=`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Bencher-Signature";
+/// The HTTP header with the webhook event type.
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Bencher-Event";
+/// The HTTP header with the UUID of the webhook delivery.
+/// Retried deliveries keep the same UUID.
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Bencher-Delivery";
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    /// The URL to send webhook deliveries to.
+    pub url: Url,
+    /// The secret used to sign webhook deliveries.
+    /// Each delivery has an HMAC-SHA256 signature of its body in the `X-Bencher-Signature` header.
+    pub secret: Secret,
+    /// The events to subscribe to.
+    /// There must be at least one event.
+    pub events: Vec<WebhookEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhooks(pub Vec<JsonWebhook>);
+
+crate::from_vec!(JsonWebhooks[JsonWebhook]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub events: Vec<WebhookEvent>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateWebhook {
+    /// The new URL to send webhook deliveries to.
+    pub url: Option<Url>,
+    /// The new secret used to sign webhook deliveries.
+    pub secret: Option<Secret>,
+    /// The new events to subscribe to.
+    /// This replaces all of the existing events.
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+const ALERT_CREATED_INT: i32 = 0;
+const ALERT_RESOLVED_INT: i32 = 1;
+const REPORT_CREATED_INT: i32 = 2;
+const TEST_INT: i32 = 3;
+
+#[typeshare::typeshare]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_more::Display,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum WebhookEvent {
+    /// A new alert was generated.
+    #[display(fmt = "alert_created")]
+    AlertCreated = ALERT_CREATED_INT,
+    /// An alert was resolved.
+    #[display(fmt = "alert_resolved")]
+    AlertResolved = ALERT_RESOLVED_INT,
+    /// A new report was created.
+    #[display(fmt = "report_created")]
+    ReportCreated = REPORT_CREATED_INT,
+    /// A test delivery.
+    /// Test deliveries are always sent, so this event can not be subscribed to.
+    #[display(fmt = "test")]
+    Test = TEST_INT,
+}
+
+#[cfg(feature = "db")]
+mod webhook_event {
+    use super::{
+        WebhookEvent, ALERT_CREATED_INT, ALERT_RESOLVED_INT, REPORT_CREATED_INT, TEST_INT,
+    };
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum WebhookEventError {
+        #[error("Invalid webhook event value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::AlertCreated => ALERT_CREATED_INT.to_sql(out),
+                Self::AlertResolved => ALERT_RESOLVED_INT.to_sql(out),
+                Self::ReportCreated => REPORT_CREATED_INT.to_sql(out),
+                Self::Test => TEST_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                ALERT_CREATED_INT => Ok(Self::AlertCreated),
+                ALERT_RESOLVED_INT => Ok(Self::AlertResolved),
+                REPORT_CREATED_INT => Ok(Self::ReportCreated),
+                TEST_INT => Ok(Self::Test),
+                value => Err(Box::new(WebhookEventError::Invalid(value))),
+            }
+        }
+    }
+}
+
+/// The body of a webhook delivery.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JsonWebhookPayload {
+    pub event: WebhookEvent,
+    pub project: ProjectUuid,
+    /// The alert for `alert_created` and `alert_resolved` events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert: Option<JsonAlert>,
+    /// The report for `report_created` events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report: Option<JsonReport>,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDeliveries(pub Vec<JsonWebhookDelivery>);
+
+crate::from_vec!(JsonWebhookDeliveries[JsonWebhookDelivery]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook: WebhookUuid,
+    pub event: WebhookEvent,
+    pub status: WebhookDeliveryStatus,
+    /// The JSON body of the delivery.
+    pub payload: String,
+    /// The number of delivery attempts so far.
+    pub attempts: u32,
+    /// When the next delivery attempt will be made, if the delivery is still pending.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_attempt: Option<DateTime>,
+    /// The HTTP status code of the response to the last delivery attempt, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<u16>,
+    /// The error from the last delivery attempt, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+const PENDING_INT: i32 = 0;
+const DELIVERED_INT: i32 = 1;
+const FAILED_INT: i32 = 2;
+
+#[typeshare::typeshare]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum WebhookDeliveryStatus {
+    /// The delivery is waiting to be sent or retried.
+    #[default]
+    Pending = PENDING_INT,
+    /// The delivery received a successful (2xx) response.
+    Delivered = DELIVERED_INT,
+    /// The delivery ran out of attempts without a successful response.
+    Failed = FAILED_INT,
+}
+
+#[cfg(feature = "db")]
+mod webhook_delivery_status {
+    use super::{WebhookDeliveryStatus, DELIVERED_INT, FAILED_INT, PENDING_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum WebhookDeliveryStatusError {
+        #[error("Invalid webhook delivery status value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for WebhookDeliveryStatus
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Pending => PENDING_INT.to_sql(out),
+                Self::Delivered => DELIVERED_INT.to_sql(out),
+                Self::Failed => FAILED_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for WebhookDeliveryStatus
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                PENDING_INT => Ok(Self::Pending),
+                DELIVERED_INT => Ok(Self::Delivered),
+                FAILED_INT => Ok(Self::Failed),
+                value => Err(Box::new(WebhookDeliveryStatusError::Invalid(value))),
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonProject, UserUuid};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNotifications(pub Vec<JsonNotification>);
+
+crate::from_vec!(JsonNotifications[JsonNotification]);
+
+/// The notification preferences of a user for a project
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNotification {
+    pub user: UserUuid,
+    pub project: JsonProject,
+    /// Whether the user is emailed when a report for the project generates alerts.
+    pub alert_email: bool,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateNotification {
+    /// Update whether the user is emailed when a report for the project generates alerts.
+    pub alert_email: Option<bool>,
+}
@@ -0,0 +1,248 @@
+use derive_more::Display;
+#[cfg(all(feature = "full", not(feature = "lite")))]
+use regex::{escape, Regex};
+#[cfg(feature = "lite")]
+use regex_lite::{escape, Regex};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::{benchmark_name::is_valid_benchmark_name, ValidError};
+
+const REGEX_DELIMITER: char = '/';
+const GLOB_ANY: char = '*';
+const GLOB_ONE: char = '?';
+
+/// Select benchmarks by name.
+/// A selector wrapped in slashes (`/^foo_.*$/`) is a regular expression.
+/// A selector containing `*` or `?` is a glob.
+/// Otherwise, the selector is an exact benchmark name.
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct BenchmarkSelector(String);
+
+#[cfg(feature = "db")]
+crate::typed_string!(BenchmarkSelector);
+
+/// The kind of benchmark selector, ordered from least to most specific
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BenchmarkSelectorKind {
+    Regex,
+    Glob,
+    Exact,
+}
+
+#[derive(Debug, Clone)]
+pub enum BenchmarkMatcher {
+    Regex(Regex),
+    Glob(Regex),
+    Exact(String),
+}
+
+impl BenchmarkSelector {
+    pub fn kind(&self) -> BenchmarkSelectorKind {
+        selector_kind(&self.0)
+    }
+
+    pub fn matcher(&self) -> Result<BenchmarkMatcher, ValidError> {
+        selector_matcher(&self.0)
+    }
+}
+
+impl BenchmarkMatcher {
+    pub fn kind(&self) -> BenchmarkSelectorKind {
+        match self {
+            Self::Regex(_) => BenchmarkSelectorKind::Regex,
+            Self::Glob(_) => BenchmarkSelectorKind::Glob,
+            Self::Exact(_) => BenchmarkSelectorKind::Exact,
+        }
+    }
+
+    pub fn is_match(&self, benchmark_name: &str) -> bool {
+        match self {
+            Self::Regex(regex) | Self::Glob(regex) => regex.is_match(benchmark_name),
+            Self::Exact(name) => name == benchmark_name,
+        }
+    }
+}
+
+fn regex_pattern(selector: &str) -> Option<&str> {
+    selector
+        .strip_prefix(REGEX_DELIMITER)?
+        .strip_suffix(REGEX_DELIMITER)
+        .filter(|pattern| !pattern.is_empty())
+}
+
+fn selector_kind(selector: &str) -> BenchmarkSelectorKind {
+    if regex_pattern(selector).is_some() {
+        BenchmarkSelectorKind::Regex
+    } else if selector.contains([GLOB_ANY, GLOB_ONE]) {
+        BenchmarkSelectorKind::Glob
+    } else {
+        BenchmarkSelectorKind::Exact
+    }
+}
+
+fn selector_matcher(selector: &str) -> Result<BenchmarkMatcher, ValidError> {
+    let invalid = || ValidError::BenchmarkSelector(selector.into());
+    match selector_kind(selector) {
+        BenchmarkSelectorKind::Regex => {
+            let pattern = regex_pattern(selector).ok_or_else(invalid)?;
+            Regex::new(pattern)
+                .map(BenchmarkMatcher::Regex)
+                .map_err(|_e| invalid())
+        },
+        BenchmarkSelectorKind::Glob => {
+            let mut pattern = String::with_capacity(selector.len() + 2);
+            pattern.push('^');
+            for c in selector.chars() {
+                match c {
+                    GLOB_ANY => pattern.push_str(".*"),
+                    GLOB_ONE => pattern.push('.'),
+                    c => pattern.push_str(&escape(c.encode_utf8(&mut [0; 4]))),
+                }
+            }
+            pattern.push('$');
+            Regex::new(&pattern)
+                .map(BenchmarkMatcher::Glob)
+                .map_err(|_e| invalid())
+        },
+        BenchmarkSelectorKind::Exact => Ok(BenchmarkMatcher::Exact(selector.into())),
+    }
+}
+
+impl FromStr for BenchmarkSelector {
+    type Err = ValidError;
+
+    fn from_str(benchmark_selector: &str) -> Result<Self, Self::Err> {
+        if is_valid_benchmark_selector(benchmark_selector) {
+            Ok(Self(benchmark_selector.into()))
+        } else {
+            Err(ValidError::BenchmarkSelector(benchmark_selector.into()))
+        }
+    }
+}
+
+impl AsRef<str> for BenchmarkSelector {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<BenchmarkSelector> for String {
+    fn from(benchmark_selector: BenchmarkSelector) -> Self {
+        benchmark_selector.0
+    }
+}
+
+impl<'de> Deserialize<'de> for BenchmarkSelector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BenchmarkSelectorVisitor)
+    }
+}
+
+struct BenchmarkSelectorVisitor;
+
+impl Visitor<'_> for BenchmarkSelectorVisitor {
+    type Value = BenchmarkSelector;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a valid benchmark name, glob, or regular expression")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_benchmark_selector(benchmark_selector: &str) -> bool {
+    is_valid_benchmark_name(benchmark_selector) && selector_matcher(benchmark_selector).is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_valid_benchmark_selector, BenchmarkSelector, BenchmarkSelectorKind};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_benchmark_selector() {
+        assert_eq!(true, is_valid_benchmark_selector("a"));
+        assert_eq!(true, is_valid_benchmark_selector("abc ~ABC!"));
+        assert_eq!(true, is_valid_benchmark_selector("bench_*"));
+        assert_eq!(true, is_valid_benchmark_selector("bench_?"));
+        assert_eq!(true, is_valid_benchmark_selector("/^bench_[0-9]+$/"));
+        assert_eq!(true, is_valid_benchmark_selector("/"));
+        assert_eq!(true, is_valid_benchmark_selector("//"));
+
+        assert_eq!(false, is_valid_benchmark_selector(""));
+        assert_eq!(false, is_valid_benchmark_selector("/bench_[0-9/"));
+    }
+
+    #[test]
+    fn test_benchmark_selector_kind() {
+        let selector: BenchmarkSelector = "bench".parse().unwrap();
+        assert_eq!(selector.kind(), BenchmarkSelectorKind::Exact);
+        let selector: BenchmarkSelector = "bench::*".parse().unwrap();
+        assert_eq!(selector.kind(), BenchmarkSelectorKind::Glob);
+        let selector: BenchmarkSelector = "/^bench::.*$/".parse().unwrap();
+        assert_eq!(selector.kind(), BenchmarkSelectorKind::Regex);
+        let selector: BenchmarkSelector = "//".parse().unwrap();
+        assert_eq!(selector.kind(), BenchmarkSelectorKind::Exact);
+
+        assert!(BenchmarkSelectorKind::Exact > BenchmarkSelectorKind::Glob);
+        assert!(BenchmarkSelectorKind::Glob > BenchmarkSelectorKind::Regex);
+    }
+
+    #[test]
+    fn test_benchmark_selector_exact() {
+        let selector: BenchmarkSelector = "bench::noisy".parse().unwrap();
+        let matcher = selector.matcher().unwrap();
+        assert_eq!(true, matcher.is_match("bench::noisy"));
+        assert_eq!(false, matcher.is_match("bench::noisy_too"));
+        assert_eq!(false, matcher.is_match("bench"));
+    }
+
+    #[test]
+    fn test_benchmark_selector_glob() {
+        let selector: BenchmarkSelector = "bench::io_*.v?".parse().unwrap();
+        let matcher = selector.matcher().unwrap();
+        assert_eq!(true, matcher.is_match("bench::io_read.v1"));
+        assert_eq!(true, matcher.is_match("bench::io_.v2"));
+        assert_eq!(false, matcher.is_match("bench::io_read.v10"));
+        assert_eq!(false, matcher.is_match("bench::io_readXv1"));
+        assert_eq!(false, matcher.is_match("other::bench::io_read.v1"));
+    }
+
+    #[test]
+    fn test_benchmark_selector_regex() {
+        let selector: BenchmarkSelector = "/^bench_[0-9]+$/".parse().unwrap();
+        let matcher = selector.matcher().unwrap();
+        assert_eq!(true, matcher.is_match("bench_1"));
+        assert_eq!(true, matcher.is_match("bench_42"));
+        assert_eq!(false, matcher.is_match("bench_"));
+        assert_eq!(false, matcher.is_match("bench_1a"));
+
+        // Unanchored regular expressions match anywhere in the name
+        let selector: BenchmarkSelector = "/noisy/".parse().unwrap();
+        let matcher = selector.matcher().unwrap();
+        assert_eq!(true, matcher.is_match("very_noisy_bench"));
+        assert_eq!(false, matcher.is_match("quiet_bench"));
+    }
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::{Boundary, SampleSize, Window};
+use crate::{Boundary, ModelTest, SampleSize, Smoothing, Window};
 
 pub(crate) const REGEX_ERROR: &str = "Failed to compile regex.";
 
@@ -26,6 +26,8 @@ pub enum ValidError {
     BranchName(String),
     #[error("Failed to validate benchmark name: {0}")]
     BenchmarkName(String),
+    #[error("Failed to validate benchmark selector: {0}")]
+    BenchmarkSelector(String),
     #[error("Failed to validate name ID: {0}")]
     NameId(String),
     #[error("Failed to validate non-empty ID: {0}")]
@@ -52,6 +54,10 @@ pub enum ValidError {
     Window(u32),
     #[error("Failed to parse model window: {0}")]
     WindowStr(std::num::ParseIntError),
+    #[error("Invalid model smoothing factor: {0}")]
+    Smoothing(f64),
+    #[error("Failed to parse model smoothing factor: {0}")]
+    SmoothingStr(std::num::ParseFloatError),
     #[error("Invalid plot index: {0}")]
     Index(u8),
     #[error("Failed to parse plot index: {0}")]
@@ -114,6 +120,16 @@ pub enum ValidError {
     StaticMaxSampleSize(SampleSize),
     #[error("Invalid static model, includes a sampling window: {0}")]
     StaticWindow(Window),
+    #[error("Invalid {test} model, includes a level smoothing factor: {smoothing}")]
+    LevelSmoothing {
+        test: ModelTest,
+        smoothing: Smoothing,
+    },
+    #[error("Invalid {test} model, includes a trend smoothing factor: {smoothing}")]
+    TrendSmoothing {
+        test: ModelTest,
+        smoothing: Smoothing,
+    },
     #[error("Invalid percentage boundary: {0}")]
     PercentageBoundary(f64),
     #[error("Invalid statistical boundary: {0}")]
@@ -4,6 +4,7 @@ use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 
 mod benchmark_name;
+mod benchmark_selector;
 mod branch_name;
 mod date_time;
 mod email;
@@ -26,6 +27,7 @@ pub use crate::git_hash::GitHash;
 pub use crate::slug::Slug;
 pub use crate::url::Url;
 pub use benchmark_name::BenchmarkName;
+pub use benchmark_selector::{BenchmarkMatcher, BenchmarkSelector, BenchmarkSelectorKind};
 pub use branch_name::BranchName;
 pub use date_time::{DateTime, DateTimeMillis};
 pub use email::Email;
@@ -37,6 +39,7 @@ pub use model::{
     boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary},
     model_test::ModelTest,
     sample_size::SampleSize,
+    smoothing::Smoothing,
     window::Window,
     Model,
 };
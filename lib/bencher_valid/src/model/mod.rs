@@ -10,11 +10,13 @@ use crate::ValidError;
 pub mod boundary;
 pub mod model_test;
 pub mod sample_size;
+pub mod smoothing;
 pub mod window;
 
 use boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
 use model_test::ModelTest;
 use sample_size::SampleSize;
+use smoothing::Smoothing;
 use window::Window;
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
@@ -37,6 +39,14 @@ pub struct Model {
     /// The upper boundary used to calculate the upper boundary limit.
     /// The requirements for this field depend on which `test` is selected.
     pub upper_boundary: Option<Boundary>,
+    /// The smoothing factor for the level of exponentially weighted tests, between 0.0 and 1.0.
+    /// Larger values give more weight to the most recent samples.
+    /// This field is only used by the `ewma` and `holt` tests.
+    pub level_smoothing: Option<Smoothing>,
+    /// The smoothing factor for the trend of the Holt linear trend test, between 0.0 and 1.0.
+    /// Larger values allow the trend to change more quickly.
+    /// This field is only used by the `holt` test.
+    pub trend_smoothing: Option<Smoothing>,
 }
 
 impl Model {
@@ -48,6 +58,8 @@ impl Model {
             window: None,
             lower_boundary: Some(Boundary::NINETY_EIGHT),
             upper_boundary: None,
+            level_smoothing: None,
+            trend_smoothing: None,
         }
     }
 
@@ -59,6 +71,8 @@ impl Model {
             window: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_EIGHT),
+            level_smoothing: None,
+            trend_smoothing: None,
         }
     }
 
@@ -75,7 +89,10 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
         window,
         lower_boundary,
         upper_boundary,
+        level_smoothing,
+        trend_smoothing,
     } = model;
+    validate_smoothing(test, level_smoothing, trend_smoothing)?;
     match test {
         ModelTest::Static => {
             if let Some(&min_sample_size) = min_sample_size.as_ref() {
@@ -102,7 +119,15 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<PercentageBoundary>(lower_boundary, upper_boundary)
         },
-        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal => {
+        ModelTest::ZScore
+        | ModelTest::TTest
+        | ModelTest::LogNormal
+        | ModelTest::Welch
+        | ModelTest::MannWhitney
+        | ModelTest::ChangePoint
+        | ModelTest::Bootstrap
+        | ModelTest::Ewma
+        | ModelTest::Holt => {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<CdfBoundary>(lower_boundary, upper_boundary)
         },
@@ -126,6 +151,27 @@ fn validate_sample_size(
     Ok(())
 }
 
+fn validate_smoothing(
+    test: ModelTest,
+    level_smoothing: Option<Smoothing>,
+    trend_smoothing: Option<Smoothing>,
+) -> Result<(), ValidError> {
+    // Only the exponentially weighted tests use a level smoothing factor
+    if let Some(smoothing) = level_smoothing {
+        if !matches!(test, ModelTest::Ewma | ModelTest::Holt) {
+            return Err(ValidError::LevelSmoothing { test, smoothing });
+        }
+    }
+    // Only the Holt linear trend test uses a trend smoothing factor
+    if let Some(smoothing) = trend_smoothing {
+        if !matches!(test, ModelTest::Holt) {
+            return Err(ValidError::TrendSmoothing { test, smoothing });
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_boundary<B>(lower: Option<Boundary>, upper: Option<Boundary>) -> Result<(), ValidError>
 where
     B: TryFrom<Boundary, Error = ValidError>,
@@ -10,6 +10,12 @@ const T_TEST_INT: i32 = 1;
 const LOG_NORMAL_INT: i32 = 10;
 const IQR_INT: i32 = 40;
 const DELTA_IQR_INT: i32 = 41;
+const WELCH_INT: i32 = 50;
+const MANN_WHITNEY_INT: i32 = 51;
+const CHANGE_POINT_INT: i32 = 60;
+const BOOTSTRAP_INT: i32 = 70;
+const EWMA_INT: i32 = 80;
+const HOLT_INT: i32 = 81;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
@@ -28,12 +34,37 @@ pub enum ModelTest {
     LogNormal = LOG_NORMAL_INT,
     Iqr = IQR_INT,
     DeltaIqr = DELTA_IQR_INT,
+    #[serde(alias = "welch_t")]
+    Welch = WELCH_INT,
+    #[serde(alias = "mann_whitney_u")]
+    MannWhitney = MANN_WHITNEY_INT,
+    #[serde(alias = "cusum")]
+    ChangePoint = CHANGE_POINT_INT,
+    Bootstrap = BOOTSTRAP_INT,
+    #[serde(alias = "exponentially_weighted")]
+    Ewma = EWMA_INT,
+    #[serde(alias = "holt_linear")]
+    Holt = HOLT_INT,
+}
+
+impl ModelTest {
+    /// Two-sample tests compare the candidate report against the historical metrics,
+    /// instead of comparing a single new metric against the historical distribution.
+    pub fn is_two_sample(self) -> bool {
+        matches!(self, Self::Welch | Self::MannWhitney)
+    }
+
+    /// Series tests detect changes over the entire history, including the candidate report.
+    pub fn is_series(self) -> bool {
+        matches!(self, Self::ChangePoint)
+    }
 }
 
 #[cfg(feature = "db")]
 mod db {
     use super::{
-        ModelTest, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT,
+        ModelTest, BOOTSTRAP_INT, CHANGE_POINT_INT, DELTA_IQR_INT, EWMA_INT, HOLT_INT, IQR_INT,
+        LOG_NORMAL_INT, MANN_WHITNEY_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT, WELCH_INT,
         Z_SCORE_INT,
     };
 
@@ -60,6 +91,12 @@ mod db {
                 Self::LogNormal => LOG_NORMAL_INT.to_sql(out),
                 Self::Iqr => IQR_INT.to_sql(out),
                 Self::DeltaIqr => DELTA_IQR_INT.to_sql(out),
+                Self::Welch => WELCH_INT.to_sql(out),
+                Self::MannWhitney => MANN_WHITNEY_INT.to_sql(out),
+                Self::ChangePoint => CHANGE_POINT_INT.to_sql(out),
+                Self::Bootstrap => BOOTSTRAP_INT.to_sql(out),
+                Self::Ewma => EWMA_INT.to_sql(out),
+                Self::Holt => HOLT_INT.to_sql(out),
             }
         }
     }
@@ -78,6 +115,12 @@ mod db {
                 LOG_NORMAL_INT => Ok(Self::LogNormal),
                 IQR_INT => Ok(Self::Iqr),
                 DELTA_IQR_INT => Ok(Self::DeltaIqr),
+                WELCH_INT => Ok(Self::Welch),
+                MANN_WHITNEY_INT => Ok(Self::MannWhitney),
+                CHANGE_POINT_INT => Ok(Self::ChangePoint),
+                BOOTSTRAP_INT => Ok(Self::Bootstrap),
+                EWMA_INT => Ok(Self::Ewma),
+                HOLT_INT => Ok(Self::Holt),
                 value => Err(Box::new(ModelTestError::Invalid(value))),
             }
         }
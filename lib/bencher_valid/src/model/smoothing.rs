@@ -0,0 +1,159 @@
+use derive_more::Display;
+use ordered_float::OrderedFloat;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::ValidError;
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Double))]
+pub struct Smoothing(OrderedFloat<f64>);
+
+impl TryFrom<f64> for Smoothing {
+    type Error = ValidError;
+
+    fn try_from(smoothing: f64) -> Result<Self, Self::Error> {
+        is_valid_smoothing(smoothing)
+            .then(|| Self(smoothing.into()))
+            .ok_or(ValidError::Smoothing(smoothing))
+    }
+}
+
+impl From<Smoothing> for f64 {
+    fn from(smoothing: Smoothing) -> Self {
+        smoothing.0.into()
+    }
+}
+
+impl Smoothing {
+    pub const TENTH: Self = Self(OrderedFloat(0.1));
+    pub const THIRD: Self = Self(OrderedFloat(0.3));
+    pub const ONE: Self = Self(OrderedFloat(1.0));
+
+    // The level smoothing used if none is specified
+    pub const DEFAULT_LEVEL: Self = Self::THIRD;
+    // The trend smoothing used if none is specified
+    pub const DEFAULT_TREND: Self = Self::TENTH;
+}
+
+impl FromStr for Smoothing {
+    type Err = ValidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(f64::from_str(s).map_err(ValidError::SmoothingStr)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for Smoothing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(SmoothingVisitor)
+    }
+}
+
+struct SmoothingVisitor;
+
+impl Visitor<'_> for SmoothingVisitor {
+    type Value = Smoothing;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a smoothing factor greater than 0.0 and less than or equal to 1.0")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        #[allow(clippy::cast_precision_loss)]
+        (value as f64).try_into().map_err(E::custom)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.try_into().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::Smoothing;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Double, DB> for Smoothing
+    where
+        DB: diesel::backend::Backend,
+        for<'a> f64: diesel::serialize::ToSql<diesel::sql_types::Double, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            out.set_value(f64::from(*self));
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Double, DB> for Smoothing
+    where
+        DB: diesel::backend::Backend,
+        f64: diesel::deserialize::FromSql<diesel::sql_types::Double, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            f64::from_sql(bytes)?.try_into().map_err(Into::into)
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_smoothing(smoothing: f64) -> bool {
+    smoothing > 0.0 && smoothing <= 1.0
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{is_valid_smoothing, Smoothing};
+
+    #[test]
+    fn test_smoothing() {
+        assert_eq!(true, is_valid_smoothing(Smoothing::TENTH.into()));
+        assert_eq!(true, is_valid_smoothing(Smoothing::THIRD.into()));
+        assert_eq!(true, is_valid_smoothing(Smoothing::ONE.into()));
+        assert_eq!(true, is_valid_smoothing(f64::MIN_POSITIVE));
+
+        assert_eq!(false, is_valid_smoothing(0.0));
+        assert_eq!(false, is_valid_smoothing(-0.5));
+        assert_eq!(false, is_valid_smoothing(1.5));
+        assert_eq!(false, is_valid_smoothing(f64::INFINITY));
+        assert_eq!(false, is_valid_smoothing(f64::NAN));
+    }
+
+    #[test]
+    fn test_smoothing_serde() {
+        let smoothing: Smoothing = serde_json::from_str("0.5").unwrap();
+        assert_eq!(Smoothing(0.5.into()), smoothing);
+        let smoothing: Smoothing = serde_json::from_str("1").unwrap();
+        assert_eq!(Smoothing::ONE, smoothing);
+
+        let smoothing = serde_json::from_str::<Smoothing>("0.0");
+        assert!(smoothing.is_err());
+        let smoothing = serde_json::from_str::<Smoothing>("2.0");
+        assert!(smoothing.is_err());
+    }
+}
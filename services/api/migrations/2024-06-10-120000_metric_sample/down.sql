@@ -0,0 +1 @@
+DROP TABLE metric_sample;
\ No newline at end of file
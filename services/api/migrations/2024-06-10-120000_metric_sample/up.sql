@@ -0,0 +1,7 @@
+CREATE TABLE metric_sample (
+    metric_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    value DOUBLE NOT NULL,
+    FOREIGN KEY (metric_id) REFERENCES metric (id) ON DELETE CASCADE,
+    PRIMARY KEY (metric_id, position)
+);
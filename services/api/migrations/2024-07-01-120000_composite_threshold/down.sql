@@ -0,0 +1,36 @@
+PRAGMA foreign_keys = off;
+-- alert
+CREATE TABLE down_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    status INTEGER NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE
+);
+INSERT INTO down_alert(
+        id,
+        uuid,
+        boundary_id,
+        boundary_limit,
+        status,
+        modified
+    )
+SELECT id,
+    uuid,
+    boundary_id,
+    boundary_limit,
+    status,
+    modified
+FROM alert
+WHERE composite_threshold_id IS NULL;
+DROP TABLE alert;
+ALTER TABLE down_alert
+    RENAME TO alert;
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+-- composite member
+DROP TABLE composite_member;
+-- composite threshold
+DROP TABLE composite_threshold;
+PRAGMA foreign_keys = on;
@@ -0,0 +1,51 @@
+PRAGMA foreign_keys = off;
+-- threshold
+CREATE TABLE up_threshold (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    benchmark TEXT,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    FOREIGN KEY (model_id) REFERENCES model (id)
+);
+INSERT INTO up_threshold(
+        id,
+        uuid,
+        project_id,
+        branch_id,
+        testbed_id,
+        measure_id,
+        model_id,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    project_id,
+    branch_id,
+    testbed_id,
+    measure_id,
+    model_id,
+    created,
+    modified
+FROM threshold;
+DROP TABLE threshold;
+ALTER TABLE up_threshold
+    RENAME TO threshold;
+-- A NULL benchmark selector must still be unique for the branch, testbed, and measure
+CREATE UNIQUE INDEX index_threshold_benchmark ON threshold(
+    branch_id,
+    testbed_id,
+    measure_id,
+    IFNULL(benchmark, '')
+);
+PRAGMA foreign_keys = on;
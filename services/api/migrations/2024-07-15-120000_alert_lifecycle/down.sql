@@ -0,0 +1,43 @@
+PRAGMA foreign_keys = off;
+-- alert event
+DROP TABLE alert_event;
+-- alert
+CREATE TABLE down_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    composite_threshold_id INTEGER,
+    status INTEGER NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE,
+    FOREIGN KEY (composite_threshold_id) REFERENCES composite_threshold (id) ON DELETE CASCADE
+);
+-- acknowledged and silenced alerts become active again
+-- resolved alerts become dismissed
+INSERT INTO down_alert(
+        id,
+        uuid,
+        boundary_id,
+        boundary_limit,
+        composite_threshold_id,
+        status,
+        modified
+    )
+SELECT id,
+    uuid,
+    boundary_id,
+    boundary_limit,
+    composite_threshold_id,
+    CASE
+        WHEN status IN (2, 3) THEN 0
+        WHEN status = 4 THEN 1
+        ELSE status
+    END,
+    modified
+FROM alert;
+DROP TABLE alert;
+ALTER TABLE down_alert
+    RENAME TO alert;
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+PRAGMA foreign_keys = on;
@@ -0,0 +1,57 @@
+PRAGMA foreign_keys = off;
+-- alert
+CREATE TABLE up_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    composite_threshold_id INTEGER,
+    status INTEGER NOT NULL,
+    assignee_id INTEGER,
+    silenced_until BIGINT,
+    silenced_version INTEGER,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE,
+    FOREIGN KEY (composite_threshold_id) REFERENCES composite_threshold (id) ON DELETE CASCADE,
+    FOREIGN KEY (assignee_id) REFERENCES user (id) ON DELETE SET NULL
+);
+INSERT INTO up_alert(
+        id,
+        uuid,
+        boundary_id,
+        boundary_limit,
+        composite_threshold_id,
+        status,
+        modified
+    )
+SELECT id,
+    uuid,
+    boundary_id,
+    boundary_limit,
+    composite_threshold_id,
+    status,
+    modified
+FROM alert;
+DROP TABLE alert;
+ALTER TABLE up_alert
+    RENAME TO alert;
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+-- alert event
+CREATE TABLE alert_event (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    alert_id INTEGER NOT NULL,
+    user_id INTEGER,
+    report_id INTEGER,
+    status INTEGER NOT NULL,
+    assignee_id INTEGER,
+    silenced_until BIGINT,
+    silenced_version INTEGER,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (alert_id) REFERENCES alert (id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES user (id) ON DELETE SET NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE SET NULL,
+    FOREIGN KEY (assignee_id) REFERENCES user (id) ON DELETE SET NULL
+);
+CREATE INDEX index_alert_event_alert ON alert_event(alert_id);
+PRAGMA foreign_keys = on;
@@ -0,0 +1,8 @@
+PRAGMA foreign_keys = off;
+-- webhook delivery
+DROP TABLE webhook_delivery;
+-- webhook subscription
+DROP TABLE webhook_subscription;
+-- webhook
+DROP TABLE webhook;
+PRAGMA foreign_keys = on;
@@ -0,0 +1,39 @@
+PRAGMA foreign_keys = off;
+-- webhook
+CREATE TABLE webhook (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE INDEX index_webhook_project ON webhook(project_id);
+-- webhook subscription
+CREATE TABLE webhook_subscription (
+    webhook_id INTEGER NOT NULL,
+    event INTEGER NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE,
+    PRIMARY KEY (webhook_id, event)
+);
+-- webhook delivery
+CREATE TABLE webhook_delivery (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    event INTEGER NOT NULL,
+    payload TEXT NOT NULL,
+    status INTEGER NOT NULL,
+    attempts INTEGER NOT NULL,
+    next_attempt BIGINT,
+    response_status INTEGER,
+    error TEXT,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE
+);
+CREATE INDEX index_webhook_delivery_webhook ON webhook_delivery(webhook_id);
+CREATE INDEX index_webhook_delivery_pending ON webhook_delivery(status, next_attempt);
+PRAGMA foreign_keys = on;
@@ -0,0 +1,4 @@
+PRAGMA foreign_keys = off;
+-- project notification
+DROP TABLE project_notification;
+PRAGMA foreign_keys = on;
@@ -0,0 +1,15 @@
+PRAGMA foreign_keys = off;
+-- project notification
+CREATE TABLE project_notification (
+    id INTEGER PRIMARY KEY NOT NULL,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    alert_email BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(user_id, project_id)
+);
+CREATE INDEX index_project_notification_project ON project_notification(project_id);
+PRAGMA foreign_keys = on;
@@ -25,6 +25,7 @@ use crate::model::server::QueryServer;
 use crate::{
     context::{ApiContext, Database, DbConnection},
     endpoints::Api,
+    model::project::webhook::delivery::QueryWebhookDelivery,
 };
 
 use super::Config;
@@ -32,7 +33,7 @@ use super::Config;
 use super::{plus::Plus, DEFAULT_BUSY_TIMEOUT};
 
 const DATABASE_URL: &str = "DATABASE_URL";
-const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+pub(crate) const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
 
 pub struct ConfigTx {
     pub config: Config,
@@ -133,6 +134,9 @@ impl ConfigTx {
             query_server.spawn_stats(log.clone(), conn, context.stats, licensor, messenger);
         }
 
+        debug!(log, "Starting webhook delivery worker");
+        QueryWebhookDelivery::spawn_worker(log.clone(), context.database.connection.clone());
+
         let mut api = ApiDescription::new();
         debug!(log, "Registering server APIs");
         Api::register(
@@ -0,0 +1,136 @@
+use std::fmt::Write;
+
+use slog::Logger;
+
+use super::FmtBody;
+
+#[derive(Debug)]
+pub struct AlertBody {
+    pub name: String,
+    pub project: String,
+    pub branch: String,
+    pub testbed: String,
+    pub alerts: Vec<AlertBodyItem>,
+    pub settings_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlertBodyItem {
+    pub benchmark: String,
+    pub measure: String,
+    pub value: String,
+    pub limit: String,
+    pub alert_url: String,
+    pub perf_url: String,
+}
+
+impl FmtBody for AlertBody {
+    fn text(&self) -> String {
+        let Self {
+            name,
+            project,
+            branch,
+            testbed,
+            alerts,
+            settings_url,
+        } = self;
+        let mut alerts_text = String::new();
+        for AlertBodyItem {
+            benchmark,
+            measure,
+            value,
+            limit,
+            alert_url,
+            perf_url,
+        } in alerts
+        {
+            // Writing to a `String` is infallible
+            let _ = writeln!(
+                alerts_text,
+                "- {benchmark} ({measure}): {value} exceeded the {limit}\n  View alert: {alert_url}\n  View plot: {perf_url}"
+            );
+        }
+        let alerts = alerts_text;
+        format!(
+            "\nAhoy {name},\n{summary}\n\nBranch: {branch}\nTestbed: {testbed}\n\n{alerts}\n🐰 Bencher\nBencher - Continuous Benchmarking\nManage notification settings ({settings_url})",
+            summary = summary(self.alerts.len(), project),
+        )
+    }
+
+    fn html(&self, _log: &Logger) -> String {
+        let Self {
+            name,
+            project,
+            branch,
+            testbed,
+            alerts,
+            settings_url,
+        } = self;
+        let summary = summary(alerts.len(), &escape_html(project));
+        let mut alerts_html = String::new();
+        for AlertBodyItem {
+            benchmark,
+            measure,
+            value,
+            limit,
+            alert_url,
+            perf_url,
+        } in alerts
+        {
+            // Writing to a `String` is infallible
+            let _ = write!(
+                alerts_html,
+                "<tr><td>{benchmark}</td><td>{measure}</td><td>{value}</td><td>{limit}</td><td><a href=\"{alert_url}\">view alert</a> | <a href=\"{perf_url}\">view plot</a></td></tr>",
+                benchmark = escape_html(benchmark),
+                measure = escape_html(measure),
+            );
+        }
+        let alerts = alerts_html;
+        format!(
+            "<!doctype html>
+<html>
+    <head>
+        <meta charset=\"utf-8\" />
+        <meta name=\"viewport\" content=\"width=device-width, initial-scale=1, shrink-to-fit=no\" />
+        <meta name=\"theme-color\" content=\"#ffffff\" />
+        <title>Bencher Alerts</title>
+    </head>
+    <body>
+        <p>Ahoy {name},</p>
+        <p>{summary}</p>
+        <br />
+        <p>Branch: {branch}</p>
+        <p>Testbed: {testbed}</p>
+        <br />
+        <table>
+            <tr><th>Benchmark</th><th>Measure</th><th>Value</th><th>Limit</th><th>View</th></tr>
+            {alerts}
+        </table>
+        <br />
+        <p>🐰 Bencher</p>
+        <br />
+        <a href=\"{settings_url}\">Manage notification settings</a>
+    </body>
+</html>",
+            name = escape_html(name),
+            branch = escape_html(branch),
+            testbed = escape_html(testbed),
+        )
+    }
+}
+
+fn summary(alerts_len: usize, project: &str) -> String {
+    if alerts_len == 1 {
+        format!("A new report for {project} generated an alert.")
+    } else {
+        format!("A new report for {project} generated {alerts_len} alerts.")
+    }
+}
+
+// Benchmark names and the like are user provided
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
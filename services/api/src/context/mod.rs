@@ -23,7 +23,9 @@ pub use database::{DataStoreError, Database, DbConnection};
 pub use indexer::Indexer;
 #[cfg(feature = "plus")]
 pub use messenger::ServerStatsBody;
-pub use messenger::{Body, ButtonBody, Email, Message, Messenger, NewUserBody};
+pub use messenger::{
+    AlertBody, AlertBodyItem, Body, ButtonBody, Email, Message, Messenger, NewUserBody,
+};
 pub use rbac::{Rbac, RbacError};
 
 pub struct ApiContext {
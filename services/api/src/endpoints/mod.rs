@@ -142,6 +142,12 @@ impl Api {
         }
         api.register(project::perf::img::proj_perf_img_get)?;
 
+        // Change Points
+        if http_options {
+            api.register(project::change_points::proj_change_points_options)?;
+        }
+        api.register(project::change_points::proj_change_points_get)?;
+
         // Plots
         if http_options {
             api.register(project::plots::proj_plots_options)?;
@@ -214,17 +220,43 @@ impl Api {
         api.register(project::thresholds::proj_threshold_put)?;
         api.register(project::thresholds::proj_threshold_delete)?;
 
+        // Composite Thresholds
+        if http_options {
+            api.register(project::composite_thresholds::proj_composite_thresholds_options)?;
+            api.register(project::composite_thresholds::proj_composite_threshold_options)?;
+        }
+        api.register(project::composite_thresholds::proj_composite_thresholds_get)?;
+        api.register(project::composite_thresholds::proj_composite_threshold_get)?;
+        api.register(project::composite_thresholds::proj_composite_threshold_delete)?;
+
         // Threshold Alerts
         if http_options {
             api.register(project::alerts::proj_alerts_options)?;
             api.register(project::alerts::proj_alert_options)?;
+            api.register(project::alerts::proj_alert_history_options)?;
             api.register(project::alerts::proj_alert_stats_options)?;
         }
         api.register(project::alerts::proj_alerts_get)?;
         api.register(project::alerts::proj_alert_get)?;
         api.register(project::alerts::proj_alert_patch)?;
+        api.register(project::alerts::proj_alert_history_get)?;
         api.register(project::alerts::proj_alert_stats_get)?;
 
+        // Webhooks
+        if http_options {
+            api.register(project::webhooks::proj_webhooks_options)?;
+            api.register(project::webhooks::proj_webhook_options)?;
+            api.register(project::webhooks::proj_webhook_test_options)?;
+            api.register(project::webhooks::proj_webhook_deliveries_options)?;
+        }
+        api.register(project::webhooks::proj_webhooks_get)?;
+        api.register(project::webhooks::proj_webhook_post)?;
+        api.register(project::webhooks::proj_webhook_get)?;
+        api.register(project::webhooks::proj_webhook_patch)?;
+        api.register(project::webhooks::proj_webhook_delete)?;
+        api.register(project::webhooks::proj_webhook_test_post)?;
+        api.register(project::webhooks::proj_webhook_deliveries_get)?;
+
         // Users
         if http_options {
             api.register(user::users::users_options)?;
@@ -244,6 +276,15 @@ impl Api {
         api.register(user::tokens::user_token_get)?;
         api.register(user::tokens::user_token_patch)?;
 
+        // Notifications
+        if http_options {
+            api.register(user::notifications::user_notifications_options)?;
+            api.register(user::notifications::user_notification_options)?;
+        }
+        api.register(user::notifications::user_notifications_get)?;
+        api.register(user::notifications::user_notification_get)?;
+        api.register(user::notifications::user_notification_patch)?;
+
         // Server
         if http_options {
             api.register(system::server::version::server_version_options)?;
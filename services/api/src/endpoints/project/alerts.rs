@@ -1,6 +1,6 @@
 use bencher_json::{
     project::alert::{AlertStatus, JsonAlertStats, JsonUpdateAlert},
-    AlertUuid, JsonAlert, JsonAlerts, JsonDirection, JsonPagination, ResourceId,
+    AlertUuid, JsonAlert, JsonAlertEvents, JsonAlerts, JsonDirection, JsonPagination, ResourceId,
 };
 use bencher_rbac::project::Permission;
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
@@ -15,10 +15,14 @@ use crate::{
         endpoint::{CorsResponse, Get, Patch, ResponseOk},
         Endpoint,
     },
-    error::{resource_conflict_err, resource_not_found_err},
+    error::resource_not_found_err,
     model::{
         project::{
-            threshold::alert::{QueryAlert, UpdateAlert},
+            threshold::{
+                alert::{QueryAlert, UpdateAlert},
+                alert_event::QueryAlertEvent,
+            },
+            webhook::QueryWebhook,
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
@@ -64,7 +68,7 @@ pub async fn proj_alerts_options(
 /// List all alerts for a project.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
-/// By default, the alerts are sorted by status (active then dismissed) and modification date time in reverse chronological order.
+/// By default, the alerts are sorted by status and modification date time in reverse chronological order.
 /// The HTTP response header `X-Total-Count` contains the total number of alerts.
 #[endpoint {
     method = GET,
@@ -293,7 +297,10 @@ async fn get_one_inner(
 ///
 /// Update an alert for a project.
 /// The user must have `edit` permissions for the project.
-/// Use this endpoint to dismiss an alert.
+/// Use this endpoint to dismiss, acknowledge, silence, resolve, or reactivate an alert.
+/// An acknowledged alert is assigned to a member of the project's organization.
+/// A silenced alert stays silenced until a date time and/or a branch version number is reached.
+/// Every change is recorded in the alert history.
 #[endpoint {
     method = PATCH,
     path =  "/v0/projects/{project}/alerts/{alert}",
@@ -333,16 +340,154 @@ async fn patch_inner(
 
     let query_alert =
         QueryAlert::from_uuid(conn_lock!(context), query_project.id, path_params.alert)?;
-    let update_alert = UpdateAlert::from(json_alert.clone());
-    diesel::update(schema::alert::table.filter(schema::alert::id.eq(query_alert.id)))
-        .set(&update_alert)
-        .execute(conn_lock!(context))
-        .map_err(resource_conflict_err!(Alert, (&query_alert, &json_alert)))?;
-
-    let alert = QueryAlert::get(conn_lock!(context), query_alert.id)?;
+    // Hold the database lock so the alert and its history are updated together
+    let alert = conn_lock!(context, |conn| UpdateAlert::from_json(
+        conn,
+        &query_project,
+        &query_alert,
+        auth_user,
+        json_alert
+    )?
+    .update(conn, &query_alert, Some(auth_user.id()), None))?;
 
     // Separate out this query to prevent a deadlock when getting the conn_lock
-    alert.into_json(context).await
+    let is_resolved =
+        alert.status == AlertStatus::Resolved && query_alert.status != AlertStatus::Resolved;
+    let json_alert = alert.into_json(context).await?;
+    if is_resolved {
+        QueryWebhook::enqueue_alert_resolved(
+            conn_lock!(context),
+            &query_project,
+            json_alert.clone(),
+        )?;
+    }
+    Ok(json_alert)
+}
+
+pub type ProjAlertHistoryPagination = JsonPagination<ProjAlertHistorySort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjAlertHistorySort {
+    /// Sort by alert event creation date time.
+    #[default]
+    Created,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/alerts/{alert}/history",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_history_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjAlertParams>,
+    _pagination_params: Query<ProjAlertHistoryPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// View the history of an alert
+///
+/// View every change to the status of an alert for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// Changes made automatically by Bencher, such as resolving an alert when a later report is within the boundary limits,
+/// include the report that caused the change instead of a user.
+/// By default, the changes are sorted by creation date time in chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of changes.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/alerts/{alert}/history",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_alert_history_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjAlertParams>,
+    pagination_params: Query<ProjAlertHistoryPagination>,
+) -> Result<ResponseOk<JsonAlertEvents>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_history_inner(
+        rqctx.context(),
+        auth_user.as_ref(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::response_ok_with_total_count(
+        json,
+        auth_user.is_some(),
+        total_count,
+    ))
+}
+
+async fn get_history_inner(
+    context: &ApiContext,
+    auth_user: Option<&AuthUser>,
+    path_params: ProjAlertParams,
+    pagination_params: ProjAlertHistoryPagination,
+) -> Result<(JsonAlertEvents, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let query_alert =
+        QueryAlert::from_uuid(conn_lock!(context), query_project.id, path_params.alert)?;
+
+    let mut query = schema::alert_event::table
+        .filter(schema::alert_event::alert_id.eq(query_alert.id))
+        .select(QueryAlertEvent::as_select())
+        .into_boxed();
+    query = match pagination_params.order() {
+        ProjAlertHistorySort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order((
+                schema::alert_event::created.asc(),
+                schema::alert_event::id.asc(),
+            )),
+            Some(JsonDirection::Desc) => query.order((
+                schema::alert_event::created.desc(),
+                schema::alert_event::id.desc(),
+            )),
+        },
+    };
+    let alert_events = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryAlertEvent>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            AlertEvent,
+            (&query_alert, &pagination_params)
+        ))?;
+
+    // Separate out these queries to prevent a deadlock when getting the conn_lock
+    let mut json_alert_events = Vec::with_capacity(alert_events.len());
+    for alert_event in alert_events {
+        match alert_event.into_json(conn_lock!(context), query_alert.uuid) {
+            Ok(alert_event) => json_alert_events.push(alert_event),
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&err);
+            },
+        }
+    }
+
+    let total_count = schema::alert_event::table
+        .filter(schema::alert_event::alert_id.eq(query_alert.id))
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            AlertEvent,
+            (&query_alert, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_alert_events.into(), total_count))
 }
 
 #[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
@@ -0,0 +1,205 @@
+use bencher_json::{
+    project::{
+        branch::{JsonVersion, VersionNumber},
+        change_point::{JsonChangePoint, JsonChangePointQuery},
+        report::Iteration,
+    },
+    Boundary, CdfBoundary, DateTime, GitHash, JsonChangePoints, MetricUuid, ReportUuid, ResourceId,
+};
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+use http::StatusCode;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Get, ResponseOk},
+        Endpoint,
+    },
+    error::{bad_request_error, issue_error, resource_not_found_err},
+    model::{
+        project::QueryProject,
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema,
+};
+
+// The maximum number of the most recent metrics to search for change points
+const MAX_METRICS: i64 = 4096;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjChangePointsParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/change_points",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_change_points_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjChangePointsParams>,
+    _query_params: Query<JsonChangePointQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Detect project change points
+///
+/// Detect the change points in the performance metrics for a project.
+/// A change point is a persistent shift in the mean of the metrics for a branch, testbed, benchmark, and measure.
+/// Only the most recent 4,096 metrics are searched.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/change_points",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_change_points_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjChangePointsParams>,
+    query_params: Query<JsonChangePointQuery>,
+) -> Result<ResponseOk<JsonChangePoints>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        query_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+type ChangePointQuery = (
+    MetricUuid,
+    f64,
+    ReportUuid,
+    Iteration,
+    DateTime,
+    VersionNumber,
+    Option<GitHash>,
+);
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjChangePointsParams,
+    query: JsonChangePointQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonChangePoints, HttpError> {
+    let confidence = CdfBoundary::try_from(query.confidence.unwrap_or(Boundary::NINETY_FIVE))
+        .map_err(bad_request_error)?;
+
+    let project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let mut sql = schema::metric::table
+        .inner_join(
+            schema::report_benchmark::table
+                .inner_join(
+                    schema::report::table
+                        .inner_join(
+                            schema::version::table.inner_join(
+                                schema::branch_version::table.inner_join(
+                                    schema::branch::table
+                                        .on(schema::branch_version::branch_id.eq(schema::branch::id)),
+                                ),
+                            ),
+                        )
+                        .inner_join(schema::testbed::table),
+                )
+                .inner_join(schema::benchmark::table),
+        )
+        .inner_join(schema::measure::table)
+        // Filter for the branch on the `branch_version` table to include any start point history.
+        .filter(schema::branch::uuid.eq(query.branch))
+        .filter(schema::testbed::uuid.eq(query.testbed))
+        .filter(schema::benchmark::uuid.eq(query.benchmark))
+        .filter(schema::measure::uuid.eq(query.measure))
+        // Make sure that the project is the same for all dimensions
+        .filter(schema::branch::project_id.eq(project.id))
+        .filter(schema::testbed::project_id.eq(project.id))
+        .filter(schema::benchmark::project_id.eq(project.id))
+        .filter(schema::measure::project_id.eq(project.id))
+        .into_boxed();
+
+    if let Some(start_time) = query.start_time {
+        sql = sql.filter(schema::report::start_time.ge(DateTime::from(start_time)));
+    }
+    if let Some(end_time) = query.end_time {
+        sql = sql.filter(schema::report::end_time.le(DateTime::from(end_time)));
+    }
+
+    let mut metrics = sql
+        // Order by the newest version first, so that only the most recent metrics are searched.
+        .order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+            schema::report_benchmark::iteration.desc(),
+        ))
+        .limit(MAX_METRICS)
+        .select((
+            schema::metric::uuid,
+            schema::metric::value,
+            schema::report::uuid,
+            schema::report_benchmark::iteration,
+            schema::report::start_time,
+            schema::version::number,
+            schema::version::hash,
+        ))
+        .load::<ChangePointQuery>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Metric,
+            (&project, query.branch, query.testbed, query.benchmark, query.measure)
+        ))?;
+    // Change points are detected in chronological order
+    metrics.reverse();
+
+    let series = metrics
+        .iter()
+        .map(|(_, value, ..)| *value)
+        .collect::<Vec<_>>();
+    let change_points = bencher_boundary::change_point::change_points(&series, confidence)
+        .map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to detect change points",
+                &format!(
+                    "Failed to detect change points for project ({project:?}) and query ({query:?})."
+                ),
+                e,
+            )
+        })?;
+
+    Ok(change_points
+        .into_iter()
+        .filter_map(|change_point| {
+            let (metric, _, report, iteration, start_time, number, hash) =
+                metrics.get(change_point.index)?.clone();
+            Some(JsonChangePoint {
+                metric,
+                report,
+                iteration,
+                start_time,
+                version: JsonVersion { number, hash },
+                direction: change_point.direction(),
+                before: change_point.before.into(),
+                after: change_point.after.into(),
+                p_value: change_point.p_value.into(),
+            })
+        })
+        .collect::<Vec<_>>()
+        .into())
+}
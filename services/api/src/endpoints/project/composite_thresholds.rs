@@ -0,0 +1,272 @@
+use bencher_json::{
+    CompositeThresholdUuid, JsonCompositeThreshold, JsonCompositeThresholds, JsonDirection,
+    JsonPagination, ResourceId,
+};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Delete, Get, ResponseDeleted, ResponseOk},
+        Endpoint,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{threshold::composite::QueryCompositeThreshold, QueryProject},
+        user::auth::{AuthUser, BearerToken, PubBearerToken},
+    },
+    schema,
+    util::headers::TotalCount,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjCompositeThresholdsParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjCompositeThresholdsPagination = JsonPagination<ProjCompositeThresholdsSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjCompositeThresholdsSort {
+    /// Sort by composite threshold creation date time.
+    #[default]
+    Created,
+    /// Sort by composite threshold modified date time.
+    Modified,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/composite_thresholds",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_composite_thresholds_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjCompositeThresholdsParams>,
+    _pagination_params: Query<ProjCompositeThresholdsPagination>,
+) -> Result<CorsResponse, HttpError> {
+    // Composite thresholds are created with the threshold endpoint
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List composite thresholds for a project
+///
+/// List all composite thresholds for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// By default, the composite thresholds are sorted by creation date time in chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of composite thresholds.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/composite_thresholds",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_composite_thresholds_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjCompositeThresholdsParams>,
+    pagination_params: Query<ProjCompositeThresholdsPagination>,
+) -> Result<ResponseOk<JsonCompositeThresholds>, HttpError> {
+    let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        auth_user.as_ref(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::response_ok_with_total_count(
+        json,
+        auth_user.is_some(),
+        total_count,
+    ))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: Option<&AuthUser>,
+    path_params: ProjCompositeThresholdsParams,
+    pagination_params: ProjCompositeThresholdsPagination,
+) -> Result<(JsonCompositeThresholds, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let mut query = QueryCompositeThreshold::belonging_to(&query_project).into_boxed();
+    query = match pagination_params.order() {
+        ProjCompositeThresholdsSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => {
+                query.order(schema::composite_threshold::created.asc())
+            },
+            Some(JsonDirection::Desc) => query.order(schema::composite_threshold::created.desc()),
+        },
+        ProjCompositeThresholdsSort::Modified => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order(schema::composite_threshold::modified.asc()),
+            Some(JsonDirection::Desc) | None => {
+                query.order(schema::composite_threshold::modified.desc())
+            },
+        },
+    };
+    let composite_thresholds = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryCompositeThreshold>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            CompositeThreshold,
+            (&query_project, &pagination_params)
+        ))?;
+
+    // Separate out these queries to prevent a deadlock when getting the conn_lock
+    let mut json_composite_thresholds = Vec::with_capacity(composite_thresholds.len());
+    for composite_threshold in composite_thresholds {
+        match composite_threshold.into_json_for_project(conn_lock!(context), &query_project) {
+            Ok(composite_threshold) => json_composite_thresholds.push(composite_threshold),
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&err);
+            },
+        }
+    }
+
+    let total_count = QueryCompositeThreshold::belonging_to(&query_project)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            CompositeThreshold,
+            (&query_project, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_composite_thresholds.into(), total_count))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjCompositeThresholdParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a composite threshold.
+    pub composite_threshold: CompositeThresholdUuid,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/composite_thresholds/{composite_threshold}",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_composite_threshold_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjCompositeThresholdParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Delete.into()]))
+}
+
+/// View a composite threshold
+///
+/// View a composite threshold for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/composite_thresholds/{composite_threshold}",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_composite_threshold_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjCompositeThresholdParams>,
+) -> Result<ResponseOk<JsonCompositeThreshold>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjCompositeThresholdParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonCompositeThreshold, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    conn_lock!(context, |conn| QueryCompositeThreshold::get_with_uuid(
+        conn,
+        &query_project,
+        path_params.composite_threshold
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+/// Delete a composite threshold
+///
+/// Delete a composite threshold for a project.
+/// The user must have `delete` permissions for the project.
+/// The member thresholds are not deleted,
+/// and they go back to generating alerts on their own.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/composite_thresholds/{composite_threshold}",
+    tags = ["projects", "thresholds"]
+}]
+pub async fn proj_composite_threshold_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjCompositeThresholdParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjCompositeThresholdParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_composite_threshold = QueryCompositeThreshold::get_with_uuid(
+        conn_lock!(context),
+        &query_project,
+        path_params.composite_threshold,
+    )?;
+
+    diesel::delete(
+        schema::composite_threshold::table
+            .filter(schema::composite_threshold::id.eq(query_composite_threshold.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(
+        CompositeThreshold,
+        query_composite_threshold
+    ))?;
+
+    Ok(())
+}
@@ -24,6 +24,7 @@ use crate::{
             branch_version::QueryBranchVersion,
             measure::QueryMeasure,
             metric_boundary::QueryMetricBoundary,
+            metric_sample::QueryMetricSample,
             testbed::QueryTestbed,
             threshold::{
                 alert::QueryAlert, boundary::QueryBoundary, model::QueryModel, QueryThreshold,
@@ -141,6 +142,7 @@ async fn get_one_inner(
                     schema::threshold::measure_id,
                     schema::threshold::branch_id,
                     schema::threshold::testbed_id,
+                    schema::threshold::benchmark,
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
@@ -155,6 +157,8 @@ async fn get_one_inner(
                     schema::model::window,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
+                    schema::model::level_smoothing,
+                    schema::model::trend_smoothing,
                     schema::model::created,
                     schema::model::replaced,
                 ),
@@ -163,7 +167,11 @@ async fn get_one_inner(
                     schema::alert::uuid,
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
+                    schema::alert::composite_threshold_id,
                     schema::alert::status,
+                    schema::alert::assignee_id,
+                    schema::alert::silenced_until,
+                    schema::alert::silenced_version,
                     schema::alert::modified,
                 ).nullable(),
             ).nullable(),
@@ -215,6 +223,8 @@ async fn metric_query_json(
     let (threshold, alert) = threshold_model_alert(project, tma);
     let (metric, boundary) = QueryMetricBoundary::split(query_metric_boundary);
     let metric_uuid = metric.uuid;
+    let samples = QueryMetricSample::samples(conn_lock!(context), metric.id)?;
+    let samples = (!samples.is_empty()).then(|| samples.into_iter().map(Into::into).collect());
     let metric = metric.into_json();
     let boundary = boundary.map(QueryBoundary::into_json);
 
@@ -229,6 +239,7 @@ async fn metric_query_json(
         benchmark,
         measure,
         metric,
+        samples,
         threshold,
         boundary,
         alert,
@@ -2,6 +2,8 @@ pub mod alerts;
 pub mod allowed;
 pub mod benchmarks;
 pub mod branches;
+pub mod change_points;
+pub mod composite_thresholds;
 pub mod measures;
 pub mod metrics;
 pub mod perf;
@@ -10,3 +12,4 @@ pub mod projects;
 pub mod reports;
 pub mod testbeds;
 pub mod thresholds;
+pub mod webhooks;
@@ -301,6 +301,7 @@ async fn perf_query(
                     schema::threshold::measure_id,
                     schema::threshold::branch_id,
                     schema::threshold::testbed_id,
+                    schema::threshold::benchmark,
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
@@ -315,6 +316,8 @@ async fn perf_query(
                     schema::model::window,
                     schema::model::lower_boundary,
                     schema::model::upper_boundary,
+                    schema::model::level_smoothing,
+                    schema::model::trend_smoothing,
                     schema::model::created,
                     schema::model::replaced,
                 ),
@@ -323,7 +326,11 @@ async fn perf_query(
                     schema::alert::uuid,
                     schema::alert::boundary_id,
                     schema::alert::boundary_limit,
+                    schema::alert::composite_threshold_id,
                     schema::alert::status,
+                    schema::alert::assignee_id,
+                    schema::alert::silenced_until,
+                    schema::alert::silenced_version,
                     schema::alert::modified,
                 ).nullable(),
             ).nullable(),
@@ -34,9 +34,13 @@ use crate::{
             report::{results::ReportResults, InsertReport, QueryReport, ReportId},
             testbed::QueryTestbed,
             version::{QueryVersion, VersionId},
+            webhook::QueryWebhook,
             QueryProject,
         },
-        user::auth::{AuthUser, BearerToken, PubBearerToken},
+        user::{
+            auth::{AuthUser, BearerToken, PubBearerToken},
+            notification::QueryProjectNotification,
+        },
     },
     schema,
     util::{
@@ -366,7 +370,11 @@ async fn post_inner(
     // Don't return the error from processing the report until after the metrics usage has been checked
     processed_report?;
     // If the report was processed successfully, then return the report with the results
-    query_report.into_json(log, context).await
+    let report_id = query_report.id;
+    let json_report = query_report.into_json(log, context).await?;
+    QueryWebhook::enqueue_for_report(log, context, &project, report_id, &json_report).await;
+    QueryProjectNotification::notify_report_alerts(log, context, &project, &json_report).await;
+    Ok(json_report)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -1,7 +1,7 @@
 use bencher_json::{
     project::threshold::{
-        JsonNewThreshold, JsonThreshold, JsonThresholdQuery, JsonThresholdQueryParams,
-        JsonUpdateThreshold,
+        JsonCreatedThreshold, JsonNewSingleThreshold, JsonNewThreshold, JsonThreshold,
+        JsonThresholdQuery, JsonThresholdQueryParams, JsonUpdateThreshold,
     },
     JsonDirection, JsonPagination, JsonThresholds, ModelUuid, ResourceId, ThresholdUuid,
 };
@@ -29,7 +29,11 @@ use crate::{
             branch::QueryBranch,
             measure::QueryMeasure,
             testbed::QueryTestbed,
-            threshold::{model::QueryModel, InsertThreshold, QueryThreshold},
+            threshold::{
+                composite::{InsertCompositeThreshold, QueryCompositeThreshold},
+                model::QueryModel,
+                InsertThreshold, QueryThreshold,
+            },
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
@@ -219,7 +223,11 @@ type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
 ///
 /// Create a threshold for a project.
 /// The user must have `create` permissions for the project.
-/// There can only be one threshold for any unique combination of: branch, testbed, and measure.
+/// There can only be one threshold for any unique combination of: branch, testbed, measure, and benchmark selector.
+/// A composite threshold combines two or more existing thresholds for the same branch and testbed
+/// into a single alert rule.
+/// Once a threshold is a member of a composite threshold,
+/// it no longer generates alerts on its own.
 #[endpoint {
     method = POST,
     path =  "/v0/projects/{project}/thresholds",
@@ -230,12 +238,12 @@ pub async fn proj_threshold_post(
     bearer_token: BearerToken,
     path_params: Path<ProjThresholdsParams>,
     body: TypedBody<JsonNewThreshold>,
-) -> Result<ResponseCreated<JsonThreshold>, HttpError> {
+) -> Result<ResponseCreated<JsonCreatedThreshold>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
     let json = post_inner(
         rqctx.context(),
         path_params.into_inner(),
-        &body.into_inner(),
+        body.into_inner(),
         &auth_user,
     )
     .await?;
@@ -245,11 +253,13 @@ pub async fn proj_threshold_post(
 async fn post_inner(
     context: &ApiContext,
     path_params: ProjThresholdsParams,
-    json_threshold: &JsonNewThreshold,
+    json_threshold: JsonNewThreshold,
     auth_user: &AuthUser,
-) -> Result<JsonThreshold, HttpError> {
+) -> Result<JsonCreatedThreshold, HttpError> {
     // Validate the new model
-    json_threshold.model.validate().map_err(bad_request_error)?;
+    if let JsonNewThreshold::Single(json_threshold) = &json_threshold {
+        json_threshold.model.validate().map_err(bad_request_error)?;
+    }
 
     // Verify that the user is allowed
     let query_project = QueryProject::is_allowed(
@@ -260,6 +270,33 @@ async fn post_inner(
         Permission::Create,
     )?;
 
+    match json_threshold {
+        JsonNewThreshold::Single(json_threshold) => {
+            post_single_inner(context, &query_project, &json_threshold)
+                .await
+                .map(|json| JsonCreatedThreshold::Single(Box::new(json)))
+        },
+        JsonNewThreshold::Composite(json_composite_threshold) => {
+            // Hold the database lock so all of the members are added together
+            conn_lock!(context, |conn| {
+                let composite_threshold_id = InsertCompositeThreshold::insert_from_json(
+                    conn,
+                    &query_project,
+                    json_composite_threshold,
+                )?;
+                QueryCompositeThreshold::get(conn, composite_threshold_id)?
+                    .into_json_for_project(conn, &query_project)
+            })
+            .map(|json| JsonCreatedThreshold::Composite(Box::new(json)))
+        },
+    }
+}
+
+async fn post_single_inner(
+    context: &ApiContext,
+    query_project: &QueryProject,
+    json_threshold: &JsonNewSingleThreshold,
+) -> Result<JsonThreshold, HttpError> {
     let project_id = query_project.id;
     // Verify that the branch, testbed, and measure are part of the same project
     let branch_id =
@@ -276,6 +313,7 @@ async fn post_inner(
         branch_id,
         testbed_id,
         measure_id,
+        json_threshold.benchmark.clone(),
         json_threshold.model,
     )?;
 
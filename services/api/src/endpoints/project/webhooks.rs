@@ -0,0 +1,523 @@
+use bencher_json::{
+    project::webhook::{JsonUpdateWebhook, JsonWebhookDelivery},
+    JsonDirection, JsonNewWebhook, JsonPagination, JsonWebhook, JsonWebhookDeliveries,
+    JsonWebhooks, ResourceId, WebhookUuid,
+};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{
+            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+        },
+        Endpoint,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            webhook::{delivery::QueryWebhookDelivery, InsertWebhook, QueryWebhook, UpdateWebhook},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+    util::headers::TotalCount,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhooksParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjWebhooksPagination = JsonPagination<ProjWebhooksSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhooksSort {
+    /// Sort by webhook creation date time.
+    #[default]
+    Created,
+    /// Sort by webhook modified date time.
+    Modified,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhooksParams>,
+    _pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List webhooks for a project
+///
+/// List all webhooks for a project.
+/// The user must have `manage` permissions for the project.
+/// By default, the webhooks are sorted by creation date time in chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of webhooks.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<ResponseOk<JsonWebhooks>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhooksParams,
+    pagination_params: ProjWebhooksPagination,
+) -> Result<(JsonWebhooks, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let mut query = QueryWebhook::belonging_to(&query_project).into_boxed();
+    query = match pagination_params.order() {
+        ProjWebhooksSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::webhook::created.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::webhook::created.desc()),
+        },
+        ProjWebhooksSort::Modified => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order(schema::webhook::modified.asc()),
+            Some(JsonDirection::Desc) | None => query.order(schema::webhook::modified.desc()),
+        },
+    };
+    let webhooks = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhook>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?;
+
+    // Separate out these queries to prevent a deadlock when getting the conn_lock
+    let mut json_webhooks = Vec::with_capacity(webhooks.len());
+    for webhook in webhooks {
+        match webhook.into_json_for_project(conn_lock!(context), &query_project) {
+            Ok(webhook) => json_webhooks.push(webhook),
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&err);
+            },
+        }
+    }
+
+    let total_count = QueryWebhook::belonging_to(&query_project)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_webhooks.into(), total_count))
+}
+
+/// Create a webhook
+///
+/// Create a webhook for a project.
+/// The user must have `manage` permissions for the project.
+/// Whenever one of the subscribed events occurs, a signed delivery is sent to the webhook URL.
+/// Failed deliveries are retried with exponential backoff.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseCreated<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    // Create the new webhook
+    // Hold the database lock so all of the events are added together
+    conn_lock!(context, |conn| {
+        let webhook_id = InsertWebhook::insert_from_json(conn, &query_project, json_webhook)?;
+        QueryWebhook::get(conn, webhook_id)?.into_json_for_project(conn, &query_project)
+    })
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhookParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a webhook.
+    pub webhook: WebhookUuid,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a webhook
+///
+/// View a webhook for a project.
+/// The user must have `manage` permissions for the project.
+/// The webhook secret is never returned.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    conn_lock!(context, |conn| QueryWebhook::get_with_uuid(
+        conn,
+        &query_project,
+        path_params.webhook
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+/// Update a webhook
+///
+/// Update a webhook for a project.
+/// The user must have `manage` permissions for the project.
+/// If the events are updated, then they replace all of the existing events.
+/// Deliveries that are already queued are not affected.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    body: TypedBody<JsonUpdateWebhook>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    json_webhook: JsonUpdateWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+    // Hold the database lock so the webhook and its events are updated together
+    conn_lock!(context, |conn| {
+        UpdateWebhook::update_from_json(conn, &query_webhook, json_webhook)?;
+        QueryWebhook::get(conn, query_webhook.id)?.into_json_for_project(conn, &query_project)
+    })
+}
+
+/// Delete a webhook
+///
+/// Delete a webhook for a project.
+/// The user must have `manage` permissions for the project.
+/// All of the webhook's deliveries are also deleted, including any that are still pending.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, query_webhook))?;
+
+    Ok(())
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/test",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_test_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Send a test delivery to a webhook
+///
+/// Send a `test` event delivery to a webhook for a project and wait for the response.
+/// The user must have `manage` permissions for the project.
+/// Test deliveries are always sent, regardless of the subscribed events.
+/// A failed test delivery is recorded in the delivery log, but it is not retried.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/test",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_test_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseCreated<JsonWebhookDelivery>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_test_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_test_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhookDelivery, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+    let query_delivery =
+        QueryWebhookDelivery::send_test(context, &query_project, &query_webhook).await?;
+
+    Ok(query_delivery.into_json(query_webhook.uuid))
+}
+
+pub type ProjWebhookDeliveriesPagination = JsonPagination<ProjWebhookDeliveriesSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhookDeliveriesSort {
+    /// Sort by webhook delivery creation date time.
+    #[default]
+    Created,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+    _pagination_params: Query<ProjWebhookDeliveriesPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List deliveries for a webhook
+///
+/// List the delivery log for a webhook for a project.
+/// The user must have `manage` permissions for the project.
+/// Each delivery includes its payload, status, number of attempts,
+/// and the response status or error from the last attempt.
+/// By default, the deliveries are sorted by creation date time in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of deliveries.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    pagination_params: Query<ProjWebhookDeliveriesPagination>,
+) -> Result<ResponseOk<JsonWebhookDeliveries>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_deliveries_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_deliveries_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhookParams,
+    pagination_params: ProjWebhookDeliveriesPagination,
+) -> Result<(JsonWebhookDeliveries, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+
+    let mut query = QueryWebhookDelivery::belonging_to(&query_webhook).into_boxed();
+    query = match pagination_params.order() {
+        ProjWebhookDeliveriesSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order((
+                schema::webhook_delivery::created.asc(),
+                schema::webhook_delivery::id.asc(),
+            )),
+            Some(JsonDirection::Desc) | None => query.order((
+                schema::webhook_delivery::created.desc(),
+                schema::webhook_delivery::id.desc(),
+            )),
+        },
+    };
+    let json_deliveries = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhookDelivery>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            WebhookDelivery,
+            (&query_webhook, &pagination_params)
+        ))?
+        .into_iter()
+        .map(|delivery| delivery.into_json(query_webhook.uuid))
+        .collect();
+
+    let total_count = QueryWebhookDelivery::belonging_to(&query_webhook)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            WebhookDelivery,
+            (&query_webhook, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_deliveries, total_count))
+}
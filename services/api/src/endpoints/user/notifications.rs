@@ -0,0 +1,252 @@
+use bencher_json::{
+    user::notification::JsonUpdateNotification, JsonDirection, JsonNotification, JsonNotifications,
+    JsonPagination, ResourceId,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Get, Patch, ResponseOk},
+        Endpoint,
+    },
+    error::resource_not_found_err,
+    model::{
+        project::QueryProject,
+        user::{
+            auth::{AuthUser, BearerToken},
+            notification::QueryProjectNotification,
+            same_user, QueryUser, UserId,
+        },
+    },
+    schema,
+    util::headers::TotalCount,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct UserNotificationsParams {
+    /// The slug or UUID for a user.
+    pub user: ResourceId,
+}
+
+pub type UserNotificationsPagination = JsonPagination<UserNotificationsSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserNotificationsSort {
+    /// Sort by project name.
+    #[default]
+    Name,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/users/{user}/notifications",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notifications_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<UserNotificationsParams>,
+    _pagination_params: Query<UserNotificationsPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List notification preferences for a user
+///
+/// List the notification preferences of a user for every project that they are a member of.
+/// Only the authenticated user themselves and server admins have access to this endpoint.
+/// By default, the notification preferences are sorted in alphabetical order by project name.
+/// The HTTP response header `X-Total-Count` contains the total number of projects.
+#[endpoint {
+    method = GET,
+    path =  "/v0/users/{user}/notifications",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notifications_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<UserNotificationsParams>,
+    pagination_params: Query<UserNotificationsPagination>,
+) -> Result<ResponseOk<JsonNotifications>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: UserNotificationsParams,
+    pagination_params: UserNotificationsPagination,
+    auth_user: &AuthUser,
+) -> Result<(JsonNotifications, TotalCount), HttpError> {
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    same_user!(auth_user, context.rbac, query_user.uuid);
+
+    let projects = get_ls_query(&pagination_params, query_user.id)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryProject>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Project,
+            (&pagination_params, &query_user)
+        ))?;
+
+    let mut json_notifications = Vec::with_capacity(projects.len());
+    for query_project in projects {
+        match QueryProjectNotification::get_json(conn_lock!(context), &query_user, query_project) {
+            Ok(json_notification) => json_notifications.push(json_notification),
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&err);
+            },
+        }
+    }
+
+    let total_count = get_ls_query(&pagination_params, query_user.id)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Project,
+            (&pagination_params, &query_user)
+        ))?
+        .try_into()?;
+
+    Ok((json_notifications.into(), total_count))
+}
+
+fn get_ls_query(
+    pagination_params: &UserNotificationsPagination,
+    user_id: UserId,
+) -> schema::project::BoxedQuery<'static, diesel::sqlite::Sqlite> {
+    let organizations = schema::organization_role::table
+        .filter(schema::organization_role::user_id.eq(user_id))
+        .select(schema::organization_role::organization_id);
+    let query = schema::project::table
+        .filter(schema::project::organization_id.eq_any(organizations))
+        .into_boxed();
+
+    match pagination_params.order() {
+        UserNotificationsSort::Name => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::project::name.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::project::name.desc()),
+        },
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct UserNotificationParams {
+    /// The slug or UUID for a user.
+    pub user: ResourceId,
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/users/{user}/notifications/{project}",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notification_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<UserNotificationParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into()]))
+}
+
+/// View notification preferences for a project
+///
+/// View the notification preferences of a user for a project.
+/// The user must be a member of the project's organization.
+/// Only the authenticated user themselves and server admins have access to this endpoint.
+#[endpoint {
+    method = GET,
+    path =  "/v0/users/{user}/notifications/{project}",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notification_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<UserNotificationParams>,
+) -> Result<ResponseOk<JsonNotification>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: UserNotificationParams,
+    auth_user: &AuthUser,
+) -> Result<JsonNotification, HttpError> {
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    same_user!(auth_user, context.rbac, query_user.uuid);
+
+    let query_project = QueryProject::from_resource_id(conn_lock!(context), &path_params.project)?;
+    QueryProjectNotification::assert_member(conn_lock!(context), &query_user, &query_project)?;
+
+    QueryProjectNotification::get_json(conn_lock!(context), &query_user, query_project)
+}
+
+/// Update notification preferences for a project
+///
+/// Update the notification preferences of a user for a project.
+/// The user must be a member of the project's organization.
+/// Notifications are opt-in, so by default a user does not get any alert emails for a project.
+/// Only the authenticated user themselves and server admins have access to this endpoint.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/users/{user}/notifications/{project}",
+    tags = ["users", "notifications"]
+}]
+pub async fn user_notification_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<UserNotificationParams>,
+    body: TypedBody<JsonUpdateNotification>,
+) -> Result<ResponseOk<JsonNotification>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: UserNotificationParams,
+    json_notification: JsonUpdateNotification,
+    auth_user: &AuthUser,
+) -> Result<JsonNotification, HttpError> {
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    same_user!(auth_user, context.rbac, query_user.uuid);
+
+    let query_project = QueryProject::from_resource_id(conn_lock!(context), &path_params.project)?;
+    QueryProjectNotification::assert_member(conn_lock!(context), &query_user, &query_project)?;
+
+    QueryProjectNotification::update_from_json(
+        conn_lock!(context),
+        &query_user,
+        &query_project,
+        &json_notification,
+    )?;
+
+    QueryProjectNotification::get_json(conn_lock!(context), &query_user, query_project)
+}
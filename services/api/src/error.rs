@@ -1,5 +1,6 @@
 use std::fmt;
 
+use bencher_adapter::ParseError;
 use dropshot::HttpError;
 use http::StatusCode;
 use once_cell::sync::Lazy;
@@ -25,12 +26,20 @@ pub enum BencherResource {
     Benchmark,
     Measure,
     Metric,
+    MetricSample,
     Threshold,
+    CompositeThreshold,
+    CompositeMember,
     Model,
     Boundary,
     Alert,
+    AlertEvent,
+    Webhook,
+    WebhookSubscription,
+    WebhookDelivery,
     User,
     Token,
+    ProjectNotification,
     #[cfg(feature = "plus")]
     Plan,
     #[cfg(feature = "plus")]
@@ -61,12 +70,20 @@ impl fmt::Display for BencherResource {
                 Self::Benchmark => "Benchmark",
                 Self::Measure => "Measure",
                 Self::Metric => "Metric",
+                Self::MetricSample => "Metric Sample",
                 Self::Threshold => "Threshold",
+                Self::CompositeThreshold => "Composite Threshold",
+                Self::CompositeMember => "Composite Member",
                 Self::Model => "Model",
                 Self::Boundary => "Boundary",
                 Self::Alert => "Alert",
+                Self::AlertEvent => "Alert Event",
+                Self::Webhook => "Webhook",
+                Self::WebhookSubscription => "Webhook Subscription",
+                Self::WebhookDelivery => "Webhook Delivery",
                 Self::User => "User",
                 Self::Token => "Token",
+                Self::ProjectNotification => "Project Notification",
                 #[cfg(feature = "plus")]
                 Self::Plan => "Plan",
                 #[cfg(feature = "plus")]
@@ -85,6 +102,18 @@ where
     HttpError::for_client_error(None, StatusCode::BAD_REQUEST, error.to_string())
 }
 
+// The parse error is returned as JSON so clients can point at the offending line
+pub fn parse_error(error: &ParseError) -> HttpError {
+    match serde_json::to_string(error) {
+        Ok(message) => HttpError::for_client_error(
+            Some(ParseError::ERROR_CODE.to_owned()),
+            StatusCode::BAD_REQUEST,
+            message,
+        ),
+        Err(_) => bad_request_error(error),
+    }
+}
+
 pub fn unauthorized_error<E>(error: E) -> HttpError
 where
     E: fmt::Display,
@@ -547,6 +547,7 @@ impl InsertBranch {
             new_branch_id,
             query_threshold.testbed_id,
             query_threshold.measure_id,
+            query_threshold.benchmark.clone(),
         );
 
         // Create the new threshold
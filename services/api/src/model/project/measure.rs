@@ -1,11 +1,19 @@
 use bencher_json::{
     project::measure::{
-        JsonUpdateMeasure, MeasureUuid, ESTIMATED_CYCLES_NAME_STR, ESTIMATED_CYCLES_SLUG_STR,
-        FILE_SIZE_NAME_STR, FILE_SIZE_SLUG_STR, INSTRUCTIONS_NAME_STR, INSTRUCTIONS_SLUG_STR,
+        JsonUpdateMeasure, MeasureUuid, ALLOCATED_BYTES_NAME_STR, ALLOCATED_BYTES_SLUG_STR,
+        ALLOCATIONS_NAME_STR, ALLOCATIONS_SLUG_STR, ALLOCATION_RATE_NAME_STR,
+        ALLOCATION_RATE_SLUG_STR, ERROR_RATE_NAME_STR, ERROR_RATE_SLUG_STR,
+        ESTIMATED_CYCLES_NAME_STR, ESTIMATED_CYCLES_SLUG_STR, FILE_SIZE_NAME_STR,
+        FILE_SIZE_SLUG_STR, GC_COUNT_NAME_STR, GC_COUNT_SLUG_STR, GC_TIME_NAME_STR,
+        GC_TIME_SLUG_STR, GEN0_COLLECTIONS_NAME_STR, GEN0_COLLECTIONS_SLUG_STR,
+        GEN1_COLLECTIONS_NAME_STR, GEN1_COLLECTIONS_SLUG_STR, GEN2_COLLECTIONS_NAME_STR,
+        GEN2_COLLECTIONS_SLUG_STR, INSTRUCTIONS_NAME_STR, INSTRUCTIONS_SLUG_STR,
         L1_ACCESSES_NAME_STR, L1_ACCESSES_SLUG_STR, L2_ACCESSES_NAME_STR, L2_ACCESSES_SLUG_STR,
-        LATENCY_NAME_STR, LATENCY_SLUG_STR, MEASURE_UNITS, RAM_ACCESSES_NAME_STR,
-        RAM_ACCESSES_SLUG_STR, THROUGHPUT_NAME_STR, THROUGHPUT_SLUG_STR, TOTAL_ACCESSES_NAME_STR,
-        TOTAL_ACCESSES_SLUG_STR,
+        LATENCY_NAME_STR, LATENCY_P50_NAME_STR, LATENCY_P50_SLUG_STR, LATENCY_P90_NAME_STR,
+        LATENCY_P90_SLUG_STR, LATENCY_P99_NAME_STR, LATENCY_P99_SLUG_STR, LATENCY_SLUG_STR,
+        MEASURE_UNITS, RAM_ACCESSES_NAME_STR, RAM_ACCESSES_SLUG_STR, SYSTEM_TIME_NAME_STR,
+        SYSTEM_TIME_SLUG_STR, THROUGHPUT_NAME_STR, THROUGHPUT_SLUG_STR, TOTAL_ACCESSES_NAME_STR,
+        TOTAL_ACCESSES_SLUG_STR, USER_TIME_NAME_STR, USER_TIME_SLUG_STR,
     },
     DateTime, JsonMeasure, JsonNewMeasure, MeasureNameId, NameIdKind, ResourceName, Slug,
 };
@@ -85,6 +93,34 @@ impl QueryMeasure {
             ESTIMATED_CYCLES_NAME_STR | ESTIMATED_CYCLES_SLUG_STR => {
                 JsonNewMeasure::estimated_cycles()
             },
+            // Allocations
+            ALLOCATIONS_NAME_STR | ALLOCATIONS_SLUG_STR => JsonNewMeasure::allocations(),
+            ALLOCATED_BYTES_NAME_STR | ALLOCATED_BYTES_SLUG_STR => {
+                JsonNewMeasure::allocated_bytes()
+            },
+            // Garbage collections
+            GEN0_COLLECTIONS_NAME_STR | GEN0_COLLECTIONS_SLUG_STR => {
+                JsonNewMeasure::gen0_collections()
+            },
+            GEN1_COLLECTIONS_NAME_STR | GEN1_COLLECTIONS_SLUG_STR => {
+                JsonNewMeasure::gen1_collections()
+            },
+            GEN2_COLLECTIONS_NAME_STR | GEN2_COLLECTIONS_SLUG_STR => {
+                JsonNewMeasure::gen2_collections()
+            },
+            ALLOCATION_RATE_NAME_STR | ALLOCATION_RATE_SLUG_STR => {
+                JsonNewMeasure::allocation_rate()
+            },
+            GC_COUNT_NAME_STR | GC_COUNT_SLUG_STR => JsonNewMeasure::gc_count(),
+            GC_TIME_NAME_STR | GC_TIME_SLUG_STR => JsonNewMeasure::gc_time(),
+            // Load tests
+            LATENCY_P50_NAME_STR | LATENCY_P50_SLUG_STR => JsonNewMeasure::latency_p50(),
+            LATENCY_P90_NAME_STR | LATENCY_P90_SLUG_STR => JsonNewMeasure::latency_p90(),
+            LATENCY_P99_NAME_STR | LATENCY_P99_SLUG_STR => JsonNewMeasure::latency_p99(),
+            ERROR_RATE_NAME_STR | ERROR_RATE_SLUG_STR => JsonNewMeasure::error_rate(),
+            // CPU time
+            USER_TIME_NAME_STR | USER_TIME_SLUG_STR => JsonNewMeasure::user_time(),
+            SYSTEM_TIME_NAME_STR | SYSTEM_TIME_SLUG_STR => JsonNewMeasure::system_time(),
             // File size
             FILE_SIZE_NAME_STR | FILE_SIZE_SLUG_STR => JsonNewMeasure::file_size(),
             _ => {
@@ -103,13 +103,15 @@ impl InsertMetric {
     pub fn from_json(
         report_benchmark_id: ReportBenchmarkId,
         measure_id: MeasureId,
-        metric: JsonNewMetric,
+        metric: &JsonNewMetric,
     ) -> Self {
+        // The raw samples are stored separately, see `InsertMetricSample`
         let JsonNewMetric {
             value,
             lower_value,
             upper_value,
-        } = metric;
+            ..
+        } = *metric;
         Self {
             uuid: MetricUuid::new(),
             report_benchmark_id,
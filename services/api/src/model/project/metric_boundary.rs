@@ -37,6 +37,7 @@ pub struct QueryMetricBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub p_value: Option<f64>,
 }
 
 impl QueryMetricBoundary {
@@ -56,6 +57,7 @@ impl QueryMetricBoundary {
             baseline,
             lower_limit,
             upper_limit,
+            p_value,
         } = self;
         let query_metric = QueryMetric {
             id: metric_id,
@@ -78,6 +80,7 @@ impl QueryMetricBoundary {
                 baseline,
                 lower_limit,
                 upper_limit,
+                p_value,
             })
         } else {
             None
@@ -0,0 +1,92 @@
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use ordered_float::OrderedFloat;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::{self, metric_sample as metric_sample_table},
+};
+
+use super::metric::{MetricId, QueryMetric};
+
+// Each sample binds three parameters,
+// so this stays under the SQLite bound parameter limit.
+const SAMPLES_CHUNK_SIZE: usize = 10_000;
+
+#[derive(Debug, diesel::Queryable, diesel::Associations, diesel::Selectable)]
+#[diesel(table_name = metric_sample_table)]
+#[diesel(belongs_to(QueryMetric, foreign_key = metric_id))]
+pub struct QueryMetricSample {
+    pub metric_id: MetricId,
+    pub position: i32,
+    pub value: f64,
+}
+
+impl QueryMetricSample {
+    // The raw samples for a metric, in the order they were reported
+    pub fn samples(conn: &mut DbConnection, metric_id: MetricId) -> Result<Vec<f64>, HttpError> {
+        schema::metric_sample::table
+            .filter(schema::metric_sample::metric_id.eq(metric_id))
+            .order(schema::metric_sample::position.asc())
+            .select(schema::metric_sample::value)
+            .load::<f64>(conn)
+            .map_err(resource_not_found_err!(MetricSample, metric_id))
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = metric_sample_table)]
+pub struct InsertMetricSample {
+    pub metric_id: MetricId,
+    pub position: i32,
+    pub value: f64,
+}
+
+impl InsertMetricSample {
+    pub fn insert(
+        conn: &mut DbConnection,
+        metric_id: MetricId,
+        samples: Vec<OrderedFloat<f64>>,
+    ) -> Result<(), HttpError> {
+        let insert_samples = (0..)
+            .zip(samples)
+            .map(|(position, value)| Self {
+                metric_id,
+                position,
+                value: value.into(),
+            })
+            .collect::<Vec<_>>();
+        for insert_samples in insert_samples.chunks(SAMPLES_CHUNK_SIZE) {
+            diesel::insert_into(schema::metric_sample::table)
+                .values(insert_samples)
+                .execute(conn)
+                .map_err(resource_conflict_err!(MetricSample, metric_id))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ordered_float::OrderedFloat;
+
+    use crate::{model::project::metric::MetricId, util::test_db::test_db};
+
+    use super::{InsertMetricSample, QueryMetricSample, SAMPLES_CHUNK_SIZE};
+
+    #[test]
+    fn test_insert_samples_chunked() {
+        let mut conn = test_db();
+        let metric_id = MetricId::default();
+        let count = SAMPLES_CHUNK_SIZE * 2 + 1;
+        #[allow(clippy::cast_precision_loss)]
+        let values = (0..count).map(|i| i as f64).collect::<Vec<_>>();
+        let samples = values.iter().copied().map(OrderedFloat::from).collect();
+
+        InsertMetricSample::insert(&mut conn, metric_id, samples).unwrap();
+
+        let samples = QueryMetricSample::samples(&mut conn, metric_id).unwrap();
+        assert_eq!(samples, values);
+    }
+}
@@ -28,12 +28,14 @@ pub mod branch_version;
 pub mod measure;
 pub mod metric;
 pub mod metric_boundary;
+pub mod metric_sample;
 pub mod plot;
 pub mod project_role;
 pub mod report;
 pub mod testbed;
 pub mod threshold;
 pub mod version;
+pub mod webhook;
 
 crate::util::typed_id::typed_id!(ProjectId);
 
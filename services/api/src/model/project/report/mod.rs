@@ -149,6 +149,7 @@ async fn get_report_results(
                 schema::threshold::measure_id,
                 schema::threshold::branch_id,
                 schema::threshold::testbed_id,
+                schema::threshold::benchmark,
                 schema::threshold::model_id,
                 schema::threshold::created,
                 schema::threshold::modified,
@@ -163,6 +164,8 @@ async fn get_report_results(
                 schema::model::window,
                 schema::model::lower_boundary,
                 schema::model::upper_boundary,
+                schema::model::level_smoothing,
+                schema::model::trend_smoothing,
                 schema::model::created,
                 schema::model::replaced,
             )
@@ -0,0 +1,311 @@
+use bencher_json::{
+    project::{boundary::BoundaryLimit, composite_threshold::CompositeOperator},
+    BenchmarkMatcher, BenchmarkName, BenchmarkSelector, BoundaryUuid,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{bad_request_error, resource_not_found_err},
+    model::project::{
+        branch::BranchId,
+        measure::MeasureId,
+        report::ReportId,
+        testbed::TestbedId,
+        threshold::{
+            alert::{AlertSilence, InsertAlert},
+            composite::{CompositeThresholdId, QueryCompositeMember, QueryCompositeThreshold},
+            ThresholdId,
+        },
+    },
+    schema,
+};
+
+use super::Detection;
+
+#[derive(Debug, Clone)]
+pub struct CompositeDetector {
+    pub id: CompositeThresholdId,
+    pub operator: CompositeOperator,
+    pub members: Vec<CompositeMember>,
+}
+
+/// A member threshold of a composite threshold.
+/// Only one threshold is selected for each benchmark and measure,
+/// so a member is resolved against the detection of the selected threshold for its measure.
+/// That is, a benchmark-specific threshold stands in for a more generic member threshold.
+#[derive(Debug, Clone)]
+pub struct CompositeMember {
+    pub threshold_id: ThresholdId,
+    pub measure_id: MeasureId,
+    // Only benchmarks matching the member threshold selector are checked, if one is set
+    pub benchmark: Option<BenchmarkMatcher>,
+    pub limit: Option<BoundaryLimit>,
+}
+
+impl CompositeDetector {
+    pub fn new_list(
+        conn: &mut DbConnection,
+        branch_id: BranchId,
+        testbed_id: TestbedId,
+    ) -> Result<Vec<Self>, HttpError> {
+        let composite_thresholds = schema::composite_threshold::table
+            .filter(schema::composite_threshold::branch_id.eq(branch_id))
+            .filter(schema::composite_threshold::testbed_id.eq(testbed_id))
+            .select(QueryCompositeThreshold::as_select())
+            .load::<QueryCompositeThreshold>(conn)
+            .map_err(resource_not_found_err!(
+                CompositeThreshold,
+                (branch_id, testbed_id)
+            ))?;
+
+        composite_thresholds
+            .into_iter()
+            .map(|composite_threshold| {
+                let members = composite_threshold
+                    .members(conn)?
+                    .into_iter()
+                    .map(|query_member| CompositeMember::new(conn, &query_member))
+                    .collect::<Result<Vec<_>, HttpError>>()?;
+                Ok(Self {
+                    id: composite_threshold.id,
+                    operator: composite_threshold.operator,
+                    members,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether the threshold selected for the benchmark and measure stands in for a member
+    pub fn is_member(&self, measure_id: MeasureId, benchmark_name: &BenchmarkName) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.is_match(measure_id, benchmark_name))
+    }
+
+    pub fn detect(
+        &self,
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        benchmark_name: &BenchmarkName,
+        detections: &[Detection],
+    ) -> Result<(), HttpError> {
+        // The composite alert is anchored to the boundary of the first member with an outlier
+        if let Some((boundary_uuid, boundary_limit, silence)) =
+            self.outlier(benchmark_name, detections)
+        {
+            InsertAlert::from_composite(
+                conn,
+                report_id,
+                boundary_uuid,
+                boundary_limit,
+                self.id,
+                silence,
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    fn outlier(
+        &self,
+        benchmark_name: &BenchmarkName,
+        detections: &[Detection],
+    ) -> Option<(BoundaryUuid, BoundaryLimit, Option<AlertSilence>)> {
+        // A member without a detection, such as a measure missing from the benchmark results
+        // or a member threshold selector that does not match the benchmark, does not have an outlier.
+        let outliers = self
+            .members
+            .iter()
+            .map(|member| {
+                detections
+                    .iter()
+                    .find(|detection| member.is_match(detection.measure_id, benchmark_name))
+                    .and_then(|detection| {
+                        detection
+                            .outlier
+                            .filter(|outlier| member.limit.map_or(true, |limit| limit == *outlier))
+                            .map(|outlier| (detection.boundary_uuid, outlier, detection.silence))
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        if self.operator.combine(outliers.iter().map(Option::is_some)) {
+            outliers.into_iter().flatten().next()
+        } else {
+            None
+        }
+    }
+}
+
+impl CompositeMember {
+    fn new(
+        conn: &mut DbConnection,
+        query_member: &QueryCompositeMember,
+    ) -> Result<Self, HttpError> {
+        let &QueryCompositeMember {
+            threshold_id,
+            boundary_limit,
+            ..
+        } = query_member;
+        let (measure_id, benchmark) = schema::threshold::table
+            .filter(schema::threshold::id.eq(threshold_id))
+            .select((schema::threshold::measure_id, schema::threshold::benchmark))
+            .first::<(MeasureId, Option<BenchmarkSelector>)>(conn)
+            .map_err(resource_not_found_err!(Threshold, threshold_id))?;
+        let benchmark = benchmark
+            .map(|benchmark| benchmark.matcher())
+            .transpose()
+            .map_err(bad_request_error)?;
+        Ok(Self {
+            threshold_id,
+            measure_id,
+            benchmark,
+            limit: boundary_limit,
+        })
+    }
+
+    fn is_match(&self, measure_id: MeasureId, benchmark_name: &BenchmarkName) -> bool {
+        self.measure_id == measure_id
+            && self.benchmark.as_ref().map_or(true, |benchmark| {
+                benchmark.is_match(benchmark_name.as_ref())
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{
+        project::{boundary::BoundaryLimit, composite_threshold::CompositeOperator},
+        BenchmarkName, BenchmarkSelector, BoundaryUuid,
+    };
+
+    use crate::model::project::{measure::MeasureId, threshold::ThresholdId};
+
+    use super::{CompositeDetector, CompositeMember, Detection};
+
+    const LATENCY: i32 = 1;
+    const THROUGHPUT: i32 = 2;
+
+    fn member(threshold_id: i32, measure_id: i32, selector: Option<&str>) -> CompositeMember {
+        CompositeMember {
+            threshold_id: ThresholdId::from(threshold_id),
+            measure_id: MeasureId::from(measure_id),
+            benchmark: selector.map(|selector| {
+                selector
+                    .parse::<BenchmarkSelector>()
+                    .unwrap()
+                    .matcher()
+                    .unwrap()
+            }),
+            limit: None,
+        }
+    }
+
+    fn composite(operator: CompositeOperator, members: Vec<CompositeMember>) -> CompositeDetector {
+        CompositeDetector {
+            id: 1.into(),
+            operator,
+            members,
+        }
+    }
+
+    fn detection(threshold_id: i32, measure_id: i32, outlier: Option<BoundaryLimit>) -> Detection {
+        Detection {
+            threshold_id: ThresholdId::from(threshold_id),
+            measure_id: MeasureId::from(measure_id),
+            boundary_uuid: BoundaryUuid::new(),
+            outlier,
+            silence: None,
+        }
+    }
+
+    fn benchmark(name: &str) -> BenchmarkName {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_composite_resolves_selected_threshold() {
+        // Generic member thresholds for both measures
+        let composite_detector = composite(
+            CompositeOperator::And,
+            vec![member(1, LATENCY, None), member(2, THROUGHPUT, None)],
+        );
+        let benchmark_name = benchmark("fib_10");
+        assert!(composite_detector.is_member(LATENCY.into(), &benchmark_name));
+        assert!(composite_detector.is_member(THROUGHPUT.into(), &benchmark_name));
+
+        // A benchmark-specific latency threshold (3) is selected instead of the generic member (1)
+        let specific = detection(3, LATENCY, Some(BoundaryLimit::Upper));
+        let detections = [
+            specific,
+            detection(2, THROUGHPUT, Some(BoundaryLimit::Lower)),
+        ];
+        let (boundary_uuid, boundary_limit, _) = composite_detector
+            .outlier(&benchmark_name, &detections)
+            .unwrap();
+        assert_eq!(boundary_uuid, specific.boundary_uuid);
+        assert_eq!(boundary_limit, BoundaryLimit::Upper);
+
+        // Every member must have an outlier
+        let detections = [
+            detection(3, LATENCY, Some(BoundaryLimit::Upper)),
+            detection(2, THROUGHPUT, None),
+        ];
+        assert!(composite_detector
+            .outlier(&benchmark_name, &detections)
+            .is_none());
+    }
+
+    #[test]
+    fn test_composite_member_selector() {
+        let composite_detector = composite(
+            CompositeOperator::Or,
+            vec![member(1, LATENCY, Some("fib_*"))],
+        );
+        let detections = [detection(1, LATENCY, Some(BoundaryLimit::Upper))];
+
+        let benchmark_name = benchmark("fib_10");
+        assert!(composite_detector.is_member(LATENCY.into(), &benchmark_name));
+        assert!(!composite_detector.is_member(THROUGHPUT.into(), &benchmark_name));
+        assert!(composite_detector
+            .outlier(&benchmark_name, &detections)
+            .is_some());
+
+        // The member threshold selector does not match the benchmark
+        let benchmark_name = benchmark("sort_1024");
+        assert!(!composite_detector.is_member(LATENCY.into(), &benchmark_name));
+        assert!(composite_detector
+            .outlier(&benchmark_name, &detections)
+            .is_none());
+    }
+
+    #[test]
+    fn test_composite_member_limit() {
+        let mut lower = member(1, LATENCY, None);
+        lower.limit = Some(BoundaryLimit::Lower);
+        let composite_detector = composite(
+            CompositeOperator::Or,
+            vec![lower, member(2, THROUGHPUT, None)],
+        );
+        let benchmark_name = benchmark("fib_10");
+
+        let detections = [
+            detection(1, LATENCY, Some(BoundaryLimit::Upper)),
+            detection(2, THROUGHPUT, None),
+        ];
+        assert!(composite_detector
+            .outlier(&benchmark_name, &detections)
+            .is_none());
+
+        let detections = [
+            detection(1, LATENCY, Some(BoundaryLimit::Upper)),
+            detection(2, THROUGHPUT, Some(BoundaryLimit::Upper)),
+        ];
+        let (_, boundary_limit, _) = composite_detector
+            .outlier(&benchmark_name, &detections)
+            .unwrap();
+        assert_eq!(boundary_limit, BoundaryLimit::Upper);
+    }
+}
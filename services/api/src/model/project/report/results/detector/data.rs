@@ -8,21 +8,27 @@ use crate::{
     context::DbConnection,
     error::not_found_error,
     model::project::{
-        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, testbed::TestbedId,
+        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, metric::MetricId,
+        report::ReportId, testbed::TestbedId,
     },
     schema,
 };
 
 use super::threshold::ThresholdModel;
 
+const METRIC_IDS_CHUNK_SIZE: usize = 10_000;
+
+#[allow(clippy::too_many_arguments)]
 pub fn metrics_data(
     log: &Logger,
     conn: &mut DbConnection,
+    report_id: ReportId,
     branch_id: BranchId,
     testbed_id: TestbedId,
     benchmark_id: BenchmarkId,
     measure_id: MeasureId,
     model: &ThresholdModel,
+    candidate: &[f64],
 ) -> Result<MetricsData, HttpError> {
     let mut query =
         schema::metric::table
@@ -47,6 +53,12 @@ pub fn metrics_data(
             .filter(schema::metric::measure_id.eq(measure_id))
             .into_boxed();
 
+    // Two-sample and series tests compare the candidate report against all of the other reports
+    let two_sample = model.test.is_two_sample();
+    if two_sample || model.test.is_series() {
+        query = query.filter(schema::report::id.ne(report_id));
+    }
+
     if let Some(window) = model.window {
         let now = Utc::now().timestamp();
         if let Some(start_time) = now.checked_sub(window.into()) {
@@ -70,12 +82,40 @@ pub fn metrics_data(
         query = query.limit(max_sample_size.into());
     }
 
-    let data = query
-        .select(schema::metric::value)
-        .load::<f64>(conn)
+    let (metric_ids, data): (Vec<MetricId>, Vec<f64>) = query
+        .select((schema::metric::id, schema::metric::value))
+        .load::<(MetricId, f64)>(conn)
         .map_err(not_found_error)?
         .into_iter()
-        .collect();
+        .unzip();
+
+    let samples = metric_samples(conn, &metric_ids)?;
 
-    Ok(MetricsData { data })
+    // The candidate sample is only used by two-sample tests
+    let candidate = if two_sample {
+        candidate.to_vec()
+    } else {
+        Vec::new()
+    };
+
+    Ok(MetricsData {
+        data,
+        samples,
+        candidate,
+    })
+}
+
+fn metric_samples(conn: &mut DbConnection, metric_ids: &[MetricId]) -> Result<Vec<f64>, HttpError> {
+    // Query the raw samples in chunks to stay under the SQLite bound parameter limit
+    let mut samples = Vec::new();
+    for metric_ids in metric_ids.chunks(METRIC_IDS_CHUNK_SIZE) {
+        samples.extend(
+            schema::metric_sample::table
+                .filter(schema::metric_sample::metric_id.eq_any(metric_ids))
+                .select(schema::metric_sample::value)
+                .load::<f64>(conn)
+                .map_err(not_found_error)?,
+        );
+    }
+    Ok(samples)
 }
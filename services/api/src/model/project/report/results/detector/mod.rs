@@ -1,5 +1,5 @@
 use bencher_boundary::MetricsBoundary;
-use bencher_json::BoundaryUuid;
+use bencher_json::{project::boundary::BoundaryLimit, BenchmarkName, BoundaryUuid};
 use diesel::RunQueryDsl;
 use dropshot::HttpError;
 use slog::Logger;
@@ -13,12 +13,18 @@ use crate::{
         branch::BranchId,
         measure::MeasureId,
         metric::QueryMetric,
+        report::ReportId,
         testbed::TestbedId,
-        threshold::{alert::InsertAlert, boundary::InsertBoundary},
+        threshold::{
+            alert::{AlertSilence, InsertAlert, QueryAlert},
+            boundary::InsertBoundary,
+            ThresholdId,
+        },
     },
     schema,
 };
 
+pub mod composite;
 pub mod data;
 pub mod threshold;
 
@@ -31,41 +37,68 @@ pub struct Detector {
     pub testbed_id: TestbedId,
     pub measure_id: MeasureId,
     pub threshold: Threshold,
+    // Whether the threshold is resolved as a member of a composite threshold for the benchmark
+    pub composite: bool,
+}
+
+/// The result of checking a metric against a threshold
+#[derive(Debug, Clone, Copy)]
+pub struct Detection {
+    pub threshold_id: ThresholdId,
+    pub measure_id: MeasureId,
+    pub boundary_uuid: BoundaryUuid,
+    pub outlier: Option<BoundaryLimit>,
+    // The silence to carry over to a new alert, if the benchmark is silenced
+    pub silence: Option<AlertSilence>,
 }
 
 impl Detector {
-    pub fn new(
+    pub fn new_list(
         conn: &mut DbConnection,
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
-    ) -> Option<Self> {
-        // Check to see if there is a threshold for the branch/testbed/measure grouping.
-        // If not, then there will be nothing to detect.
-        Threshold::new(conn, branch_id, testbed_id, measure_id).map(|threshold| Self {
-            branch_id,
-            testbed_id,
-            measure_id,
-            threshold,
-        })
+    ) -> Result<Vec<Self>, HttpError> {
+        // Get all of the thresholds for the branch/testbed/measure grouping.
+        // If there are none, then there will be nothing to detect.
+        Ok(
+            Threshold::new_list(conn, branch_id, testbed_id, measure_id)?
+                .into_iter()
+                .map(|threshold| Self {
+                    branch_id,
+                    testbed_id,
+                    measure_id,
+                    threshold,
+                    composite: false,
+                })
+                .collect(),
+        )
+    }
+
+    pub fn is_match(&self, benchmark_name: &BenchmarkName) -> bool {
+        self.threshold.is_match(benchmark_name)
     }
 
     pub async fn detect(
         &self,
         log: &Logger,
         context: &ApiContext,
+        report_id: ReportId,
         benchmark_id: BenchmarkId,
         query_metric: &QueryMetric,
-    ) -> Result<(), HttpError> {
+        candidate: &[f64],
+    ) -> Result<Detection, HttpError> {
         // Query the historical population/sample data for the benchmark
         let metrics_data = metrics_data(
             log,
             conn_lock!(context),
+            report_id,
             self.branch_id,
             self.testbed_id,
             benchmark_id,
             self.measure_id,
             &self.threshold.model,
+            candidate,
         )?;
 
         // Check to see if the metric has a boundary check for the given threshold model.
@@ -77,6 +110,8 @@ impl Detector {
             self.threshold.model.min_sample_size,
             self.threshold.model.lower_boundary,
             self.threshold.model.upper_boundary,
+            self.threshold.model.level_smoothing,
+            self.threshold.model.trend_smoothing,
         )
         .map_err(bad_request_error)?;
 
@@ -89,6 +124,7 @@ impl Detector {
             baseline: boundary.limits.baseline,
             lower_limit: boundary.limits.lower.map(Into::into),
             upper_limit: boundary.limits.upper.map(Into::into),
+            p_value: boundary.p_value,
         };
 
         diesel::insert_into(schema::boundary::table)
@@ -96,11 +132,35 @@ impl Detector {
             .execute(conn_lock!(context))
             .map_err(resource_conflict_err!(Boundary, insert_boundary))?;
 
+        // Open alerts from earlier reports are resolved or reactivated once the whole report is checked,
+        // but a new alert is silenced if the threshold is still silenced for the benchmark.
+        let silence = QueryAlert::silence_from_report(
+            conn_lock!(context),
+            report_id,
+            self.threshold.id,
+            benchmark_id,
+        )?;
+
         // If the boundary check detects an outlier then create an alert for it on the given side.
+        // Members of a composite threshold are alerted on together by the composite threshold instead.
         if let Some(boundary_limit) = boundary.outlier {
-            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
-        } else {
-            Ok(())
+            if !self.composite {
+                InsertAlert::from_boundary(
+                    conn_lock!(context),
+                    report_id,
+                    boundary_uuid,
+                    boundary_limit,
+                    silence,
+                )?;
+            }
         }
+
+        Ok(Detection {
+            threshold_id: self.threshold.id,
+            measure_id: self.measure_id,
+            boundary_uuid,
+            outlier: boundary.outlier,
+            silence,
+        })
     }
 }
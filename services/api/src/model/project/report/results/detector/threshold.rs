@@ -1,11 +1,18 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, Window};
+use std::cmp::Reverse;
+
+use bencher_json::{
+    BenchmarkMatcher, BenchmarkName, BenchmarkSelector, Boundary, ModelTest, SampleSize, Smoothing,
+    Window,
+};
 use diesel::{
     ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
     SelectableHelper,
 };
+use dropshot::HttpError;
 
 use crate::{
     context::DbConnection,
+    error::resource_not_found_err,
     model::project::{
         branch::BranchId,
         measure::MeasureId,
@@ -21,6 +28,8 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Threshold {
     pub id: ThresholdId,
+    // Only benchmarks matching the selector are checked, if one is set
+    pub benchmark: Option<BenchmarkMatcher>,
     pub model: ThresholdModel,
 }
 
@@ -33,16 +42,20 @@ pub struct ThresholdModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub level_smoothing: Option<Smoothing>,
+    pub trend_smoothing: Option<Smoothing>,
 }
 
 impl Threshold {
-    pub fn new(
+    /// All of the thresholds for the branch/testbed/measure grouping,
+    /// ordered from the most to the least specific benchmark selector.
+    pub fn new_list(
         conn: &mut DbConnection,
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
-    ) -> Option<Self> {
-        schema::model::table
+    ) -> Result<Vec<Self>, HttpError> {
+        let thresholds = schema::model::table
             .inner_join(
                 schema::threshold::table
                     .on(schema::model::id.nullable().eq(schema::threshold::model_id)),
@@ -50,33 +63,74 @@ impl Threshold {
             .filter(schema::threshold::branch_id.eq(branch_id))
             .filter(schema::threshold::testbed_id.eq(testbed_id))
             .filter(schema::threshold::measure_id.eq(measure_id))
-            .select((schema::threshold::id, QueryModel::as_select()))
-            .first::<(ThresholdId, QueryModel)>(conn)
-            .map(|(threshold_id, query_model)| {
-                let QueryModel {
-                    id,
-                    test,
-                    min_sample_size,
-                    max_sample_size,
-                    window,
-                    lower_boundary,
-                    upper_boundary,
-                    ..
-                } = query_model;
-                let model = ThresholdModel {
-                    id,
-                    test,
-                    min_sample_size,
-                    max_sample_size,
-                    window,
-                    lower_boundary,
-                    upper_boundary,
+            .order(schema::threshold::id)
+            .select((
+                schema::threshold::id,
+                schema::threshold::benchmark,
+                QueryModel::as_select(),
+            ))
+            .load::<(ThresholdId, Option<BenchmarkSelector>, QueryModel)>(conn)
+            .map_err(resource_not_found_err!(
+                Threshold,
+                (branch_id, testbed_id, measure_id)
+            ))?;
+
+        let mut thresholds = thresholds
+            .into_iter()
+            .filter_map(|(threshold_id, benchmark, query_model)| {
+                // A threshold with an invalid benchmark selector is skipped
+                let benchmark = match benchmark {
+                    Some(benchmark) => Some(benchmark.matcher().ok()?),
+                    None => None,
                 };
-                Self {
-                    id: threshold_id,
-                    model,
-                }
+                Some(Self::new(threshold_id, benchmark, &query_model))
             })
-            .ok()
+            .collect::<Vec<_>>();
+        // The sort is stable, so the oldest threshold wins a tie
+        thresholds.sort_by_key(|threshold| {
+            Reverse(threshold.benchmark.as_ref().map(BenchmarkMatcher::kind))
+        });
+        Ok(thresholds)
+    }
+
+    fn new(
+        threshold_id: ThresholdId,
+        benchmark: Option<BenchmarkMatcher>,
+        query_model: &QueryModel,
+    ) -> Self {
+        let &QueryModel {
+            id,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            level_smoothing,
+            trend_smoothing,
+            ..
+        } = query_model;
+        let model = ThresholdModel {
+            id,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            level_smoothing,
+            trend_smoothing,
+        };
+        Self {
+            id: threshold_id,
+            benchmark,
+            model,
+        }
+    }
+
+    pub fn is_match(&self, benchmark_name: &BenchmarkName) -> bool {
+        self.benchmark.as_ref().map_or(true, |benchmark| {
+            benchmark.is_match(benchmark_name.as_ref())
+        })
     }
 }
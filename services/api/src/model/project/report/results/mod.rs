@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 
 use bencher_adapter::{
     results::adapter_metrics::AdapterMetrics, AdapterResults, AdapterResultsArray,
@@ -16,14 +16,16 @@ use slog::Logger;
 use crate::{
     conn_lock,
     context::ApiContext,
-    error::{bad_request_error, issue_error, resource_conflict_err},
+    error::{issue_error, parse_error, resource_conflict_err},
     model::project::{
         benchmark::{BenchmarkId, QueryBenchmark},
         branch::BranchId,
         measure::{MeasureId, QueryMeasure},
         metric::{InsertMetric, QueryMetric},
+        metric_sample::InsertMetricSample,
         report::report_benchmark::{InsertReportBenchmark, QueryReportBenchmark},
         testbed::TestbedId,
+        threshold::{alert::QueryAlert, ThresholdId},
         ProjectId,
     },
     schema,
@@ -31,7 +33,7 @@ use crate::{
 
 pub mod detector;
 
-use detector::Detector;
+use detector::{composite::CompositeDetector, Detection, Detector};
 
 use super::ReportId;
 
@@ -43,7 +45,24 @@ pub struct ReportResults {
     pub report_id: ReportId,
     pub benchmark_cache: HashMap<BenchmarkName, BenchmarkId>,
     pub measure_cache: HashMap<MeasureNameId, MeasureId>,
-    pub detector_cache: HashMap<MeasureId, Option<Detector>>,
+    pub detector_cache: HashMap<MeasureId, Vec<Detector>>,
+    pub composite_detector_cache: Option<Vec<CompositeDetector>>,
+    pub candidate_cache: Candidates,
+    pub deferred_detections: Vec<DeferredDetections>,
+    // Whether any iteration of the benchmark is an outlier for the threshold
+    pub outlier_cache: HashMap<(ThresholdId, BenchmarkId), bool>,
+}
+
+pub type Candidates = HashMap<BenchmarkName, HashMap<MeasureNameId, Vec<f64>>>;
+
+/// Two-sample detections compare the candidate sample from every iteration of the report,
+/// so they are deferred until all of the iterations have been inserted.
+/// The other detections from the last iteration of the benchmark are kept for its composite thresholds.
+pub struct DeferredDetections {
+    pub benchmark_id: BenchmarkId,
+    pub benchmark_name: BenchmarkName,
+    pub metrics: Vec<(Detector, QueryMetric, Vec<f64>)>,
+    pub detections: Vec<Detection>,
 }
 
 impl ReportResults {
@@ -61,6 +80,10 @@ impl ReportResults {
             benchmark_cache: HashMap::new(),
             measure_cache: HashMap::new(),
             detector_cache: HashMap::new(),
+            composite_detector_cache: None,
+            candidate_cache: HashMap::new(),
+            deferred_detections: Vec::new(),
+            outlier_cache: HashMap::new(),
         }
     }
 
@@ -73,13 +96,13 @@ impl ReportResults {
         settings: JsonReportSettings,
         #[cfg(feature = "plus")] usage: &mut u32,
     ) -> Result<(), HttpError> {
-        let adapter_settings = AdapterSettings::new(settings.average);
-        let results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)
-            .map_err(|e| {
-                bad_request_error(format!(
-                    "Failed to convert results with adapter ({adapter} | {settings:?}): {e}"
-                ))
-            })?;
+        let adapter_settings = AdapterSettings::new(settings.average)
+            .with_csv(settings.csv.clone())
+            .with_go_strip_cpu(settings.go_strip_cpu);
+        let results_array = AdapterResultsArray::new(results_array, adapter, &adapter_settings)
+            .map_err(|e| parse_error(&e))?;
+        // The candidate sample is taken before folding so it has every iteration
+        self.candidate_cache = candidates(&results_array);
 
         if let Some(fold) = settings.fold {
             let results = results_array.fold(fold);
@@ -106,7 +129,8 @@ impl ReportResults {
             }
         };
 
-        Ok(())
+        self.deferred_detections(log, context).await?;
+        self.update_alerts(context).await
     }
 
     async fn results(
@@ -143,7 +167,7 @@ impl ReportResults {
     ) -> Result<(), HttpError> {
         // If benchmark name is ignored then strip the special suffix before querying
         let (benchmark_name, ignore_benchmark) = benchmark_name.to_strip_ignore();
-        let benchmark_id = self.benchmark_id(context, benchmark_name).await?;
+        let benchmark_id = self.benchmark_id(context, benchmark_name.clone()).await?;
 
         let insert_report_benchmark =
             InsertReportBenchmark::from_json(self.report_id, iteration, benchmark_id);
@@ -157,10 +181,13 @@ impl ReportResults {
         let report_benchmark_id =
             QueryReportBenchmark::get_id(conn_lock!(context), insert_report_benchmark.uuid)?;
 
+        let mut detections = Vec::new();
+        let mut deferred = Vec::new();
         for (measure_key, metric) in metrics.inner {
-            let measure_id = self.measure_id(context, measure_key).await?;
+            let measure_id = self.measure_id(context, measure_key.clone()).await?;
 
-            let insert_metric = InsertMetric::from_json(report_benchmark_id, measure_id, metric);
+            let insert_metric = InsertMetric::from_json(report_benchmark_id, measure_id, &metric);
+            let samples = metric.samples.filter(|samples| !samples.is_empty());
             diesel::insert_into(schema::metric::table)
                 .values(&insert_metric)
                 .execute(conn_lock!(context))
@@ -173,24 +200,142 @@ impl ReportResults {
             }
 
             // Ignored benchmarks do not get checked against the threshold even if one exists
-            if !ignore_benchmark {
-                let Some(detector) = self.detector(context, measure_id).await else {
-                    continue;
-                };
-                let query_metric = QueryMetric::from_uuid(conn_lock!(context), insert_metric.uuid).map_err(|e| {
-                    issue_error(
-                        StatusCode::NOT_FOUND,
-                        "Failed to find metric",
-                        &format!("Failed to find new metric ({insert_metric:?}) for report benchmark ({insert_report_benchmark:?}) even though it was just created."),
-                        e,
+            let detector = if ignore_benchmark {
+                None
+            } else {
+                self.detector(context, measure_id, &benchmark_name).await?
+            };
+            if samples.is_none() && detector.is_none() {
+                continue;
+            }
+
+            let query_metric = QueryMetric::from_uuid(conn_lock!(context), insert_metric.uuid).map_err(|e| {
+                issue_error(
+                    StatusCode::NOT_FOUND,
+                    "Failed to find metric",
+                    &format!("Failed to find new metric ({insert_metric:?}) for report benchmark ({insert_report_benchmark:?}) even though it was just created."),
+                    e,
+                )
+            })?;
+            if let Some(samples) = samples {
+                InsertMetricSample::insert(conn_lock!(context), query_metric.id, samples)?;
+            }
+            if let Some(detector) = detector {
+                if detector.threshold.model.test.is_two_sample() {
+                    let candidate = self
+                        .candidate_cache
+                        .get(&benchmark_name)
+                        .and_then(|measures| measures.get(&measure_key))
+                        .cloned()
+                        .unwrap_or_default();
+                    deferred.push((detector, query_metric, candidate));
+                } else {
+                    let detection = detector
+                        .detect(
+                            log,
+                            context,
+                            self.report_id,
+                            benchmark_id,
+                            &query_metric,
+                            &[],
+                        )
+                        .await?;
+                    self.cache_outlier(benchmark_id, &detection);
+                    detections.push(detection);
+                }
+            }
+        }
+
+        if deferred.is_empty() {
+            self.composite_detect(context, &benchmark_name, &detections)
+                .await
+        } else {
+            // Only the last iteration of the benchmark is detected
+            self.deferred_detections
+                .retain(|deferred| deferred.benchmark_id != benchmark_id);
+            self.deferred_detections.push(DeferredDetections {
+                benchmark_id,
+                benchmark_name,
+                metrics: deferred,
+                detections,
+            });
+            Ok(())
+        }
+    }
+
+    async fn deferred_detections(
+        &mut self,
+        log: &Logger,
+        context: &ApiContext,
+    ) -> Result<(), HttpError> {
+        let report_id = self.report_id;
+        for DeferredDetections {
+            benchmark_id,
+            benchmark_name,
+            metrics,
+            mut detections,
+        } in std::mem::take(&mut self.deferred_detections)
+        {
+            for (detector, query_metric, candidate) in metrics {
+                let detection = detector
+                    .detect(
+                        log,
+                        context,
+                        report_id,
+                        benchmark_id,
+                        &query_metric,
+                        &candidate,
                     )
-                })?;
-                detector
-                    .detect(log, context, benchmark_id, &query_metric)
                     .await?;
+                self.cache_outlier(benchmark_id, &detection);
+                detections.push(detection);
             }
+            self.composite_detect(context, &benchmark_name, &detections)
+                .await?;
         }
+        Ok(())
+    }
+
+    fn cache_outlier(&mut self, benchmark_id: BenchmarkId, detection: &Detection) {
+        *self
+            .outlier_cache
+            .entry((detection.threshold_id, benchmark_id))
+            .or_default() |= detection.outlier.is_some();
+    }
 
+    // Open alerts from earlier reports are only updated once for the whole report
+    async fn update_alerts(&mut self, context: &ApiContext) -> Result<(), HttpError> {
+        for ((threshold_id, benchmark_id), outlier) in std::mem::take(&mut self.outlier_cache) {
+            QueryAlert::update_from_report(
+                conn_lock!(context),
+                self.report_id,
+                threshold_id,
+                benchmark_id,
+                outlier,
+            )?;
+        }
+        Ok(())
+    }
+
+    // Composite thresholds combine the detections across all of the measures for the benchmark
+    async fn composite_detect(
+        &mut self,
+        context: &ApiContext,
+        benchmark_name: &BenchmarkName,
+        detections: &[Detection],
+    ) -> Result<(), HttpError> {
+        if detections.is_empty() {
+            return Ok(());
+        }
+        let report_id = self.report_id;
+        for composite_detector in self.composite_detectors(context).await? {
+            composite_detector.detect(
+                conn_lock!(context),
+                report_id,
+                benchmark_name,
+                detections,
+            )?;
+        }
         Ok(())
     }
 
@@ -229,18 +374,115 @@ impl ReportResults {
         })
     }
 
-    async fn detector(&mut self, context: &ApiContext, measure_id: MeasureId) -> Option<Detector> {
-        if let Some(detector) = self.detector_cache.get(&measure_id) {
-            detector.clone()
-        } else {
-            let detector = Detector::new(
+    async fn detector(
+        &mut self,
+        context: &ApiContext,
+        measure_id: MeasureId,
+        benchmark_name: &BenchmarkName,
+    ) -> Result<Option<Detector>, HttpError> {
+        let detectors = match self.detector_cache.entry(measure_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Detector::new_list(
                 conn_lock!(context),
                 self.branch_id,
                 self.testbed_id,
                 measure_id,
-            );
-            self.detector_cache.insert(measure_id, detector.clone());
-            detector
+            )?),
+        };
+        // The detectors are ordered from the most to the least specific benchmark selector
+        let Some(mut detector) = detectors
+            .iter()
+            .find(|detector| detector.is_match(benchmark_name))
+            .cloned()
+        else {
+            return Ok(None);
+        };
+        // The selected threshold stands in for any composite member threshold of the same measure
+        // that matches the benchmark, so it is alerted on by the composite threshold instead.
+        detector.composite = self
+            .composite_detectors(context)
+            .await?
+            .iter()
+            .any(|composite_detector| composite_detector.is_member(measure_id, benchmark_name));
+        Ok(Some(detector))
+    }
+
+    async fn composite_detectors(
+        &mut self,
+        context: &ApiContext,
+    ) -> Result<&[CompositeDetector], HttpError> {
+        if self.composite_detector_cache.is_none() {
+            let composite_detectors =
+                CompositeDetector::new_list(conn_lock!(context), self.branch_id, self.testbed_id)?;
+            self.composite_detector_cache = Some(composite_detectors);
+        }
+        Ok(self.composite_detector_cache.as_deref().unwrap_or_default())
+    }
+}
+
+// The candidate sample for each benchmark measure across every iteration of the report,
+// using the raw samples instead if any were reported.
+fn candidates(results_array: &AdapterResultsArray) -> Candidates {
+    let mut values = Candidates::new();
+    let mut samples = Candidates::new();
+    for results in &results_array.inner {
+        for (benchmark_name, metrics) in &results.inner {
+            for (measure, metric) in &metrics.inner {
+                values
+                    .entry(benchmark_name.clone())
+                    .or_default()
+                    .entry(measure.clone())
+                    .or_default()
+                    .push(metric.value.into());
+                if let Some(metric_samples) = metric.samples.as_ref().filter(|s| !s.is_empty()) {
+                    samples
+                        .entry(benchmark_name.clone())
+                        .or_default()
+                        .entry(measure.clone())
+                        .or_default()
+                        .extend(metric_samples.iter().copied().map(f64::from));
+                }
+            }
         }
     }
+    for (benchmark_name, measures) in samples {
+        values.entry(benchmark_name).or_default().extend(measures);
+    }
+    values
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_adapter::{AdapterResultsArray, Settings};
+    use bencher_json::project::report::Adapter;
+
+    use super::candidates;
+
+    #[test]
+    fn test_candidates() {
+        let results_array = AdapterResultsArray::new(
+            &[
+                r#"{"a": {"latency": {"value": 1.0}}, "b": {"latency": {"value": 10.0, "samples": [9.0, 11.0]}}}"#,
+                r#"{"a": {"latency": {"value": 2.0}}, "b": {"latency": {"value": 20.0, "samples": [19.0, 21.0]}}}"#,
+                r#"{"a": {"latency": {"value": 3.0}}}"#,
+            ],
+            Adapter::Json,
+            &Settings::default(),
+        )
+        .unwrap();
+        let latency = "latency".parse().unwrap();
+
+        let candidates = candidates(&results_array);
+        assert_eq!(candidates.len(), 2);
+        let candidate = |benchmark: &str| {
+            candidates
+                .get(&benchmark.parse().unwrap())
+                .and_then(|measures| measures.get(&latency))
+                .map(Vec::as_slice)
+        };
+        // Every iteration is part of the candidate sample, even if the results are later folded
+        assert_eq!(candidate("a"), Some([1.0, 2.0, 3.0].as_slice()));
+        // The raw samples are used instead of the values if any were reported
+        assert_eq!(candidate("b"), Some([9.0, 11.0, 19.0, 21.0].as_slice()));
+    }
 }
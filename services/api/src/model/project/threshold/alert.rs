@@ -2,22 +2,33 @@ use bencher_json::{
     project::{
         alert::{AlertStatus, JsonAlert, JsonPerfAlert, JsonUpdateAlert},
         boundary::BoundaryLimit,
+        branch::VersionNumber,
         report::Iteration,
     },
-    AlertUuid, BoundaryUuid, DateTime, ReportUuid,
+    AlertUuid, BoundaryUuid, DateTime, ReportUuid, ResourceId,
 };
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::HttpError;
 
 use super::{
+    alert_event::InsertAlertEvent,
     boundary::{BoundaryId, QueryBoundary},
-    QueryThreshold,
+    composite::{CompositeThresholdId, QueryCompositeThreshold},
+    QueryThreshold, ThresholdId,
 };
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection},
-    error::{resource_conflict_err, resource_not_found_err},
-    model::project::{benchmark::QueryBenchmark, metric::QueryMetric, ProjectId, QueryProject},
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            benchmark::{BenchmarkId, QueryBenchmark},
+            metric::QueryMetric,
+            report::ReportId,
+            ProjectId, QueryProject,
+        },
+        user::{auth::AuthUser, QueryUser, UserId},
+    },
     schema::{self, alert as alert_table},
     util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
 };
@@ -31,7 +42,11 @@ pub struct QueryAlert {
     pub uuid: AlertUuid,
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
+    pub composite_threshold_id: Option<CompositeThresholdId>,
     pub status: AlertStatus,
+    pub assignee_id: Option<UserId>,
+    pub silenced_until: Option<DateTime>,
+    pub silenced_version: Option<VersionNumber>,
     pub modified: DateTime,
 }
 
@@ -117,10 +132,27 @@ impl QueryAlert {
         let Self {
             uuid,
             boundary_limit,
+            composite_threshold_id,
             status,
+            assignee_id,
+            silenced_until,
+            silenced_version,
             modified,
             ..
         } = self;
+        let composite_threshold = if let Some(composite_threshold_id) = composite_threshold_id {
+            Some(QueryCompositeThreshold::get_uuid(
+                conn_lock!(context),
+                composite_threshold_id,
+            )?)
+        } else {
+            None
+        };
+        let assignee = if let Some(assignee_id) = assignee_id {
+            Some(QueryUser::get(conn_lock!(context), assignee_id)?.into_json())
+        } else {
+            None
+        };
         let threshold_id = query_boundary.threshold_id;
         let model_id = query_boundary.model_id;
         let benchmark =
@@ -132,7 +164,11 @@ impl QueryAlert {
             threshold: QueryThreshold::get_json(conn_lock!(context), threshold_id, model_id)?,
             benchmark,
             limit: boundary_limit,
+            composite_threshold,
             status,
+            assignee,
+            silenced_until,
+            silenced_version,
             created,
             modified,
         })
@@ -153,6 +189,130 @@ impl QueryAlert {
             modified,
         }
     }
+
+    pub fn silence(&self) -> AlertSilence {
+        AlertSilence {
+            until: self.silenced_until,
+            version: self.silenced_version,
+        }
+    }
+
+    /// The silence of any still silenced alerts from earlier reports for the same threshold and benchmark,
+    /// so that it can be carried over to a new alert.
+    pub fn silence_from_report(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        threshold_id: ThresholdId,
+        benchmark_id: BenchmarkId,
+    ) -> Result<Option<AlertSilence>, HttpError> {
+        let silenced_alerts = Self::open_alerts(conn, report_id, threshold_id, benchmark_id)?
+            .into_iter()
+            .filter(|query_alert| query_alert.status == AlertStatus::Silenced)
+            .collect::<Vec<_>>();
+        // The version number is only needed to check for a silence that expires at a version
+        let version_number = silenced_alerts
+            .iter()
+            .any(|query_alert| query_alert.silenced_version.is_some())
+            .then(|| Self::report_version_number(conn, report_id))
+            .transpose()?;
+        Ok(silenced_alerts
+            .iter()
+            .map(Self::silence)
+            .find(|alert_silence| !alert_silence.is_expired(version_number)))
+    }
+
+    /// Update the open alerts from earlier reports for the same threshold and benchmark
+    /// once a later report has been checked against the threshold.
+    /// If none of the iterations of the later report are outliers, then the open alerts are resolved.
+    /// Otherwise, any silenced alerts that have expired are reactivated.
+    pub fn update_from_report(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        threshold_id: ThresholdId,
+        benchmark_id: BenchmarkId,
+        outlier: bool,
+    ) -> Result<(), HttpError> {
+        // The version number is only needed to check for a silence that expires at a version
+        let mut version_number = None;
+        for query_alert in Self::open_alerts(conn, report_id, threshold_id, benchmark_id)? {
+            let status = if !outlier {
+                AlertStatus::Resolved
+            } else if query_alert.status == AlertStatus::Silenced {
+                let alert_silence = query_alert.silence();
+                if alert_silence.version.is_some() && version_number.is_none() {
+                    version_number = Some(Self::report_version_number(conn, report_id)?);
+                }
+                if alert_silence.is_expired(version_number) {
+                    AlertStatus::Active
+                } else {
+                    continue;
+                }
+            } else {
+                continue;
+            };
+            UpdateAlert::from_status(status).update(conn, &query_alert, None, Some(report_id))?;
+        }
+        Ok(())
+    }
+
+    fn open_alerts(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        threshold_id: ThresholdId,
+        benchmark_id: BenchmarkId,
+    ) -> Result<Vec<Self>, HttpError> {
+        schema::alert::table
+            .inner_join(
+                schema::boundary::table
+                    .inner_join(schema::metric::table.inner_join(schema::report_benchmark::table)),
+            )
+            .filter(schema::boundary::threshold_id.eq(threshold_id))
+            .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+            .filter(schema::report_benchmark::report_id.ne(report_id))
+            .filter(schema::alert::status.eq_any([
+                AlertStatus::Active,
+                AlertStatus::Acknowledged,
+                AlertStatus::Silenced,
+            ]))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                Alert,
+                (report_id, threshold_id, benchmark_id)
+            ))
+    }
+
+    fn report_version_number(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+    ) -> Result<VersionNumber, HttpError> {
+        schema::report::table
+            .filter(schema::report::id.eq(report_id))
+            .inner_join(schema::version::table)
+            .select(schema::version::number)
+            .first(conn)
+            .map_err(resource_not_found_err!(Version, report_id))
+    }
+}
+
+/// A silenced alert stays silenced until either the date time or the branch version number is reached
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlertSilence {
+    pub until: Option<DateTime>,
+    pub version: Option<VersionNumber>,
+}
+
+impl AlertSilence {
+    fn is_expired(self, version_number: Option<VersionNumber>) -> bool {
+        let date_expired = self.until.map_or(false, |until| {
+            DateTime::now().timestamp() >= until.timestamp()
+        });
+        let version_expired = self
+            .version
+            .zip(version_number)
+            .map_or(false, |(silenced, current)| current.0 >= silenced.0);
+        date_expired || version_expired
+    }
 }
 
 #[derive(Debug, diesel::Insertable)]
@@ -161,21 +321,74 @@ pub struct InsertAlert {
     pub uuid: AlertUuid,
     pub boundary_id: BoundaryId,
     pub boundary_limit: BoundaryLimit,
+    pub composite_threshold_id: Option<CompositeThresholdId>,
     pub status: AlertStatus,
+    pub assignee_id: Option<UserId>,
+    pub silenced_until: Option<DateTime>,
+    pub silenced_version: Option<VersionNumber>,
     pub modified: DateTime,
 }
 
 impl InsertAlert {
     pub fn from_boundary(
         conn: &mut DbConnection,
+        report_id: ReportId,
+        boundary_uuid: BoundaryUuid,
+        boundary_limit: BoundaryLimit,
+        silence: Option<AlertSilence>,
+    ) -> Result<(), HttpError> {
+        Self::insert(
+            conn,
+            report_id,
+            boundary_uuid,
+            boundary_limit,
+            None,
+            silence,
+        )
+    }
+
+    pub fn from_composite(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        boundary_uuid: BoundaryUuid,
+        boundary_limit: BoundaryLimit,
+        composite_threshold_id: CompositeThresholdId,
+        silence: Option<AlertSilence>,
+    ) -> Result<(), HttpError> {
+        Self::insert(
+            conn,
+            report_id,
+            boundary_uuid,
+            boundary_limit,
+            Some(composite_threshold_id),
+            silence,
+        )
+    }
+
+    fn insert(
+        conn: &mut DbConnection,
+        report_id: ReportId,
         boundary_uuid: BoundaryUuid,
         boundary_limit: BoundaryLimit,
+        composite_threshold_id: Option<CompositeThresholdId>,
+        silence: Option<AlertSilence>,
     ) -> Result<(), HttpError> {
+        // A new alert for a silenced benchmark is also silenced
+        let status = if silence.is_some() {
+            AlertStatus::Silenced
+        } else {
+            AlertStatus::default()
+        };
+        let silence = silence.unwrap_or_default();
         let insert_alert = InsertAlert {
             uuid: AlertUuid::new(),
             boundary_id: QueryBoundary::get_id(conn, boundary_uuid)?,
             boundary_limit,
-            status: AlertStatus::default(),
+            composite_threshold_id,
+            status,
+            assignee_id: None,
+            silenced_until: silence.until,
+            silenced_version: silence.version,
             modified: DateTime::now(),
         };
 
@@ -184,7 +397,9 @@ impl InsertAlert {
             .execute(conn)
             .map_err(resource_conflict_err!(Alert, insert_alert))?;
 
-        Ok(())
+        let alert_id = QueryAlert::get_id(conn, insert_alert.uuid)?;
+        let query_alert = QueryAlert::get(conn, alert_id)?;
+        InsertAlertEvent::insert(conn, &query_alert, None, Some(report_id))
     }
 }
 
@@ -192,15 +407,256 @@ impl InsertAlert {
 #[diesel(table_name = alert_table)]
 pub struct UpdateAlert {
     pub status: Option<AlertStatus>,
+    pub assignee_id: Option<Option<UserId>>,
+    pub silenced_until: Option<Option<DateTime>>,
+    pub silenced_version: Option<Option<VersionNumber>>,
     pub modified: DateTime,
 }
 
-impl From<JsonUpdateAlert> for UpdateAlert {
-    fn from(update: JsonUpdateAlert) -> Self {
-        let JsonUpdateAlert { status } = update;
+impl UpdateAlert {
+    /// Change the status of an alert, clearing any assignee or silence
+    pub fn from_status(status: AlertStatus) -> Self {
         Self {
-            status,
+            status: Some(status),
+            assignee_id: Some(None),
+            silenced_until: Some(None),
+            silenced_version: Some(None),
             modified: DateTime::now(),
         }
     }
+
+    pub fn from_json(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        query_alert: &QueryAlert,
+        auth_user: &AuthUser,
+        update: JsonUpdateAlert,
+    ) -> Result<Self, HttpError> {
+        let JsonUpdateAlert {
+            status,
+            assignee,
+            silenced_until,
+            silenced_version,
+        } = update;
+        let status = status.unwrap_or(query_alert.status);
+
+        if assignee.is_some() && status != AlertStatus::Acknowledged {
+            return Err(bad_request_error(format!(
+                "An assignee can only be set for an acknowledged alert. The alert status is: {status}"
+            )));
+        }
+        let new_silence = silenced_until.is_some() || silenced_version.is_some();
+        if new_silence && status != AlertStatus::Silenced {
+            return Err(bad_request_error(format!(
+                "A silence can only be set for a silenced alert. The alert status is: {status}"
+            )));
+        }
+
+        let mut update_alert = Self::from_status(status);
+        match status {
+            AlertStatus::Acknowledged => {
+                let assignee_id = if let Some(assignee) = assignee {
+                    Self::assignee_id(conn, query_project, &assignee)?
+                } else if let (AlertStatus::Acknowledged, Some(assignee_id)) =
+                    (query_alert.status, query_alert.assignee_id)
+                {
+                    assignee_id
+                } else {
+                    auth_user.id()
+                };
+                update_alert.assignee_id = Some(Some(assignee_id));
+            },
+            AlertStatus::Silenced => {
+                let silence = if new_silence {
+                    if let Some(until) = silenced_until {
+                        if until.timestamp() <= DateTime::now().timestamp() {
+                            return Err(bad_request_error(format!(
+                                "The alert must be silenced until a date time in the future, not {until}."
+                            )));
+                        }
+                    }
+                    AlertSilence {
+                        until: silenced_until,
+                        version: silenced_version,
+                    }
+                } else if query_alert.status == AlertStatus::Silenced {
+                    query_alert.silence()
+                } else {
+                    return Err(bad_request_error(
+                        "A silenced alert requires a `silenced_until` date time and/or a `silenced_version` number.",
+                    ));
+                };
+                update_alert.silenced_until = Some(silence.until);
+                update_alert.silenced_version = Some(silence.version);
+            },
+            AlertStatus::Active | AlertStatus::Dismissed | AlertStatus::Resolved => {},
+        }
+
+        Ok(update_alert)
+    }
+
+    // The assignee must be a member of the organization that owns the project
+    fn assignee_id(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        assignee: &ResourceId,
+    ) -> Result<UserId, HttpError> {
+        let query_user = QueryUser::from_resource_id(conn, assignee)?;
+        let is_member = diesel::select(diesel::dsl::exists(
+            schema::organization_role::table
+                .filter(schema::organization_role::user_id.eq(query_user.id))
+                .filter(
+                    schema::organization_role::organization_id.eq(query_project.organization_id),
+                ),
+        ))
+        .get_result::<bool>(conn)
+        .map_err(resource_not_found_err!(
+            OrganizationRole,
+            (&query_user, query_project)
+        ))?;
+        if is_member {
+            Ok(query_user.id)
+        } else {
+            Err(bad_request_error(format!(
+                "The assignee ({assignee}) is not a member of the organization for the project ({project}).",
+                project = query_project.slug
+            )))
+        }
+    }
+
+    /// Update the alert and record the change in its history
+    pub fn update(
+        self,
+        conn: &mut DbConnection,
+        query_alert: &QueryAlert,
+        user_id: Option<UserId>,
+        report_id: Option<ReportId>,
+    ) -> Result<QueryAlert, HttpError> {
+        diesel::update(schema::alert::table.filter(schema::alert::id.eq(query_alert.id)))
+            .set(&self)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Alert, (query_alert, &self)))?;
+
+        let query_alert = QueryAlert::get(conn, query_alert.id)?;
+        InsertAlertEvent::insert(conn, &query_alert, user_id, report_id)?;
+        Ok(query_alert)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::project::alert::AlertStatus;
+    use diesel::connection::SimpleConnection;
+
+    use crate::{
+        context::DbConnection,
+        model::project::{report::ReportId, threshold::ThresholdId},
+        util::test_db::test_db,
+    };
+
+    use super::{AlertId, QueryAlert};
+
+    const EARLIER_REPORT: i32 = 1;
+    const LATER_REPORT: i32 = 2;
+    const BENCHMARK: i32 = 1;
+    const THRESHOLD: i32 = 1;
+    const OTHER_THRESHOLD: i32 = 2;
+
+    // An earlier report with an active alert for each threshold and a silenced alert for the first threshold.
+    // The silence expires far in the future.
+    fn seed_alerts(conn: &mut DbConnection) {
+        conn.batch_execute(
+            "
+            INSERT INTO report_benchmark (id, uuid, report_id, iteration, benchmark_id) VALUES
+                (1, '00000000-0000-0000-0000-000000000001', 1, 0, 1),
+                (2, '00000000-0000-0000-0000-000000000002', 2, 0, 1);
+            INSERT INTO metric (id, uuid, report_benchmark_id, measure_id, value) VALUES
+                (1, '00000000-0000-0000-0000-000000000001', 1, 1, 1.0),
+                (2, '00000000-0000-0000-0000-000000000002', 1, 2, 1.0),
+                (3, '00000000-0000-0000-0000-000000000003', 1, 3, 1.0);
+            INSERT INTO boundary (id, uuid, threshold_id, model_id, metric_id) VALUES
+                (1, '00000000-0000-0000-0000-000000000001', 1, 1, 1),
+                (2, '00000000-0000-0000-0000-000000000002', 2, 2, 2),
+                (3, '00000000-0000-0000-0000-000000000003', 1, 1, 3);
+            INSERT INTO alert (id, uuid, boundary_id, boundary_limit, status, silenced_until, modified) VALUES
+                (1, '00000000-0000-0000-0000-000000000001', 1, 1, 0, NULL, 0),
+                (2, '00000000-0000-0000-0000-000000000002', 2, 1, 0, NULL, 0),
+                (3, '00000000-0000-0000-0000-000000000003', 3, 1, 3, 4102444800, 0);
+            ",
+        )
+        .unwrap();
+    }
+
+    fn alert_status(conn: &mut DbConnection, alert_id: i32) -> AlertStatus {
+        QueryAlert::get(conn, AlertId::from(alert_id))
+            .unwrap()
+            .status
+    }
+
+    #[test]
+    fn test_silence_from_report() {
+        let mut conn = test_db();
+        seed_alerts(&mut conn);
+
+        let silence = QueryAlert::silence_from_report(
+            &mut conn,
+            ReportId::from(LATER_REPORT),
+            ThresholdId::from(THRESHOLD),
+            BENCHMARK.into(),
+        )
+        .unwrap();
+        assert!(silence.and_then(|silence| silence.until).is_some());
+
+        // The silence is scoped to the threshold
+        let silence = QueryAlert::silence_from_report(
+            &mut conn,
+            ReportId::from(LATER_REPORT),
+            ThresholdId::from(OTHER_THRESHOLD),
+            BENCHMARK.into(),
+        )
+        .unwrap();
+        assert!(silence.is_none());
+
+        // Alerts from the report itself are not carried over
+        let silence = QueryAlert::silence_from_report(
+            &mut conn,
+            ReportId::from(EARLIER_REPORT),
+            ThresholdId::from(THRESHOLD),
+            BENCHMARK.into(),
+        )
+        .unwrap();
+        assert!(silence.is_none());
+    }
+
+    #[test]
+    fn test_update_from_report() {
+        let mut conn = test_db();
+        seed_alerts(&mut conn);
+
+        // An outlier keeps the open alerts, including the unexpired silence
+        QueryAlert::update_from_report(
+            &mut conn,
+            ReportId::from(LATER_REPORT),
+            ThresholdId::from(THRESHOLD),
+            BENCHMARK.into(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(alert_status(&mut conn, 1), AlertStatus::Active);
+        assert_eq!(alert_status(&mut conn, 2), AlertStatus::Active);
+        assert_eq!(alert_status(&mut conn, 3), AlertStatus::Silenced);
+
+        // Only the alerts for the threshold are resolved
+        QueryAlert::update_from_report(
+            &mut conn,
+            ReportId::from(LATER_REPORT),
+            ThresholdId::from(THRESHOLD),
+            BENCHMARK.into(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(alert_status(&mut conn, 1), AlertStatus::Resolved);
+        assert_eq!(alert_status(&mut conn, 2), AlertStatus::Active);
+        assert_eq!(alert_status(&mut conn, 3), AlertStatus::Resolved);
+    }
 }
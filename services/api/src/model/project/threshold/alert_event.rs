@@ -0,0 +1,141 @@
+use bencher_json::{
+    project::{
+        alert::{AlertStatus, JsonAlertEvent},
+        branch::VersionNumber,
+    },
+    AlertEventUuid, AlertUuid, DateTime,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use super::alert::{AlertId, QueryAlert};
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::report::{QueryReport, ReportId},
+        user::{QueryUser, UserId},
+    },
+    schema::{self, alert_event as alert_event_table},
+};
+
+crate::util::typed_id::typed_id!(AlertEventId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = alert_event_table)]
+pub struct QueryAlertEvent {
+    pub id: AlertEventId,
+    pub uuid: AlertEventUuid,
+    pub alert_id: AlertId,
+    pub user_id: Option<UserId>,
+    pub report_id: Option<ReportId>,
+    pub status: AlertStatus,
+    pub assignee_id: Option<UserId>,
+    pub silenced_until: Option<DateTime>,
+    pub silenced_version: Option<VersionNumber>,
+    pub created: DateTime,
+}
+
+impl QueryAlertEvent {
+    /// Get the alerts that were resolved while processing a report.
+    pub fn resolved_by_report(
+        conn: &mut DbConnection,
+        report_id: ReportId,
+    ) -> Result<Vec<AlertId>, HttpError> {
+        schema::alert_event::table
+            .filter(schema::alert_event::report_id.eq(report_id))
+            .filter(schema::alert_event::status.eq(AlertStatus::Resolved))
+            .select(schema::alert_event::alert_id)
+            .load::<AlertId>(conn)
+            .map_err(resource_not_found_err!(AlertEvent, report_id))
+    }
+
+    pub fn into_json(
+        self,
+        conn: &mut DbConnection,
+        alert_uuid: AlertUuid,
+    ) -> Result<JsonAlertEvent, HttpError> {
+        let Self {
+            uuid,
+            user_id,
+            report_id,
+            status,
+            assignee_id,
+            silenced_until,
+            silenced_version,
+            created,
+            ..
+        } = self;
+        let assignee = if let Some(assignee_id) = assignee_id {
+            Some(QueryUser::get(conn, assignee_id)?.into_json())
+        } else {
+            None
+        };
+        let user = if let Some(user_id) = user_id {
+            Some(QueryUser::get(conn, user_id)?.into_json())
+        } else {
+            None
+        };
+        let report = if let Some(report_id) = report_id {
+            Some(QueryReport::get_uuid(conn, report_id)?)
+        } else {
+            None
+        };
+        Ok(JsonAlertEvent {
+            uuid,
+            alert: alert_uuid,
+            status,
+            assignee,
+            silenced_until,
+            silenced_version,
+            user,
+            report,
+            created,
+        })
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = alert_event_table)]
+pub struct InsertAlertEvent {
+    pub uuid: AlertEventUuid,
+    pub alert_id: AlertId,
+    pub user_id: Option<UserId>,
+    pub report_id: Option<ReportId>,
+    pub status: AlertStatus,
+    pub assignee_id: Option<UserId>,
+    pub silenced_until: Option<DateTime>,
+    pub silenced_version: Option<VersionNumber>,
+    pub created: DateTime,
+}
+
+impl InsertAlertEvent {
+    /// Record the current state of an alert.
+    /// Changes made by a user have a `user_id`,
+    /// and changes made automatically while processing a report have a `report_id`.
+    pub fn insert(
+        conn: &mut DbConnection,
+        query_alert: &QueryAlert,
+        user_id: Option<UserId>,
+        report_id: Option<ReportId>,
+    ) -> Result<(), HttpError> {
+        let insert_alert_event = Self {
+            uuid: AlertEventUuid::new(),
+            alert_id: query_alert.id,
+            user_id,
+            report_id,
+            status: query_alert.status,
+            assignee_id: query_alert.assignee_id,
+            silenced_until: query_alert.silenced_until,
+            silenced_version: query_alert.silenced_version,
+            created: query_alert.modified,
+        };
+
+        diesel::insert_into(schema::alert_event::table)
+            .values(&insert_alert_event)
+            .execute(conn)
+            .map_err(resource_conflict_err!(AlertEvent, insert_alert_event))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashSet;
+
+use bencher_json::{
+    project::{
+        boundary::BoundaryLimit,
+        composite_threshold::{CompositeOperator, JsonCompositeMember, JsonCompositeThreshold},
+    },
+    CompositeThresholdUuid, DateTime, JsonNewCompositeThreshold,
+};
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::HttpError;
+
+use super::{QueryThreshold, ThresholdId};
+use crate::{
+    context::DbConnection,
+    error::{
+        assert_parentage, bad_request_error, resource_conflict_err, resource_not_found_err,
+        BencherResource,
+    },
+    model::project::{
+        branch::{BranchId, QueryBranch},
+        measure::QueryMeasure,
+        testbed::{QueryTestbed, TestbedId},
+        ProjectId, QueryProject,
+    },
+    schema::{
+        self, composite_member as composite_member_table,
+        composite_threshold as composite_threshold_table,
+    },
+    util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
+};
+
+crate::util::typed_id::typed_id!(CompositeThresholdId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = composite_threshold_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryCompositeThreshold {
+    pub id: CompositeThresholdId,
+    pub uuid: CompositeThresholdUuid,
+    pub project_id: ProjectId,
+    pub branch_id: BranchId,
+    pub testbed_id: TestbedId,
+    pub operator: CompositeOperator,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryCompositeThreshold {
+    fn_get!(composite_threshold, CompositeThresholdId);
+    fn_get_id!(
+        composite_threshold,
+        CompositeThresholdId,
+        CompositeThresholdUuid
+    );
+    fn_get_uuid!(
+        composite_threshold,
+        CompositeThresholdId,
+        CompositeThresholdUuid
+    );
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: CompositeThresholdUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(&query_project)
+            .filter(composite_threshold_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                CompositeThreshold,
+                (query_project, uuid)
+            ))
+    }
+
+    pub fn members(&self, conn: &mut DbConnection) -> Result<Vec<QueryCompositeMember>, HttpError> {
+        schema::composite_member::table
+            .filter(schema::composite_member::composite_threshold_id.eq(self.id))
+            .order(schema::composite_member::threshold_id)
+            .select(QueryCompositeMember::as_select())
+            .load::<QueryCompositeMember>(conn)
+            .map_err(resource_not_found_err!(CompositeMember, self))
+    }
+
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonCompositeThreshold, HttpError> {
+        let members = self
+            .members(conn)?
+            .into_iter()
+            .map(|member| member.into_json_for_project(conn, project))
+            .collect::<Result<Vec<_>, HttpError>>()?;
+        let Self {
+            uuid,
+            project_id,
+            branch_id,
+            testbed_id,
+            operator,
+            created,
+            modified,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::CompositeThreshold,
+            project_id,
+        );
+        Ok(JsonCompositeThreshold {
+            uuid,
+            project: project.uuid,
+            branch: QueryBranch::get(conn, branch_id)?.into_json_for_project(conn, project)?,
+            testbed: QueryTestbed::get(conn, testbed_id)?.into_json_for_project(project),
+            operator,
+            members,
+            created,
+            modified,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = composite_member_table)]
+pub struct QueryCompositeMember {
+    pub composite_threshold_id: CompositeThresholdId,
+    pub threshold_id: ThresholdId,
+    pub boundary_limit: Option<BoundaryLimit>,
+}
+
+impl QueryCompositeMember {
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonCompositeMember, HttpError> {
+        let query_threshold = QueryThreshold::get(conn, self.threshold_id)?;
+        Ok(JsonCompositeMember {
+            threshold: query_threshold.uuid,
+            measure: QueryMeasure::get(conn, query_threshold.measure_id)?
+                .into_json_for_project(project),
+            limit: self.boundary_limit,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = composite_threshold_table)]
+pub struct InsertCompositeThreshold {
+    pub uuid: CompositeThresholdUuid,
+    pub project_id: ProjectId,
+    pub branch_id: BranchId,
+    pub testbed_id: TestbedId,
+    pub operator: CompositeOperator,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertCompositeThreshold {
+    pub fn insert_from_json(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        json_composite_threshold: JsonNewCompositeThreshold,
+    ) -> Result<CompositeThresholdId, HttpError> {
+        let JsonNewCompositeThreshold { operator, members } = json_composite_threshold;
+        if members.len() < 2 {
+            return Err(bad_request_error(format!(
+                "A composite threshold must have at least two member thresholds, but {} were provided",
+                members.len()
+            )));
+        }
+
+        let mut thresholds = HashSet::with_capacity(members.len());
+        let mut insert_members = Vec::with_capacity(members.len());
+        let mut branch_testbed = None;
+        for member in members {
+            if !thresholds.insert(member.threshold) {
+                return Err(bad_request_error(format!(
+                    "Threshold ({}) is included more than once in the composite threshold",
+                    member.threshold
+                )));
+            }
+            let query_threshold =
+                QueryThreshold::get_with_uuid(conn, query_project, member.threshold)?;
+            let member_branch_testbed = (query_threshold.branch_id, query_threshold.testbed_id);
+            match branch_testbed {
+                Some(branch_testbed) if branch_testbed != member_branch_testbed => {
+                    return Err(bad_request_error(format!(
+                        "Threshold ({}) does not have the same branch and testbed as the other member thresholds",
+                        member.threshold
+                    )));
+                },
+                Some(_) => {},
+                None => branch_testbed = Some(member_branch_testbed),
+            }
+            insert_members.push((query_threshold.id, member.limit));
+        }
+        let Some((branch_id, testbed_id)) = branch_testbed else {
+            return Err(bad_request_error(
+                "A composite threshold must have at least two member thresholds",
+            ));
+        };
+
+        // Create the new composite threshold
+        let timestamp = DateTime::now();
+        let insert_composite_threshold = InsertCompositeThreshold {
+            uuid: CompositeThresholdUuid::new(),
+            project_id: query_project.id,
+            branch_id,
+            testbed_id,
+            operator,
+            created: timestamp,
+            modified: timestamp,
+        };
+        diesel::insert_into(schema::composite_threshold::table)
+            .values(&insert_composite_threshold)
+            .execute(conn)
+            .map_err(resource_conflict_err!(
+                CompositeThreshold,
+                insert_composite_threshold
+            ))?;
+
+        // Get the new composite threshold ID
+        let composite_threshold_id =
+            QueryCompositeThreshold::get_id(conn, insert_composite_threshold.uuid)?;
+
+        // Add all of the member thresholds
+        for (threshold_id, boundary_limit) in insert_members {
+            let insert_composite_member = InsertCompositeMember {
+                composite_threshold_id,
+                threshold_id,
+                boundary_limit,
+            };
+            diesel::insert_into(schema::composite_member::table)
+                .values(&insert_composite_member)
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    CompositeMember,
+                    insert_composite_member
+                ))?;
+        }
+
+        Ok(composite_threshold_id)
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = composite_member_table)]
+pub struct InsertCompositeMember {
+    pub composite_threshold_id: CompositeThresholdId,
+    pub threshold_id: ThresholdId,
+    pub boundary_limit: Option<BoundaryLimit>,
+}
@@ -1,6 +1,6 @@
 use bencher_json::{
     project::threshold::{JsonThreshold, JsonThresholdModel},
-    DateTime, Model, ModelUuid, ThresholdUuid,
+    BenchmarkSelector, DateTime, Model, ModelUuid, ThresholdUuid,
 };
 use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
@@ -25,7 +25,9 @@ use crate::{
 };
 
 pub mod alert;
+pub mod alert_event;
 pub mod boundary;
+pub mod composite;
 pub mod model;
 
 crate::util::typed_id::typed_id!(ThresholdId);
@@ -42,6 +44,7 @@ pub struct QueryThreshold {
     pub branch_id: BranchId,
     pub testbed_id: TestbedId,
     pub measure_id: MeasureId,
+    pub benchmark: Option<BenchmarkSelector>,
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
@@ -147,6 +150,7 @@ impl QueryThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            benchmark,
             created,
             modified,
             ..
@@ -159,6 +163,7 @@ impl QueryThreshold {
                 .into_json_for_project(conn, &query_project)?,
             testbed: QueryTestbed::get(conn, testbed_id)?.into_json_for_project(&query_project),
             measure: QueryMeasure::get(conn, measure_id)?.into_json_for_project(&query_project),
+            benchmark,
             // TODO remove in due time
             statistic: Some(model),
             model,
@@ -214,6 +219,7 @@ pub struct InsertThreshold {
     pub branch_id: BranchId,
     pub testbed_id: TestbedId,
     pub measure_id: MeasureId,
+    pub benchmark: Option<BenchmarkSelector>,
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
@@ -225,6 +231,7 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark: Option<BenchmarkSelector>,
     ) -> Self {
         let timestamp = DateTime::now();
         Self {
@@ -233,6 +240,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            benchmark,
             model_id: None,
             created: timestamp,
             modified: timestamp,
@@ -245,10 +253,12 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark: Option<BenchmarkSelector>,
         model: Model,
     ) -> Result<ThresholdId, HttpError> {
         // Create the new threshold
-        let insert_threshold = InsertThreshold::new(project_id, branch_id, testbed_id, measure_id);
+        let insert_threshold =
+            InsertThreshold::new(project_id, branch_id, testbed_id, measure_id, benchmark);
         diesel::insert_into(schema::threshold::table)
             .values(&insert_threshold)
             .execute(conn)
@@ -292,6 +302,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::lower_boundary(),
         )
     }
@@ -309,6 +320,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::upper_boundary(),
         )
     }
@@ -1,5 +1,5 @@
 use bencher_json::{
-    Boundary, DateTime, JsonModel, Model, ModelTest, ModelUuid, SampleSize, Window,
+    Boundary, DateTime, JsonModel, Model, ModelTest, ModelUuid, SampleSize, Smoothing, Window,
 };
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::HttpError;
@@ -28,6 +28,8 @@ pub struct QueryModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub level_smoothing: Option<Smoothing>,
+    pub trend_smoothing: Option<Smoothing>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -68,6 +70,8 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
             created,
             replaced,
             ..
@@ -87,6 +91,8 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
             created,
             replaced,
         }
@@ -104,6 +110,8 @@ pub struct InsertModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub level_smoothing: Option<Smoothing>,
+    pub trend_smoothing: Option<Smoothing>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -118,6 +126,8 @@ impl From<QueryModel> for InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
             created,
             replaced,
             ..
@@ -131,6 +141,8 @@ impl From<QueryModel> for InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
             created,
             replaced,
         }
@@ -146,6 +158,8 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         } = model;
         Self {
             uuid: ModelUuid::new(),
@@ -156,6 +170,8 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
             created: DateTime::now(),
             replaced: None,
         }
@@ -0,0 +1,499 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+    time::Duration,
+};
+
+use bencher_json::{
+    project::webhook::{
+        JsonWebhookDelivery, JsonWebhookPayload, WebhookDeliveryStatus, WebhookEvent,
+        WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER,
+    },
+    DateTime, Secret, WebhookDeliveryUuid, WebhookUuid,
+};
+use chrono::Utc;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use hmac::{Hmac, Mac};
+use http::{header::CONTENT_TYPE, StatusCode};
+use sha2::Sha256;
+use slog::Logger;
+
+use super::{QueryWebhook, WebhookId};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{issue_error, resource_conflict_err, resource_not_found_err},
+    model::project::QueryProject,
+    schema::{self, webhook_delivery as webhook_delivery_table},
+    util::fn_get::{fn_get, fn_get_id},
+};
+
+crate::util::typed_id::typed_id!(WebhookDeliveryId);
+
+/// How often to check for pending deliveries
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// The maximum number of pending deliveries to send each poll
+const BATCH_SIZE: i64 = 32;
+/// How long to wait for a response from the webhook URL
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// The maximum number of delivery attempts before giving up
+const MAX_ATTEMPTS: i32 = 8;
+/// The delay before the first retry, which doubles with each attempt
+const RETRY_DELAY_SECONDS: i64 = 60;
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = webhook_delivery_table)]
+#[diesel(belongs_to(QueryWebhook, foreign_key = webhook_id))]
+pub struct QueryWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt: Option<DateTime>,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhookDelivery {
+    fn_get!(webhook_delivery, WebhookDeliveryId);
+    fn_get_id!(webhook_delivery, WebhookDeliveryId, WebhookDeliveryUuid);
+
+    fn get_pending(conn: &mut DbConnection) -> Result<Vec<Self>, HttpError> {
+        let now = DateTime::now();
+        schema::webhook_delivery::table
+            .filter(schema::webhook_delivery::status.eq(WebhookDeliveryStatus::Pending))
+            .filter(schema::webhook_delivery::next_attempt.le(now))
+            .order(schema::webhook_delivery::next_attempt.asc())
+            .limit(BATCH_SIZE)
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(WebhookDelivery, now))
+    }
+
+    pub fn into_json(self, webhook_uuid: WebhookUuid) -> JsonWebhookDelivery {
+        let Self {
+            uuid,
+            event,
+            payload,
+            status,
+            attempts,
+            next_attempt,
+            response_status,
+            error,
+            created,
+            modified,
+            ..
+        } = self;
+        JsonWebhookDelivery {
+            uuid,
+            webhook: webhook_uuid,
+            event,
+            status,
+            payload,
+            attempts: u32::try_from(attempts).unwrap_or_default(),
+            next_attempt,
+            response_status: response_status.and_then(|status| u16::try_from(status).ok()),
+            error,
+            created,
+            modified,
+        }
+    }
+
+    /// Send the delivery to the webhook URL.
+    /// Do not hold the database connection lock while waiting on the response.
+    async fn send(
+        &self,
+        client: &reqwest::Client,
+        query_webhook: &QueryWebhook,
+    ) -> DeliveryAttempt {
+        if let Err(e) = check_destination(query_webhook.url.as_ref()).await {
+            return DeliveryAttempt::error(e);
+        }
+        let signature = match sign(&query_webhook.secret, &self.payload) {
+            Ok(signature) => signature,
+            Err(e) => return DeliveryAttempt::error(format!("Failed to sign payload: {e}")),
+        };
+        let response = client
+            .post(query_webhook.url.as_ref())
+            .header(CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_SIGNATURE_HEADER, signature)
+            .header(WEBHOOK_EVENT_HEADER, self.event.to_string())
+            .header(WEBHOOK_DELIVERY_HEADER, self.uuid.to_string())
+            .body(self.payload.clone())
+            .send()
+            .await;
+        match response {
+            Ok(response) => {
+                let status = response.status();
+                let error = (!status.is_success())
+                    .then(|| format!("Unsuccessful response status: {status}"));
+                DeliveryAttempt {
+                    response_status: Some(i32::from(status.as_u16())),
+                    error,
+                }
+            },
+            Err(e) => DeliveryAttempt::error(format!("Failed to send request: {e}")),
+        }
+    }
+
+    /// Record the result of a delivery attempt.
+    /// A failed attempt is retried with exponential backoff, unless `retry` is false.
+    fn record_attempt(
+        &self,
+        conn: &mut DbConnection,
+        attempt: DeliveryAttempt,
+        retry: bool,
+    ) -> Result<(), HttpError> {
+        let attempts = self.attempts.saturating_add(1);
+        let (status, next_attempt) = if attempt.error.is_none() {
+            (WebhookDeliveryStatus::Delivered, None)
+        } else if retry && attempts < MAX_ATTEMPTS {
+            let delay = RETRY_DELAY_SECONDS.saturating_mul(1 << attempts.saturating_sub(1));
+            let next_attempt = Utc::now() + chrono::Duration::seconds(delay);
+            (WebhookDeliveryStatus::Pending, Some(next_attempt.into()))
+        } else {
+            (WebhookDeliveryStatus::Failed, None)
+        };
+        let update_webhook_delivery = UpdateWebhookDelivery {
+            status,
+            attempts,
+            next_attempt,
+            response_status: attempt.response_status,
+            error: attempt.error,
+            modified: DateTime::now(),
+        };
+        diesel::update(
+            schema::webhook_delivery::table.filter(schema::webhook_delivery::id.eq(self.id)),
+        )
+        .set(&update_webhook_delivery)
+        .execute(conn)
+        .map_err(resource_conflict_err!(
+            WebhookDelivery,
+            (self, &update_webhook_delivery)
+        ))?;
+        Ok(())
+    }
+
+    /// Send a test delivery to a webhook and wait for the result.
+    /// Failed test deliveries are not retried.
+    pub async fn send_test(
+        context: &ApiContext,
+        query_project: &QueryProject,
+        query_webhook: &QueryWebhook,
+    ) -> Result<Self, HttpError> {
+        let payload = JsonWebhookPayload {
+            event: WebhookEvent::Test,
+            project: query_project.uuid,
+            alert: None,
+            report: None,
+            created: DateTime::now(),
+        };
+        let query_delivery = conn_lock!(context, |conn| {
+            let delivery_id = InsertWebhookDelivery::insert(conn, query_webhook.id, &payload)?;
+            Self::get(conn, delivery_id)
+        })?;
+
+        let client = delivery_client()?;
+        let attempt = query_delivery.send(&client, query_webhook).await;
+        conn_lock!(context, |conn| {
+            query_delivery.record_attempt(conn, attempt, false)?;
+            Self::get(conn, query_delivery.id)
+        })
+    }
+
+    /// Spawn a background task that sends all pending webhook deliveries,
+    /// including retries for failed attempts.
+    /// Pending deliveries are stored in the database, so they survive a server restart.
+    pub fn spawn_worker(log: Logger, conn: Arc<tokio::sync::Mutex<DbConnection>>) {
+        tokio::spawn(async move {
+            let client = match delivery_client() {
+                Ok(client) => client,
+                Err(e) => {
+                    slog::error!(log, "Failed to create webhook delivery client: {e}");
+                    return;
+                },
+            };
+            #[allow(clippy::infinite_loop)]
+            loop {
+                tokio::time::sleep(POLL_INTERVAL).await;
+
+                let pending = Self::get_pending(&mut *conn.lock().await);
+                let pending = match pending {
+                    Ok(pending) => pending,
+                    Err(e) => {
+                        slog::error!(log, "Failed to get pending webhook deliveries: {e}");
+                        continue;
+                    },
+                };
+                for query_delivery in pending {
+                    let query_webhook =
+                        QueryWebhook::get(&mut *conn.lock().await, query_delivery.webhook_id);
+                    let query_webhook = match query_webhook {
+                        Ok(query_webhook) => query_webhook,
+                        Err(e) => {
+                            slog::error!(log, "Failed to get webhook for delivery: {e}");
+                            continue;
+                        },
+                    };
+                    let attempt = query_delivery.send(&client, &query_webhook).await;
+                    if let Some(error) = &attempt.error {
+                        slog::debug!(
+                            log,
+                            "Webhook delivery ({}) attempt failed: {error}",
+                            query_delivery.uuid
+                        );
+                    }
+                    let recorded =
+                        query_delivery.record_attempt(&mut *conn.lock().await, attempt, true);
+                    if let Err(e) = recorded {
+                        slog::error!(log, "Failed to record webhook delivery attempt: {e}");
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[derive(Debug)]
+struct DeliveryAttempt {
+    response_status: Option<i32>,
+    error: Option<String>,
+}
+
+impl DeliveryAttempt {
+    fn error(error: String) -> Self {
+        Self {
+            response_status: None,
+            error: Some(error),
+        }
+    }
+}
+
+fn delivery_client() -> Result<reqwest::Client, HttpError> {
+    reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        // A redirect could point to a non-public address after the destination check
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to create webhook delivery client",
+                "Failed to create the HTTP client for webhook deliveries.",
+                e,
+            )
+        })
+}
+
+/// Webhook URLs are user provided, so the API server only sends deliveries to public addresses.
+/// The host is resolved at send time, as the DNS records may have changed since the webhook was saved.
+async fn check_destination(url: &str) -> Result<(), String> {
+    let url = url::Url::parse(url).map_err(|e| format!("Invalid webhook URL ({url}): {e}"))?;
+    let ips = match url.host() {
+        Some(url::Host::Ipv4(ip)) => vec![IpAddr::V4(ip)],
+        Some(url::Host::Ipv6(ip)) => vec![IpAddr::V6(ip)],
+        Some(url::Host::Domain(domain)) => {
+            let port = url.port_or_known_default().unwrap_or_default();
+            tokio::net::lookup_host((domain, port))
+                .await
+                .map_err(|e| format!("Failed to resolve webhook host ({domain}): {e}"))?
+                .map(|addr| addr.ip())
+                .collect()
+        },
+        None => return Err(format!("Webhook URL ({url}) does not have a host")),
+    };
+    if ips.is_empty() {
+        return Err(format!(
+            "Webhook URL ({url}) did not resolve to any address"
+        ));
+    }
+    if let Some(ip) = ips.into_iter().find(|ip| !is_public_ip(*ip)) {
+        return Err(format!(
+            "Webhook URL ({url}) resolves to a non-public address ({ip})"
+        ));
+    }
+    Ok(())
+}
+
+/// Loopback, private, link-local, unspecified, broadcast, and multicast addresses are not public
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => ip
+            .to_ipv4_mapped()
+            .map_or_else(|| is_public_ipv6(ip), is_public_ipv4),
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast())
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments().first().copied().unwrap_or_default();
+    // Unique local addresses (fc00::/7) are private
+    let unique_local = (first_segment & 0xfe00) == 0xfc00;
+    // Unicast link-local addresses (fe80::/10)
+    let link_local = (first_segment & 0xffc0) == 0xfe80;
+    !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || unique_local || link_local)
+}
+
+/// Sign the payload with HMAC-SHA256 using the webhook secret
+fn sign(secret: &Secret, payload: &str) -> Result<String, hmac::digest::InvalidLength> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_ref().as_bytes())?;
+    mac.update(payload.as_bytes());
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_delivery_table)]
+pub struct InsertWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt: Option<DateTime>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhookDelivery {
+    /// Queue up a delivery for every webhook in the project that is subscribed to the payload event.
+    pub fn enqueue(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        payload: &JsonWebhookPayload,
+    ) -> Result<(), HttpError> {
+        for webhook_id in QueryWebhook::subscribed_for_project(conn, query_project, payload.event)?
+        {
+            Self::insert(conn, webhook_id, payload)?;
+        }
+        Ok(())
+    }
+
+    fn insert(
+        conn: &mut DbConnection,
+        webhook_id: WebhookId,
+        payload: &JsonWebhookPayload,
+    ) -> Result<WebhookDeliveryId, HttpError> {
+        let timestamp = DateTime::now();
+        // Test deliveries are sent right away,
+        // so they are not picked up by the background worker.
+        let next_attempt = (payload.event != WebhookEvent::Test).then_some(timestamp);
+        let insert_webhook_delivery = Self {
+            uuid: WebhookDeliveryUuid::new(),
+            webhook_id,
+            event: payload.event,
+            payload: serde_json::to_string(payload).map_err(|e| {
+                issue_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to serialize webhook payload",
+                    &format!("Failed to serialize webhook ({webhook_id}) payload."),
+                    e,
+                )
+            })?,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt,
+            created: timestamp,
+            modified: timestamp,
+        };
+        diesel::insert_into(schema::webhook_delivery::table)
+            .values(&insert_webhook_delivery)
+            .execute(conn)
+            .map_err(resource_conflict_err!(
+                WebhookDelivery,
+                insert_webhook_delivery
+            ))?;
+        QueryWebhookDelivery::get_id(conn, insert_webhook_delivery.uuid)
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_delivery_table)]
+#[diesel(treat_none_as_null = true)]
+pub struct UpdateWebhookDelivery {
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt: Option<DateTime>,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub modified: DateTime,
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::IpAddr;
+
+    use super::{check_destination, is_public_ip};
+
+    #[test]
+    fn test_is_public_ip() {
+        for ip in ["1.1.1.1", "93.184.216.34", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+        for ip in [
+            // Loopback
+            "127.0.0.1",
+            "::1",
+            // Private
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "fd00::1",
+            // Link-local
+            "169.254.169.254",
+            "fe80::1",
+            // Unspecified
+            "0.0.0.0",
+            "::",
+            // Broadcast and multicast
+            "255.255.255.255",
+            "224.0.0.1",
+            "ff02::1",
+            // IPv4-mapped IPv6
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+        ] {
+            assert!(!is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_check_destination() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        for url in [
+            "http://169.254.169.254/latest/meta-data",
+            "http://127.0.0.1:61016/v0/server/version",
+            "http://[::1]:8080",
+            "https://10.0.0.1/hook",
+            "http://0.0.0.0",
+            "http://[::ffff:7f00:1]",
+        ] {
+            assert!(runtime.block_on(check_destination(url)).is_err(), "{url}");
+        }
+        assert!(runtime
+            .block_on(check_destination("https://1.1.1.1/hook"))
+            .is_ok());
+    }
+}
@@ -0,0 +1,354 @@
+use std::collections::BTreeSet;
+
+use bencher_json::{
+    project::webhook::{JsonUpdateWebhook, JsonWebhook, JsonWebhookPayload, WebhookEvent},
+    DateTime, JsonAlert, JsonNewWebhook, JsonReport, Secret, Url, WebhookUuid,
+};
+use diesel::{BelongingToDsl, Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use slog::Logger;
+
+use super::{
+    report::ReportId,
+    threshold::{alert::QueryAlert, alert_event::QueryAlertEvent},
+    ProjectId, QueryProject,
+};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{
+        assert_parentage, bad_request_error, resource_conflict_err, resource_not_found_err,
+        BencherResource,
+    },
+    schema::{self, webhook as webhook_table, webhook_subscription as webhook_subscription_table},
+    util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
+};
+
+pub mod delivery;
+
+use delivery::InsertWebhookDelivery;
+
+crate::util::typed_id::typed_id!(WebhookId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = webhook_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryWebhook {
+    pub id: WebhookId,
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhook {
+    fn_get!(webhook, WebhookId);
+    fn_get_id!(webhook, WebhookId, WebhookUuid);
+    fn_get_uuid!(webhook, WebhookId, WebhookUuid);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: WebhookUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(&query_project)
+            .filter(webhook_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(Webhook, (query_project, uuid)))
+    }
+
+    pub fn events(&self, conn: &mut DbConnection) -> Result<Vec<WebhookEvent>, HttpError> {
+        schema::webhook_subscription::table
+            .filter(schema::webhook_subscription::webhook_id.eq(self.id))
+            .order(schema::webhook_subscription::event)
+            .select(schema::webhook_subscription::event)
+            .load::<WebhookEvent>(conn)
+            .map_err(resource_not_found_err!(WebhookSubscription, self))
+    }
+
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonWebhook, HttpError> {
+        let events = self.events(conn)?;
+        let Self {
+            uuid,
+            project_id,
+            url,
+            created,
+            modified,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Webhook,
+            project_id,
+        );
+        Ok(JsonWebhook {
+            uuid,
+            project: project.uuid,
+            url,
+            events,
+            created,
+            modified,
+        })
+    }
+
+    /// Queue up webhook deliveries for a newly created report,
+    /// any alerts that it generated, and any alerts that it resolved.
+    /// Failing to queue a delivery does not fail the report.
+    pub async fn enqueue_for_report(
+        log: &Logger,
+        context: &ApiContext,
+        query_project: &QueryProject,
+        report_id: ReportId,
+        json_report: &JsonReport,
+    ) {
+        if let Err(e) =
+            Self::enqueue_for_report_inner(context, query_project, report_id, json_report).await
+        {
+            slog::error!(log, "Failed to queue webhook deliveries for report: {e}");
+            #[cfg(feature = "sentry")]
+            sentry::capture_error(&e);
+        }
+    }
+
+    async fn enqueue_for_report_inner(
+        context: &ApiContext,
+        query_project: &QueryProject,
+        report_id: ReportId,
+        json_report: &JsonReport,
+    ) -> Result<(), HttpError> {
+        // Most projects do not have any webhooks
+        if !Self::exists_for_project(conn_lock!(context), query_project)? {
+            return Ok(());
+        }
+
+        let payload = JsonWebhookPayload {
+            event: WebhookEvent::ReportCreated,
+            project: query_project.uuid,
+            alert: None,
+            report: Some(json_report.clone()),
+            created: DateTime::now(),
+        };
+        InsertWebhookDelivery::enqueue(conn_lock!(context), query_project, &payload)?;
+
+        for json_alert in &json_report.alerts {
+            let payload = JsonWebhookPayload {
+                event: WebhookEvent::AlertCreated,
+                project: query_project.uuid,
+                alert: Some(json_alert.clone()),
+                report: None,
+                created: DateTime::now(),
+            };
+            InsertWebhookDelivery::enqueue(conn_lock!(context), query_project, &payload)?;
+        }
+
+        let resolved_alerts = QueryAlertEvent::resolved_by_report(conn_lock!(context), report_id)?;
+        for alert_id in resolved_alerts {
+            let json_alert = QueryAlert::get(conn_lock!(context), alert_id)?
+                .into_json(context)
+                .await?;
+            Self::enqueue_alert_resolved(conn_lock!(context), query_project, json_alert)?;
+        }
+
+        Ok(())
+    }
+
+    /// Queue up webhook deliveries for a resolved alert.
+    pub fn enqueue_alert_resolved(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        json_alert: JsonAlert,
+    ) -> Result<(), HttpError> {
+        let payload = JsonWebhookPayload {
+            event: WebhookEvent::AlertResolved,
+            project: query_project.uuid,
+            alert: Some(json_alert),
+            report: None,
+            created: DateTime::now(),
+        };
+        InsertWebhookDelivery::enqueue(conn, query_project, &payload)
+    }
+
+    fn exists_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<bool, HttpError> {
+        Self::belonging_to(query_project)
+            .count()
+            .get_result::<i64>(conn)
+            .map(|count| count > 0)
+            .map_err(resource_not_found_err!(Webhook, query_project))
+    }
+
+    fn subscribed_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        event: WebhookEvent,
+    ) -> Result<Vec<WebhookId>, HttpError> {
+        schema::webhook::table
+            .inner_join(schema::webhook_subscription::table)
+            .filter(schema::webhook::project_id.eq(query_project.id))
+            .filter(schema::webhook_subscription::event.eq(event))
+            .select(schema::webhook::id)
+            .load::<WebhookId>(conn)
+            .map_err(resource_not_found_err!(Webhook, (query_project, event)))
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhook {
+    pub fn insert_from_json(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        json_webhook: JsonNewWebhook,
+    ) -> Result<WebhookId, HttpError> {
+        let JsonNewWebhook {
+            url,
+            secret,
+            events,
+        } = json_webhook;
+        validate_url(&url)?;
+        let events = validate_events(events)?;
+
+        let timestamp = DateTime::now();
+        let insert_webhook = InsertWebhook {
+            uuid: WebhookUuid::new(),
+            project_id: query_project.id,
+            url,
+            secret,
+            created: timestamp,
+            modified: timestamp,
+        };
+        diesel::insert_into(schema::webhook::table)
+            .values(&insert_webhook)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Webhook, insert_webhook))?;
+
+        let webhook_id = QueryWebhook::get_id(conn, insert_webhook.uuid)?;
+        InsertWebhookSubscription::insert_all(conn, webhook_id, &events).map_err(
+            resource_conflict_err!(WebhookSubscription, (webhook_id, &events)),
+        )?;
+
+        Ok(webhook_id)
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_table)]
+pub struct UpdateWebhook {
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub modified: DateTime,
+}
+
+impl UpdateWebhook {
+    pub fn update_from_json(
+        conn: &mut DbConnection,
+        query_webhook: &QueryWebhook,
+        json_webhook: JsonUpdateWebhook,
+    ) -> Result<(), HttpError> {
+        let JsonUpdateWebhook {
+            url,
+            secret,
+            events,
+        } = json_webhook;
+        if let Some(url) = &url {
+            validate_url(url)?;
+        }
+        let events = events.map(validate_events).transpose()?;
+
+        let update_webhook = Self {
+            url,
+            secret,
+            modified: DateTime::now(),
+        };
+        // The webhook must never be left without any events,
+        // or a report could be enqueued in between replacing them.
+        conn.transaction(|conn| {
+            diesel::update(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+                .set(&update_webhook)
+                .execute(conn)?;
+
+            // Replace all of the existing events
+            if let Some(events) = &events {
+                diesel::delete(
+                    schema::webhook_subscription::table
+                        .filter(schema::webhook_subscription::webhook_id.eq(query_webhook.id)),
+                )
+                .execute(conn)?;
+                InsertWebhookSubscription::insert_all(conn, query_webhook.id, events)?;
+            }
+            diesel::QueryResult::Ok(())
+        })
+        .map_err(resource_conflict_err!(
+            Webhook,
+            (query_webhook, &update_webhook, &events)
+        ))
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = webhook_subscription_table)]
+pub struct InsertWebhookSubscription {
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+}
+
+impl InsertWebhookSubscription {
+    fn insert_all(
+        conn: &mut DbConnection,
+        webhook_id: WebhookId,
+        events: &BTreeSet<WebhookEvent>,
+    ) -> diesel::QueryResult<()> {
+        for &event in events {
+            let insert_webhook_subscription = Self { webhook_id, event };
+            diesel::insert_into(schema::webhook_subscription::table)
+                .values(&insert_webhook_subscription)
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_url(url: &Url) -> Result<(), HttpError> {
+    let parsed_url: url::Url = url.clone().try_into().map_err(bad_request_error)?;
+    match parsed_url.scheme() {
+        "http" | "https" => Ok(()),
+        scheme => Err(bad_request_error(format!(
+            "Webhook URL ({url}) must use either `http` or `https`, not `{scheme}`"
+        ))),
+    }
+}
+
+fn validate_events(events: Vec<WebhookEvent>) -> Result<BTreeSet<WebhookEvent>, HttpError> {
+    if events.contains(&WebhookEvent::Test) {
+        return Err(bad_request_error(
+            "Webhooks can not subscribe to the `test` event. Test deliveries are always sent.",
+        ));
+    }
+    let events = events.into_iter().collect::<BTreeSet<_>>();
+    if events.is_empty() {
+        return Err(bad_request_error(
+            "A webhook must subscribe to at least one event",
+        ));
+    }
+    Ok(events)
+}
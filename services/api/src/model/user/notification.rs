@@ -0,0 +1,496 @@
+use std::time::Duration;
+
+use bencher_json::{
+    project::{alert::AlertStatus, boundary::BoundaryLimit},
+    user::notification::JsonUpdateNotification,
+    DateTime, JsonAlert, JsonNotification, JsonPerfQuery, JsonReport,
+};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use slog::Logger;
+use url::Url;
+
+use crate::{
+    conn_lock,
+    context::{AlertBody, AlertBodyItem, ApiContext, Body, DbConnection, Message},
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
+    model::project::{ProjectId, QueryProject},
+    schema::{self, project_notification as project_notification_table},
+};
+
+use super::{QueryUser, UserId};
+
+crate::util::typed_id::typed_id!(ProjectNotificationId);
+
+// 30 days
+const DEFAULT_REPORT_HISTORY: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+#[derive(Debug, Clone, diesel::Queryable)]
+pub struct QueryProjectNotification {
+    pub id: ProjectNotificationId,
+    pub user_id: UserId,
+    pub project_id: ProjectId,
+    pub alert_email: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryProjectNotification {
+    fn get_for_project(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+        query_project: &QueryProject,
+    ) -> Result<Option<Self>, HttpError> {
+        schema::project_notification::table
+            .filter(schema::project_notification::user_id.eq(query_user.id))
+            .filter(schema::project_notification::project_id.eq(query_project.id))
+            .first::<Self>(conn)
+            .optional()
+            .map_err(resource_not_found_err!(
+                ProjectNotification,
+                (query_user, query_project)
+            ))
+    }
+
+    /// Only members of a project's organization can manage notifications for the project.
+    pub fn assert_member(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+        query_project: &QueryProject,
+    ) -> Result<(), HttpError> {
+        let count = schema::organization_role::table
+            .filter(schema::organization_role::user_id.eq(query_user.id))
+            .filter(schema::organization_role::organization_id.eq(query_project.organization_id))
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(resource_not_found_err!(
+                OrganizationRole,
+                (query_user, query_project)
+            ))?;
+        if count > 0 {
+            Ok(())
+        } else {
+            Err(bad_request_error(format!(
+                "User ({user}) is not a member of the organization for project ({project})",
+                user = query_user.uuid,
+                project = query_project.uuid,
+            )))
+        }
+    }
+
+    /// Get the notification preferences of a user for a project.
+    /// Notifications are opt-in, so a user without any saved preferences does not get any emails.
+    pub fn get_json(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+        query_project: QueryProject,
+    ) -> Result<JsonNotification, HttpError> {
+        let alert_email = Self::get_for_project(conn, query_user, &query_project)?
+            .is_some_and(|notification| notification.alert_email);
+        Ok(JsonNotification {
+            user: query_user.uuid,
+            project: query_project.into_json(conn)?,
+            alert_email,
+        })
+    }
+
+    /// Update the notification preferences of a user for a project,
+    /// saving them for the first time if they do not exist yet.
+    pub fn update_from_json(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+        query_project: &QueryProject,
+        json_notification: &JsonUpdateNotification,
+    ) -> Result<(), HttpError> {
+        let alert_email = json_notification.alert_email;
+        let timestamp = DateTime::now();
+        if let Some(query_notification) = Self::get_for_project(conn, query_user, query_project)? {
+            let update_notification = UpdateProjectNotification {
+                alert_email,
+                modified: timestamp,
+            };
+            diesel::update(
+                schema::project_notification::table
+                    .filter(schema::project_notification::id.eq(query_notification.id)),
+            )
+            .set(&update_notification)
+            .execute(conn)
+            .map_err(resource_conflict_err!(
+                ProjectNotification,
+                (&query_notification, &update_notification)
+            ))?;
+        } else {
+            let insert_notification = InsertProjectNotification {
+                user_id: query_user.id,
+                project_id: query_project.id,
+                alert_email: alert_email.unwrap_or_default(),
+                created: timestamp,
+                modified: timestamp,
+            };
+            diesel::insert_into(schema::project_notification::table)
+                .values(&insert_notification)
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    ProjectNotification,
+                    insert_notification
+                ))?;
+        }
+        Ok(())
+    }
+
+    /// Get all of the users that have opted in to alert emails for a project
+    /// and are still members of the project's organization.
+    fn alert_email_recipients(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<Vec<QueryUser>, HttpError> {
+        let members = schema::organization_role::table
+            .filter(schema::organization_role::organization_id.eq(query_project.organization_id))
+            .select(schema::organization_role::user_id);
+        schema::user::table
+            .inner_join(schema::project_notification::table)
+            .filter(schema::project_notification::project_id.eq(query_project.id))
+            .filter(schema::project_notification::alert_email.eq(true))
+            .filter(schema::user::locked.eq(false))
+            .filter(schema::user::id.eq_any(members))
+            .select(schema::user::all_columns)
+            .load::<QueryUser>(conn)
+            .map_err(resource_not_found_err!(ProjectNotification, query_project))
+    }
+
+    /// Email all of the project members that have opted in
+    /// about the new alerts generated by a report.
+    /// All of the alerts for the report are grouped into a single email.
+    /// Failing to send the emails does not fail the report.
+    pub async fn notify_report_alerts(
+        log: &Logger,
+        context: &ApiContext,
+        query_project: &QueryProject,
+        json_report: &JsonReport,
+    ) {
+        if let Err(e) =
+            Self::notify_report_alerts_inner(log, context, query_project, json_report).await
+        {
+            slog::error!(log, "Failed to send alert emails for report: {e}");
+            #[cfg(feature = "sentry")]
+            sentry::capture_error(&e);
+        }
+    }
+
+    async fn notify_report_alerts_inner(
+        log: &Logger,
+        context: &ApiContext,
+        query_project: &QueryProject,
+        json_report: &JsonReport,
+    ) -> Result<(), HttpError> {
+        let messages = Self::alert_messages(
+            conn_lock!(context),
+            &context.console_url,
+            query_project,
+            json_report,
+        )?;
+        for message in messages {
+            context.messenger.send(log, message);
+        }
+        Ok(())
+    }
+
+    fn alert_messages(
+        conn: &mut DbConnection,
+        console_url: &Url,
+        query_project: &QueryProject,
+        json_report: &JsonReport,
+    ) -> Result<Vec<Message>, HttpError> {
+        // Silenced alerts should stay silent
+        let alerts = json_report
+            .alerts
+            .iter()
+            .filter(|alert| alert.status == AlertStatus::Active)
+            .map(|alert| alert_body_item(console_url, json_report, alert))
+            .collect::<Vec<_>>();
+        if alerts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let recipients = Self::alert_email_recipients(conn, query_project)?;
+        let subject = if alerts.len() == 1 {
+            format!("🐰 New Bencher Alert for {}", json_report.project.name)
+        } else {
+            format!(
+                "🐰 {} New Bencher Alerts for {}",
+                alerts.len(),
+                json_report.project.name
+            )
+        };
+        Ok(recipients
+            .into_iter()
+            .map(|recipient| {
+                let mut settings_url = console_url.clone();
+                settings_url.set_path(&format!(
+                    "/console/users/{user_slug}/notifications",
+                    user_slug = recipient.slug
+                ));
+                Message {
+                    to_name: Some(recipient.name.clone().into()),
+                    to_email: recipient.email.into(),
+                    subject: Some(subject.clone()),
+                    body: Some(Body::Alert(Box::new(AlertBody {
+                        name: recipient.name.into(),
+                        project: json_report.project.name.to_string(),
+                        branch: json_report.branch.name.to_string(),
+                        testbed: json_report.testbed.name.to_string(),
+                        alerts: alerts.clone(),
+                        settings_url: settings_url.into(),
+                    }))),
+                }
+            })
+            .collect())
+    }
+}
+
+fn alert_body_item(
+    console_url: &Url,
+    json_report: &JsonReport,
+    alert: &JsonAlert,
+) -> AlertBodyItem {
+    let project_slug = &json_report.project.slug;
+
+    let mut alert_url = console_url.clone();
+    alert_url.set_path(&format!(
+        "/console/projects/{project_slug}/alerts/{alert}",
+        alert = alert.uuid
+    ));
+
+    let json_perf_query = JsonPerfQuery {
+        branches: vec![json_report.branch.uuid],
+        testbeds: vec![json_report.testbed.uuid],
+        benchmarks: vec![alert.benchmark.uuid],
+        measures: vec![alert.threshold.measure.uuid],
+        start_time: Some((json_report.start_time.into_inner() - DEFAULT_REPORT_HISTORY).into()),
+        end_time: Some(json_report.end_time),
+    };
+    let mut perf_url = console_url.clone();
+    perf_url.set_path(&format!("/console/projects/{project_slug}/perf"));
+    perf_url.set_query(json_perf_query.to_query_string(&[]).ok().as_deref());
+
+    let boundary = alert.benchmark.boundary.as_ref();
+    let (limit_name, limit) = match alert.limit {
+        BoundaryLimit::Lower => (
+            "lower limit",
+            boundary.and_then(|boundary| boundary.lower_limit),
+        ),
+        BoundaryLimit::Upper => (
+            "upper limit",
+            boundary.and_then(|boundary| boundary.upper_limit),
+        ),
+    };
+
+    AlertBodyItem {
+        benchmark: alert.benchmark.name.to_string(),
+        measure: alert.threshold.measure.name.to_string(),
+        value: alert.benchmark.metric.value.to_string(),
+        limit: limit.map_or_else(
+            || limit_name.to_owned(),
+            |limit| format!("{limit_name} ({limit})"),
+        ),
+        alert_url: alert_url.into(),
+        perf_url: perf_url.into(),
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = project_notification_table)]
+pub struct InsertProjectNotification {
+    pub user_id: UserId,
+    pub project_id: ProjectId,
+    pub alert_email: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = project_notification_table)]
+pub struct UpdateProjectNotification {
+    pub alert_email: Option<bool>,
+    pub modified: DateTime,
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::JsonReport;
+    use diesel::connection::SimpleConnection;
+    use url::Url;
+
+    use crate::{
+        context::DbConnection,
+        model::project::{ProjectId, QueryProject},
+        util::test_db::test_db,
+    };
+
+    use super::QueryProjectNotification;
+
+    const PROJECT_UUID: &str = "00000000-0000-0000-0000-000000000001";
+
+    // Users that have all opted in to alert emails for the project:
+    // a member, a locked member, a member of a different organization, and a member that has opted out.
+    fn seed_recipients(conn: &mut DbConnection) -> QueryProject {
+        conn.batch_execute(&format!(
+            "
+            INSERT INTO project (id, uuid, organization_id, name, slug, visibility, created, modified) VALUES
+                (1, '{PROJECT_UUID}', 1, 'Project', 'project', 0, 0, 0);
+            INSERT INTO user (id, uuid, name, slug, email, admin, locked, created, modified) VALUES
+                (1, '00000000-0000-0000-0000-000000000001', 'Member', 'member', 'member@example.com', 0, 0, 0, 0),
+                (2, '00000000-0000-0000-0000-000000000002', 'Locked', 'locked', 'locked@example.com', 0, 1, 0, 0),
+                (3, '00000000-0000-0000-0000-000000000003', 'Outsider', 'outsider', 'outsider@example.com', 0, 0, 0, 0),
+                (4, '00000000-0000-0000-0000-000000000004', 'Opted Out', 'opted-out', 'opted-out@example.com', 0, 0, 0, 0);
+            INSERT INTO organization_role (id, user_id, organization_id, role, created, modified) VALUES
+                (1, 1, 1, 'member', 0, 0),
+                (2, 2, 1, 'member', 0, 0),
+                (3, 3, 2, 'member', 0, 0),
+                (4, 4, 1, 'member', 0, 0);
+            INSERT INTO project_notification (id, user_id, project_id, alert_email, created, modified) VALUES
+                (1, 1, 1, 1, 0, 0),
+                (2, 2, 1, 1, 0, 0),
+                (3, 3, 1, 1, 0, 0),
+                (4, 4, 1, 0, 0, 0);
+            "
+        ))
+        .unwrap();
+        QueryProject::get(conn, ProjectId::from(1)).unwrap()
+    }
+
+    fn json_report(alert_statuses: &[&str]) -> JsonReport {
+        let created = "2024-01-01T00:00:00Z";
+        let resource = |uuid: &str, name: &str| {
+            serde_json::Map::from_iter([
+                ("uuid".to_owned(), uuid.into()),
+                ("project".to_owned(), PROJECT_UUID.into()),
+                ("name".to_owned(), name.into()),
+                ("slug".to_owned(), name.into()),
+                ("created".to_owned(), created.into()),
+                ("modified".to_owned(), created.into()),
+            ])
+        };
+        let branch = resource("00000000-0000-0000-0000-000000000002", "main");
+        let testbed = resource("00000000-0000-0000-0000-000000000003", "localhost");
+        let mut measure = resource("00000000-0000-0000-0000-000000000004", "latency");
+        measure.insert("units".to_owned(), "nanoseconds (ns)".into());
+        let mut branch_version = branch.clone();
+        branch_version.insert("version".to_owned(), serde_json::json!({ "number": 1 }));
+        let alerts = alert_statuses
+            .iter()
+            .map(|status| {
+                let mut benchmark = resource("00000000-0000-0000-0000-000000000005", "fib");
+                benchmark.insert(
+                    "metric".to_owned(),
+                    serde_json::json!({
+                        "uuid": "00000000-0000-0000-0000-000000000006",
+                        "value": 1.0,
+                    }),
+                );
+                serde_json::json!({
+                    "uuid": "00000000-0000-0000-0000-000000000007",
+                    "report": "00000000-0000-0000-0000-000000000008",
+                    "iteration": 0,
+                    "threshold": {
+                        "uuid": "00000000-0000-0000-0000-000000000009",
+                        "project": PROJECT_UUID,
+                        "branch": branch,
+                        "testbed": testbed,
+                        "measure": measure,
+                        "model": {
+                            "uuid": "00000000-0000-0000-0000-00000000000a",
+                            "threshold": "00000000-0000-0000-0000-000000000009",
+                            "test": "static",
+                            "created": created,
+                        },
+                        "created": created,
+                        "modified": created,
+                    },
+                    "benchmark": benchmark,
+                    "limit": "upper",
+                    "status": status,
+                    "created": created,
+                    "modified": created,
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::from_value(serde_json::json!({
+            "uuid": "00000000-0000-0000-0000-000000000008",
+            "user": {
+                "uuid": "00000000-0000-0000-0000-000000000001",
+                "name": "Member",
+                "slug": "member",
+                "email": "member@example.com",
+                "admin": false,
+                "locked": false,
+            },
+            "project": {
+                "uuid": PROJECT_UUID,
+                "organization": "00000000-0000-0000-0000-000000000001",
+                "name": "Project",
+                "slug": "project",
+                "visibility": "public",
+                "created": created,
+                "modified": created,
+            },
+            "branch": branch_version,
+            "testbed": testbed,
+            "start_time": created,
+            "end_time": created,
+            "adapter": "json",
+            "results": [],
+            "alerts": alerts,
+            "created": created,
+        }))
+        .unwrap()
+    }
+
+    fn console_url() -> Url {
+        "https://bencher.dev".parse().unwrap()
+    }
+
+    #[test]
+    fn test_alert_messages_silenced() {
+        let mut conn = test_db();
+        let query_project = seed_recipients(&mut conn);
+
+        let messages = QueryProjectNotification::alert_messages(
+            &mut conn,
+            &console_url(),
+            &query_project,
+            &json_report(&["silenced", "silenced"]),
+        )
+        .unwrap();
+        assert!(messages.is_empty());
+
+        let messages = QueryProjectNotification::alert_messages(
+            &mut conn,
+            &console_url(),
+            &query_project,
+            &json_report(&["silenced", "active"]),
+        )
+        .unwrap();
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn test_alert_messages_recipients() {
+        let mut conn = test_db();
+        let query_project = seed_recipients(&mut conn);
+
+        let messages = QueryProjectNotification::alert_messages(
+            &mut conn,
+            &console_url(),
+            &query_project,
+            &json_report(&["active"]),
+        )
+        .unwrap();
+        // Locked users, users outside of the project's organization, and users that opted out are excluded
+        let recipients = messages
+            .iter()
+            .map(|message| message.to_email.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(recipients, ["member@example.com"]);
+    }
+}
@@ -6,11 +6,30 @@ diesel::table! {
         uuid -> Text,
         boundary_id -> Integer,
         boundary_limit -> Bool,
+        composite_threshold_id -> Nullable<Integer>,
         status -> Integer,
+        assignee_id -> Nullable<Integer>,
+        silenced_until -> Nullable<BigInt>,
+        silenced_version -> Nullable<Integer>,
         modified -> BigInt,
     }
 }
 
+diesel::table! {
+    alert_event (id) {
+        id -> Integer,
+        uuid -> Text,
+        alert_id -> Integer,
+        user_id -> Nullable<Integer>,
+        report_id -> Nullable<Integer>,
+        status -> Integer,
+        assignee_id -> Nullable<Integer>,
+        silenced_until -> Nullable<BigInt>,
+        silenced_version -> Nullable<Integer>,
+        created -> BigInt,
+    }
+}
+
 diesel::table! {
     benchmark (id) {
         id -> Integer,
@@ -33,6 +52,7 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        p_value -> Nullable<Double>,
     }
 }
 
@@ -57,6 +77,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    composite_member (composite_threshold_id, threshold_id) {
+        composite_threshold_id -> Integer,
+        threshold_id -> Integer,
+        boundary_limit -> Nullable<Bool>,
+    }
+}
+
+diesel::table! {
+    composite_threshold (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        branch_id -> Integer,
+        testbed_id -> Integer,
+        operator -> Integer,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     measure (id) {
         id -> Integer,
@@ -82,6 +123,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    metric_sample (metric_id, position) {
+        metric_id -> Integer,
+        position -> Integer,
+        value -> Double,
+    }
+}
+
 diesel::table! {
     model (id) {
         id -> Integer,
@@ -93,6 +142,8 @@ diesel::table! {
         window -> Nullable<BigInt>,
         lower_boundary -> Nullable<Double>,
         upper_boundary -> Nullable<Double>,
+        level_smoothing -> Nullable<Double>,
+        trend_smoothing -> Nullable<Double>,
         created -> BigInt,
         replaced -> Nullable<BigInt>,
     }
@@ -197,6 +248,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    project_notification (id) {
+        id -> Integer,
+        user_id -> Integer,
+        project_id -> Integer,
+        alert_email -> Bool,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     project_role (id) {
         id -> Integer,
@@ -262,6 +324,7 @@ diesel::table! {
         branch_id -> Integer,
         testbed_id -> Integer,
         measure_id -> Integer,
+        benchmark -> Nullable<Text>,
         model_id -> Nullable<Integer>,
         created -> BigInt,
         modified -> BigInt,
@@ -304,16 +367,62 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Text,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    webhook_delivery (id) {
+        id -> Integer,
+        uuid -> Text,
+        webhook_id -> Integer,
+        event -> Integer,
+        payload -> Text,
+        status -> Integer,
+        attempts -> Integer,
+        next_attempt -> Nullable<BigInt>,
+        response_status -> Nullable<Integer>,
+        error -> Nullable<Text>,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    webhook_subscription (webhook_id, event) {
+        webhook_id -> Integer,
+        event -> Integer,
+    }
+}
+
 diesel::joinable!(alert -> boundary (boundary_id));
+diesel::joinable!(alert -> composite_threshold (composite_threshold_id));
+diesel::joinable!(alert -> user (assignee_id));
+diesel::joinable!(alert_event -> alert (alert_id));
+diesel::joinable!(alert_event -> report (report_id));
 diesel::joinable!(benchmark -> project (project_id));
 diesel::joinable!(boundary -> metric (metric_id));
 diesel::joinable!(boundary -> model (model_id));
 diesel::joinable!(boundary -> threshold (threshold_id));
 diesel::joinable!(branch -> project (project_id));
 diesel::joinable!(branch_version -> version (version_id));
+diesel::joinable!(composite_member -> composite_threshold (composite_threshold_id));
+diesel::joinable!(composite_member -> threshold (threshold_id));
+diesel::joinable!(composite_threshold -> branch (branch_id));
+diesel::joinable!(composite_threshold -> project (project_id));
+diesel::joinable!(composite_threshold -> testbed (testbed_id));
 diesel::joinable!(measure -> project (project_id));
 diesel::joinable!(metric -> measure (measure_id));
 diesel::joinable!(metric -> report_benchmark (report_benchmark_id));
+diesel::joinable!(metric_sample -> metric (metric_id));
 diesel::joinable!(organization_role -> organization (organization_id));
 diesel::joinable!(organization_role -> user (user_id));
 diesel::joinable!(plot -> project (project_id));
@@ -326,6 +435,8 @@ diesel::joinable!(plot_measure -> plot (plot_id));
 diesel::joinable!(plot_testbed -> plot (plot_id));
 diesel::joinable!(plot_testbed -> testbed (testbed_id));
 diesel::joinable!(project -> organization (organization_id));
+diesel::joinable!(project_notification -> project (project_id));
+diesel::joinable!(project_notification -> user (user_id));
 diesel::joinable!(project_role -> project (project_id));
 diesel::joinable!(project_role -> user (user_id));
 diesel::joinable!(report -> branch (branch_id));
@@ -342,15 +453,22 @@ diesel::joinable!(threshold -> project (project_id));
 diesel::joinable!(threshold -> testbed (testbed_id));
 diesel::joinable!(token -> user (user_id));
 diesel::joinable!(version -> project (project_id));
+diesel::joinable!(webhook -> project (project_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
+diesel::joinable!(webhook_subscription -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
+    alert_event,
     benchmark,
     boundary,
     branch,
     branch_version,
+    composite_member,
+    composite_threshold,
     measure,
     metric,
+    metric_sample,
     model,
     organization,
     organization_role,
@@ -361,6 +479,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     plot_measure,
     plot_testbed,
     project,
+    project_notification,
     project_role,
     report,
     report_benchmark,
@@ -370,4 +489,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     token,
     user,
     version,
+    webhook,
+    webhook_delivery,
+    webhook_subscription,
 );
@@ -4,4 +4,6 @@ pub mod name_id;
 pub mod resource_id;
 pub mod search;
 pub mod slug;
+#[cfg(test)]
+pub mod test_db;
 pub mod typed_id;
@@ -0,0 +1,15 @@
+use diesel::{connection::SimpleConnection, Connection};
+use diesel_migrations::MigrationHarness;
+
+use crate::{config::config_tx::MIGRATIONS, context::DbConnection};
+
+// An in-memory database with all of the migrations run.
+// Foreign keys are not enforced, so only the rows under test need to exist.
+pub fn test_db() -> DbConnection {
+    let mut conn = DbConnection::establish(":memory:").expect("Failed to open test database");
+    conn.run_pending_migrations(MIGRATIONS)
+        .expect("Failed to run test database migrations");
+    conn.batch_execute("PRAGMA foreign_keys = OFF")
+        .expect("Failed to disable foreign keys");
+    conn
+}
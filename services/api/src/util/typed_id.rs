@@ -22,6 +22,13 @@ macro_rules! typed_id {
             }
         }
 
+        #[cfg(test)]
+        impl From<i32> for $name {
+            fn from(id: i32) -> Self {
+                Self(id)
+            }
+        }
+
         impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for $name
         where
             DB: diesel::backend::Backend,
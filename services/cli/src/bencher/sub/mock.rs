@@ -97,6 +97,7 @@ impl Mock {
                              value: value.into(),
                              lower_value: Some((value - variance).into()),
                              upper_value: Some((value + variance).into()),
+                            samples: None,
                         }
                     },
                 },
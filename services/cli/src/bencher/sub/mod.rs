@@ -16,7 +16,7 @@ use organization::{member::Member, organization::Organization};
 use project::{
     alert::Alert, benchmark::Benchmark, branch::Branch, measure::Measure, metric::Metric,
     perf::Perf, plot::Plot, project::Project, report::Report, run::Run, testbed::Testbed,
-    threshold::Threshold,
+    threshold::Threshold, webhook::Webhook,
 };
 pub use project::{
     run::{runner::output::Output, RunError},
@@ -24,7 +24,7 @@ pub use project::{
 };
 pub use sub_cmd::SubCmd;
 use system::{auth::Auth, server::Server};
-use user::{token::Token, user::User};
+use user::{notification::Notification, token::Token, user::User};
 
 #[derive(Debug)]
 pub enum Sub {
@@ -45,8 +45,10 @@ pub enum Sub {
     Metric(Metric),
     Threshold(Threshold),
     Alert(Alert),
+    Webhook(Webhook),
     User(User),
     Token(Token),
+    Notification(Notification),
     Server(Server),
     Mock(Mock),
     Up(Up),
@@ -76,8 +78,10 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Metric(metric) => Self::Metric(metric.try_into()?),
             CliSub::Threshold(threshold) => Self::Threshold(threshold.try_into()?),
             CliSub::Alert(alert) => Self::Alert(alert.try_into()?),
+            CliSub::Webhook(webhook) => Self::Webhook(webhook.try_into()?),
             CliSub::User(user) => Self::User(user.try_into()?),
             CliSub::Token(token) => Self::Token(token.try_into()?),
+            CliSub::Notification(notification) => Self::Notification(notification.try_into()?),
             CliSub::Server(server) => Self::Server(server.try_into()?),
             CliSub::Mock(mock) => Self::Mock(mock.into()),
             CliSub::Up(up) => Self::Up(up.into()),
@@ -107,8 +111,10 @@ impl SubCmd for Sub {
             Self::Metric(metric) => metric.exec().await,
             Self::Threshold(threshold) => threshold.exec().await,
             Self::Alert(alert) => alert.exec().await,
+            Self::Webhook(webhook) => webhook.exec().await,
             Self::User(user) => user.exec().await,
             Self::Token(token) => token.exec().await,
+            Self::Notification(notification) => notification.exec().await,
             Self::Server(server) => server.exec().await,
             Self::Mock(mock) => mock.exec().await,
             Self::Up(up) => up.exec().await,
@@ -0,0 +1,93 @@
+use bencher_client::types::{JsonDirection, ProjAlertHistorySort};
+use bencher_json::{AlertUuid, ResourceId};
+
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::{
+        project::alert::{CliAlertHistory, CliAlertHistorySort},
+        CliPagination,
+    },
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct History {
+    pub project: ResourceId,
+    pub alert: AlertUuid,
+    pub pagination: Pagination,
+    pub backend: PubBackend,
+}
+
+#[derive(Debug)]
+pub struct Pagination {
+    pub sort: Option<ProjAlertHistorySort>,
+    pub direction: Option<JsonDirection>,
+    pub per_page: Option<u8>,
+    pub page: Option<u32>,
+}
+
+impl TryFrom<CliAlertHistory> for History {
+    type Error = CliError;
+
+    fn try_from(history: CliAlertHistory) -> Result<Self, Self::Error> {
+        let CliAlertHistory {
+            project,
+            alert,
+            pagination,
+            backend,
+        } = history;
+        Ok(Self {
+            project,
+            alert,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliPagination<CliAlertHistorySort>> for Pagination {
+    fn from(pagination: CliPagination<CliAlertHistorySort>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(|sort| match sort {
+                CliAlertHistorySort::Created => ProjAlertHistorySort::Created,
+            }),
+            direction: direction.map(Into::into),
+            page,
+            per_page,
+        }
+    }
+}
+
+impl SubCmd for History {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                let mut client = client
+                    .proj_alert_history_get()
+                    .project(self.project.clone())
+                    .alert(self.alert);
+                if let Some(sort) = self.pagination.sort {
+                    client = client.sort(sort);
+                }
+                if let Some(direction) = self.pagination.direction {
+                    client = client.direction(direction);
+                }
+                if let Some(per_page) = self.pagination.per_page {
+                    client = client.per_page(per_page);
+                }
+                if let Some(page) = self.pagination.page {
+                    client = client.page(page);
+                }
+                client.send().await
+            })
+            .await?;
+        Ok(())
+    }
+}
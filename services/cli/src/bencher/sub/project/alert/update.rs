@@ -1,5 +1,5 @@
 use bencher_client::types::{AlertStatus, JsonUpdateAlert};
-use bencher_json::{AlertUuid, ResourceId};
+use bencher_json::{AlertUuid, DateTime, ResourceId};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
@@ -12,6 +12,9 @@ pub struct Update {
     pub project: ResourceId,
     pub alert: AlertUuid,
     pub status: Option<AlertStatus>,
+    pub assignee: Option<ResourceId>,
+    pub silenced_until: Option<DateTime>,
+    pub silenced_version: Option<u32>,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +26,18 @@ impl TryFrom<CliAlertUpdate> for Update {
             project,
             alert,
             status,
+            assignee,
+            silenced_until,
+            silenced_version,
             backend,
         } = create;
         Ok(Self {
             project,
             alert,
             status: status.map(Into::into),
+            assignee,
+            silenced_until,
+            silenced_version,
             backend: backend.try_into()?,
         })
     }
@@ -39,14 +48,28 @@ impl From<CliAlertStatus> for AlertStatus {
         match status {
             CliAlertStatus::Active => Self::Active,
             CliAlertStatus::Dismissed => Self::Dismissed,
+            CliAlertStatus::Acknowledged => Self::Acknowledged,
+            CliAlertStatus::Silenced => Self::Silenced,
+            CliAlertStatus::Resolved => Self::Resolved,
         }
     }
 }
 
 impl From<Update> for JsonUpdateAlert {
     fn from(update: Update) -> Self {
-        let Update { status, .. } = update;
-        Self { status }
+        let Update {
+            status,
+            assignee,
+            silenced_until,
+            silenced_version,
+            ..
+        } = update;
+        Self {
+            status,
+            assignee: assignee.map(Into::into),
+            silenced_until: silenced_until.map(Into::into),
+            silenced_version: silenced_version.map(Into::into),
+        }
     }
 }
 
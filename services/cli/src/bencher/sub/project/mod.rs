@@ -11,3 +11,4 @@ pub mod report;
 pub mod run;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
@@ -101,6 +101,8 @@ impl From<Create> for JsonNewReport {
                 adapter,
                 average,
                 fold,
+                csv: None,
+                go_strip_cpu: None,
             }),
         }
     }
@@ -12,6 +12,7 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::Cpp => Self::Cpp,
             CliRunAdapter::CppCatch2 => Self::CppCatch2,
             CliRunAdapter::CppGoogle => Self::CppGoogle,
+            CliRunAdapter::CppGoogleJson => Self::CppGoogleJson,
             CliRunAdapter::Go => Self::Go,
             CliRunAdapter::GoBench => Self::GoBench,
             CliRunAdapter::Java => Self::Java,
@@ -29,8 +30,19 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::RustCriterion => Self::RustCriterion,
             CliRunAdapter::RustIai => Self::RustIai,
             CliRunAdapter::RustIaiCallgrind => Self::RustIaiCallgrind,
+            CliRunAdapter::RustDivan => Self::RustDivan,
             CliRunAdapter::Shell => Self::Shell,
             CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
+            CliRunAdapter::ShellPerfStat => Self::ShellPerfStat,
+            CliRunAdapter::Test => Self::Test,
+            CliRunAdapter::TestJunit => Self::TestJunit,
+            CliRunAdapter::TestTap => Self::TestTap,
+            CliRunAdapter::Csv => Self::Csv,
+            CliRunAdapter::OpenMetrics => Self::OpenMetrics,
+            CliRunAdapter::Load => Self::Load,
+            CliRunAdapter::LoadK6 => Self::LoadK6,
+            CliRunAdapter::LoadWrk => Self::LoadWrk,
+            CliRunAdapter::LoadVegeta => Self::LoadVegeta,
         }
     }
 }
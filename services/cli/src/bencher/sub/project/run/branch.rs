@@ -9,6 +9,7 @@ use super::BENCHER_BRANCH;
 pub struct Branch {
     branch: NameId,
     hash: Option<GitHash>,
+    explicit_hash: bool,
     start_point: Option<StartPoint>,
 }
 
@@ -38,17 +39,30 @@ impl TryFrom<CliRunBranch> for Branch {
             deprecated: _,
         } = run_branch;
         let branch = try_branch(branch)?;
+        // A hash is explicit if it was provided or if finding a hash was disabled
+        let explicit_hash = hash.hash.is_some() || hash.no_hash;
         let hash = map_hash(hash);
         let start_point =
             map_start_point(branch_start_point, branch_start_point_hash, branch_reset);
         Ok(Self {
             branch,
             hash,
+            explicit_hash,
             start_point,
         })
     }
 }
 
+impl Branch {
+    // Use the `git` hash suggested by the benchmark harness
+    // if one was not explicitly provided.
+    pub fn suggest_hash(&mut self, hash: GitHash) {
+        if !self.explicit_hash {
+            self.hash = Some(hash);
+        }
+    }
+}
+
 fn try_branch(branch: Option<NameId>) -> Result<NameId, BranchError> {
     if let Some(branch) = branch {
         Ok(branch)
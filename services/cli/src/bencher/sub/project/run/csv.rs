@@ -0,0 +1,98 @@
+use bencher_client::types::{JsonCsvDelimiter, JsonCsvMeasure, JsonCsvSettings};
+use bencher_json::NameId;
+
+use crate::parser::project::run::{CliRunCsv, CliRunCsvDelimiter};
+
+#[derive(Debug, Clone, Default)]
+pub struct Csv(Option<JsonCsvSettings>);
+
+#[derive(thiserror::Error, Debug)]
+pub enum CsvError {
+    #[error(
+        "Failed to parse CSV measure ({0}). Expected `MEASURE=VALUE[,LOWER_VALUE[,UPPER_VALUE]]`."
+    )]
+    ParseMeasure(String),
+    #[error("Failed to parse CSV measure name, slug, or UUID ({measure}): {err}")]
+    ParseMeasureNameId {
+        measure: String,
+        err: bencher_json::ValidError,
+    },
+}
+
+impl TryFrom<CliRunCsv> for Csv {
+    type Error = CsvError;
+
+    fn try_from(csv: CliRunCsv) -> Result<Self, Self::Error> {
+        let CliRunCsv {
+            delimiter,
+            header,
+            benchmark,
+            measure,
+        } = csv;
+        if delimiter.is_none() && header.is_none() && benchmark.is_none() && measure.is_none() {
+            return Ok(Self(None));
+        }
+        let measures = if let Some(measure) = measure {
+            Some(
+                measure
+                    .into_iter()
+                    .map(parse_measure)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
+        } else {
+            None
+        };
+        Ok(Self(Some(JsonCsvSettings {
+            delimiter: delimiter.map(Into::into),
+            header,
+            benchmark,
+            measures,
+        })))
+    }
+}
+
+// `MEASURE=VALUE[,LOWER_VALUE[,UPPER_VALUE]]`
+fn parse_measure(csv_measure: String) -> Result<JsonCsvMeasure, CsvError> {
+    let Some((measure, columns)) = csv_measure.split_once('=') else {
+        return Err(CsvError::ParseMeasure(csv_measure));
+    };
+    let measure: NameId = measure
+        .parse()
+        .map_err(|err| CsvError::ParseMeasureNameId {
+            measure: measure.to_owned(),
+            err,
+        })?;
+    let mut columns = columns.split(',').map(|column| {
+        let column = column.trim();
+        (!column.is_empty()).then(|| column.to_owned())
+    });
+    let Some(Some(value)) = columns.next() else {
+        return Err(CsvError::ParseMeasure(csv_measure));
+    };
+    let lower_value = columns.next().flatten();
+    let upper_value = columns.next().flatten();
+    if columns.next().is_some() {
+        return Err(CsvError::ParseMeasure(csv_measure));
+    }
+    Ok(JsonCsvMeasure {
+        measure: measure.into(),
+        value,
+        lower_value,
+        upper_value,
+    })
+}
+
+impl From<CliRunCsvDelimiter> for JsonCsvDelimiter {
+    fn from(delimiter: CliRunCsvDelimiter) -> Self {
+        match delimiter {
+            CliRunCsvDelimiter::Comma => Self::Comma,
+            CliRunCsvDelimiter::Tab => Self::Tab,
+        }
+    }
+}
+
+impl From<Csv> for Option<JsonCsvSettings> {
+    fn from(csv: Csv) -> Self {
+        csv.0
+    }
+}
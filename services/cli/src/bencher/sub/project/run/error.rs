@@ -15,6 +15,8 @@ pub enum RunError {
     Branch(#[from] super::branch::BranchError),
     #[error("{0}")]
     Testbed(#[from] super::testbed::TestbedError),
+    #[error("{0}")]
+    Csv(#[from] super::csv::CsvError),
 
     #[error("No default shell command path for target family. Try setting a custom shell with the `--shell` argument.")]
     Shell,
@@ -58,6 +60,27 @@ pub enum RunError {
     },
     #[error("Failed to read from output file: {0}")]
     OutputFileRead(std::io::Error),
+    #[error("Failed to read from output directory: {0}")]
+    OutputDirRead(std::io::Error),
+    #[error("Failed to find any `criterion` estimates in output directory: {0}")]
+    NoCriterionEstimates(camino::Utf8PathBuf),
+    #[error("Failed to parse `criterion` estimates in output directory ({path}): {err}")]
+    CriterionEstimates {
+        path: camino::Utf8PathBuf,
+        err: bencher_adapter::AdapterError,
+    },
+    #[error("Failed to parse `criterion` output in directory ({path}): {err}")]
+    CriterionOutput {
+        path: camino::Utf8PathBuf,
+        err: bencher_adapter::ParseError,
+    },
+    #[error("Failed to use `criterion` output directory ({path}) with the {adapter:?} adapter. Use the `json` or `magic` adapter instead.")]
+    CriterionEstimatesAdapter {
+        path: camino::Utf8PathBuf,
+        adapter: bencher_client::types::Adapter,
+    },
+    #[error("Failed to serialize `criterion` estimates results: {0}")]
+    SerializeCriterionEstimates(serde_json::Error),
     #[error("Failed to parse the output file name: {0}")]
     OutputFileName(bencher_json::ValidError),
     #[error("Failed to read size of output file: {0}")]
@@ -69,6 +92,8 @@ pub enum RunError {
     SerializeReport(serde_json::Error),
     #[error("Failed to create new report: {0}")]
     SendReport(crate::bencher::BackendError),
+    #[error("Failed to parse benchmark results:\n{0}")]
+    ParseResults(bencher_adapter::ParseError),
     #[error("Failed to get console URL: {0}")]
     GetEndpoint(crate::bencher::BackendError),
     #[error("Invalid console URL: {0}")]
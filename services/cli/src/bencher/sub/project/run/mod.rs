@@ -1,13 +1,20 @@
 use std::{future::Future, pin::Pin};
 
-use bencher_client::types::{Adapter, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings};
+use bencher_adapter::{
+    adapters::python::pytest::{AdapterPythonPytest, PytestContext},
+    ParseError,
+};
+use bencher_client::{
+    types::{Adapter, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings},
+    ClientError, ErrorResponse,
+};
 use bencher_comment::ReportComment;
 use bencher_json::{DateTime, JsonConsole, JsonReport, ResourceId};
 use clap::ValueEnum;
 use url::Url;
 
 use crate::{
-    bencher::backend::AuthBackend,
+    bencher::{backend::AuthBackend, BackendError},
     cli_eprintln_quietable, cli_println, cli_println_quietable,
     parser::project::run::{CliRun, CliRunAdapter},
     CliError,
@@ -17,6 +24,7 @@ mod adapter;
 mod average;
 mod branch;
 mod ci;
+mod csv;
 mod error;
 mod fold;
 pub mod runner;
@@ -24,6 +32,7 @@ mod testbed;
 
 use branch::Branch;
 use ci::Ci;
+use csv::Csv;
 pub use error::RunError;
 use runner::Runner;
 use testbed::Testbed;
@@ -46,6 +55,8 @@ pub struct Run {
     average: Option<JsonAverage>,
     iter: usize,
     fold: Option<JsonFold>,
+    csv: Csv,
+    go_strip_cpu: bool,
     backdate: Option<DateTime>,
     allow_failure: bool,
     err: bool,
@@ -70,6 +81,8 @@ impl TryFrom<CliRun> for Run {
             average,
             iter,
             fold,
+            csv,
+            go_strip_cpu,
             backdate,
             allow_failure,
             err,
@@ -87,6 +100,8 @@ impl TryFrom<CliRun> for Run {
             average: average.map(Into::into),
             iter: iter.unwrap_or(1),
             fold: fold.map(Into::into),
+            csv: csv.try_into().map_err(RunError::Csv)?,
+            go_strip_cpu,
             backdate,
             allow_failure,
             err,
@@ -164,11 +179,7 @@ impl Run {
         let sender = report_sender(self.project.clone(), json_new_report);
         // If we are not doing complex output logging then we don't need to a strict deserialization.
         if !self.log {
-            let json_report = self
-                .backend
-                .send(sender)
-                .await
-                .map_err(RunError::SendReport)?;
+            let json_report = self.backend.send(sender).await.map_err(send_report_error)?;
             return serde_json::to_string_pretty(&json_report)
                 .map(|json| cli_println!("{json}"))
                 .map_err(RunError::SerializeReport);
@@ -179,12 +190,17 @@ impl Run {
             .backend
             .send_with(sender)
             .await
-            .map_err(RunError::SendReport)?;
+            .map_err(send_report_error)?;
         if let Ok(json) = serde_json::to_string_pretty(&json_report) {
             cli_println!("{json}");
         }
 
-        let alerts_count = json_report.alerts.len();
+        // Acknowledged and silenced alerts do not fail the run
+        let alerts_count = json_report
+            .alerts
+            .iter()
+            .filter(|alert| alert.status.is_active())
+            .count();
         self.display_results(json_report).await?;
 
         if self.err && alerts_count > 0 {
@@ -198,7 +214,10 @@ impl Run {
         let start_time = DateTime::now();
         let mut results = Vec::with_capacity(self.iter);
         for _ in 0..self.iter {
-            let output = self.runner.run(self.log).await?;
+            let output = self
+                .runner
+                .run(self.log, self.adapter, self.average)
+                .await?;
             if output.is_success() {
                 results.push(output.result());
             } else if self.allow_failure {
@@ -225,12 +244,23 @@ impl Run {
             (start_time, end_time)
         };
 
-        let (branch, hash, start_point) = self.branch.clone().into();
+        let mut branch = self.branch.clone();
+        let mut testbed = self.testbed.clone();
+        if let Some(context) = self.pytest_context(&results) {
+            if let Some(hash) = context.hash {
+                branch.suggest_hash(hash);
+            }
+            if let Some(suggested_testbed) = context.testbed {
+                testbed.suggest(suggested_testbed);
+            }
+        }
+
+        let (branch, hash, start_point) = branch.into();
         Ok(Some(JsonNewReport {
             branch,
             hash,
             start_point,
-            testbed: self.testbed.clone().into(),
+            testbed: testbed.into(),
             start_time: start_time.into(),
             end_time: end_time.into(),
             results,
@@ -238,10 +268,23 @@ impl Run {
                 adapter: self.adapter,
                 average: self.average,
                 fold: self.fold,
+                csv: self.csv.clone().into(),
+                go_strip_cpu: self.go_strip_cpu.then_some(true),
             }),
         }))
     }
 
+    // `pytest-benchmark` records the machine and commit that it was run on
+    fn pytest_context(&self, results: &[String]) -> Option<PytestContext> {
+        match self.adapter {
+            None | Some(Adapter::Magic | Adapter::Python | Adapter::PythonPytest) => {},
+            Some(_) => return None,
+        }
+        results
+            .iter()
+            .find_map(|result| AdapterPythonPytest::context(result))
+    }
+
     async fn display_results(&self, json_report: JsonReport) -> Result<(), RunError> {
         let json_console: JsonConsole = self
             .backend
@@ -293,3 +336,20 @@ fn report_sender(
         })
     })
 }
+
+// The API returns the reason the adapter failed to parse the results as JSON
+fn send_report_error(err: BackendError) -> RunError {
+    if let BackendError::Client(ClientError::ErrorResponse(ErrorResponse {
+        error_code: Some(error_code),
+        message,
+        ..
+    })) = &err
+    {
+        if error_code == ParseError::ERROR_CODE {
+            if let Ok(parse_error) = serde_json::from_str(message) {
+                return RunError::ParseResults(parse_error);
+            }
+        }
+    }
+    RunError::SendReport(err)
+}
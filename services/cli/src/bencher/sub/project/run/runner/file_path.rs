@@ -1,9 +1,20 @@
 use std::fmt;
 
-use camino::Utf8PathBuf;
+use bencher_adapter::{
+    adapters::rust::criterion_estimates::{
+        CriterionEstimates, BENCHMARK_FILE_NAME, ESTIMATES_FILE_NAME, SAMPLE_FILE_NAME,
+    },
+    Settings,
+};
+use bencher_client::types::{Adapter, JsonAverage};
+use bencher_json::project::report::JsonAverage as AdapterAverage;
+use camino::{Utf8Path, Utf8PathBuf};
 
 use crate::RunError;
 
+// `criterion` saves the results for the latest run in a `new` directory
+const CRITERION_NEW_DIR: &str = "new";
+
 #[derive(Debug, Clone)]
 pub struct FilePath(Utf8PathBuf);
 
@@ -18,7 +29,99 @@ impl FilePath {
         Self(file_path)
     }
 
-    pub fn get_results(&self) -> Result<String, RunError> {
-        std::fs::read_to_string(&self.0).map_err(RunError::OutputFileRead)
+    pub fn get_results(
+        &self,
+        adapter: Option<Adapter>,
+        average: Option<JsonAverage>,
+    ) -> Result<String, RunError> {
+        if self.0.is_dir() {
+            self.get_criterion_results(adapter, average)
+        } else {
+            std::fs::read_to_string(&self.0).map_err(RunError::OutputFileRead)
+        }
+    }
+
+    // A `criterion` output directory, usually `target/criterion`
+    fn get_criterion_results(
+        &self,
+        adapter: Option<Adapter>,
+        average: Option<JsonAverage>,
+    ) -> Result<String, RunError> {
+        // The estimates are converted to Bencher Metric Format JSON,
+        // which only the JSON and Magic adapters can parse
+        match adapter {
+            None | Some(Adapter::Magic | Adapter::Json) => {},
+            Some(adapter) => {
+                return Err(RunError::CriterionEstimatesAdapter {
+                    path: self.0.clone(),
+                    adapter,
+                })
+            },
+        }
+
+        let mut new_dirs = Vec::new();
+        find_criterion_new_dirs(&self.0, &mut new_dirs)?;
+
+        let mut criterion_estimates = CriterionEstimates::default();
+        for new_dir in new_dirs {
+            let benchmark = std::fs::read_to_string(new_dir.join(BENCHMARK_FILE_NAME))
+                .map_err(RunError::OutputFileRead)?;
+            let estimates = std::fs::read_to_string(new_dir.join(ESTIMATES_FILE_NAME))
+                .map_err(RunError::OutputFileRead)?;
+            // The raw sample is optional, as it is only used for the sample distribution
+            let sample_path = new_dir.join(SAMPLE_FILE_NAME);
+            let sample = sample_path
+                .is_file()
+                .then(|| std::fs::read_to_string(sample_path))
+                .transpose()
+                .map_err(RunError::OutputFileRead)?;
+            criterion_estimates
+                .push(&benchmark, &estimates, sample.as_deref())
+                .map_err(|err| RunError::CriterionOutput {
+                    path: new_dir.clone(),
+                    err,
+                })?;
+        }
+
+        let settings = Settings::new(average.map(|average| match average {
+            JsonAverage::Mean => AdapterAverage::Mean,
+            JsonAverage::Median => AdapterAverage::Median,
+        }));
+        let results = criterion_estimates
+            .convert(&settings)
+            .map_err(|err| RunError::CriterionEstimates {
+                path: self.0.clone(),
+                err,
+            })?
+            .ok_or_else(|| RunError::NoCriterionEstimates(self.0.clone()))?;
+        serde_json::to_string(&results).map_err(RunError::SerializeCriterionEstimates)
+    }
+}
+
+fn find_criterion_new_dirs(
+    dir: &Utf8Path,
+    new_dirs: &mut Vec<Utf8PathBuf>,
+) -> Result<(), RunError> {
+    let mut entries = dir
+        .read_dir_utf8()
+        .map_err(RunError::OutputDirRead)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(RunError::OutputDirRead)?;
+    // Keep the benchmark order stable across runs
+    entries.sort_by(|a, b| a.path().cmp(b.path()));
+    for entry in entries {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if entry.file_name() == CRITERION_NEW_DIR
+            && path.join(BENCHMARK_FILE_NAME).is_file()
+            && path.join(ESTIMATES_FILE_NAME).is_file()
+        {
+            new_dirs.push(path.to_path_buf());
+        } else {
+            find_criterion_new_dirs(path, new_dirs)?;
+        }
     }
+    Ok(())
 }
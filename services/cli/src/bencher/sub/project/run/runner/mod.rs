@@ -1,5 +1,7 @@
 use std::fmt;
 
+use bencher_client::types::{Adapter, JsonAverage};
+
 use crate::parser::project::run::CliRunCommand;
 
 pub mod command;
@@ -93,13 +95,18 @@ impl fmt::Display for Runner {
 }
 
 impl Runner {
-    pub async fn run(&self, log: bool) -> Result<Output, RunError> {
+    pub async fn run(
+        &self,
+        log: bool,
+        adapter: Option<Adapter>,
+        average: Option<JsonAverage>,
+    ) -> Result<Output, RunError> {
         Ok(match self {
             Self::Pipe(pipe) => pipe.output(),
             Self::Command(command) => command.run(log).await?,
             Self::CommandToFile(command, file_path) => {
                 let mut output = command.run(log).await?;
-                let results = file_path.get_results()?;
+                let results = file_path.get_results(adapter, average)?;
                 output.result = Some(results);
                 output
             },
@@ -110,7 +117,7 @@ impl Runner {
                 output
             },
             Self::File(file_path) => {
-                let results = file_path.get_results()?;
+                let results = file_path.get_results(adapter, average)?;
                 Output {
                     result: Some(results),
                     ..Default::default()
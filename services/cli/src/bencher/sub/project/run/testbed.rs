@@ -3,7 +3,10 @@ use bencher_json::{project::testbed::TESTBED_LOCALHOST_STR, NameId};
 use super::BENCHER_TESTBED;
 
 #[derive(Debug, Clone)]
-pub struct Testbed(pub NameId);
+pub struct Testbed {
+    testbed: NameId,
+    explicit: bool,
+}
 
 #[derive(thiserror::Error, Debug)]
 pub enum TestbedError {
@@ -15,23 +18,42 @@ impl TryFrom<Option<NameId>> for Testbed {
     type Error = TestbedError;
 
     fn try_from(testbed: Option<NameId>) -> Result<Self, Self::Error> {
-        Ok(Testbed(if let Some(testbed) = testbed {
-            testbed
+        Ok(if let Some(testbed) = testbed {
+            Self {
+                testbed,
+                explicit: true,
+            }
         } else if let Ok(env_testbed) = std::env::var(BENCHER_TESTBED) {
-            env_testbed
-                .as_str()
-                .parse()
-                .map_err(TestbedError::ParseTestbed)?
+            Self {
+                testbed: env_testbed
+                    .as_str()
+                    .parse()
+                    .map_err(TestbedError::ParseTestbed)?,
+                explicit: true,
+            }
         } else {
-            TESTBED_LOCALHOST_STR
-                .parse()
-                .map_err(TestbedError::ParseTestbed)?
-        }))
+            Self {
+                testbed: TESTBED_LOCALHOST_STR
+                    .parse()
+                    .map_err(TestbedError::ParseTestbed)?,
+                explicit: false,
+            }
+        })
+    }
+}
+
+impl Testbed {
+    // Use the testbed suggested by the benchmark harness
+    // if one was not explicitly provided.
+    pub fn suggest(&mut self, testbed: NameId) {
+        if !self.explicit {
+            self.testbed = testbed;
+        }
     }
 }
 
 impl From<Testbed> for bencher_client::types::NameId {
     fn from(testbed: Testbed) -> Self {
-        testbed.0.into()
+        testbed.testbed.into()
     }
 }
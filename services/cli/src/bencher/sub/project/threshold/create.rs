@@ -1,5 +1,5 @@
-use bencher_client::types::JsonNewThreshold;
-use bencher_json::{NameId, ResourceId};
+use bencher_client::types::{JsonNewSingleThreshold, JsonNewThreshold};
+use bencher_json::{BenchmarkSelector, NameId, ResourceId};
 
 use super::model::Model;
 use crate::{
@@ -17,6 +17,7 @@ pub struct Create {
     pub branch: NameId,
     pub testbed: NameId,
     pub measure: NameId,
+    pub benchmark: Option<BenchmarkSelector>,
     pub model: Model,
     pub backend: AuthBackend,
 }
@@ -38,6 +39,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            benchmark,
             model,
             backend,
         } = create;
@@ -46,6 +48,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            benchmark,
             model: model.try_into()?,
             backend: backend.try_into()?,
         })
@@ -71,6 +74,7 @@ impl From<Create> for JsonNewThreshold {
             testbed,
             model,
             measure,
+            benchmark,
             ..
         } = create;
         let Model {
@@ -80,18 +84,23 @@ impl From<Create> for JsonNewThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         } = model;
-        Self {
+        Self::SingleThreshold(JsonNewSingleThreshold {
             branch: branch.into(),
             testbed: testbed.into(),
             measure: measure.into(),
+            benchmark: benchmark.map(Into::into),
             test,
             min_sample_size,
             max_sample_size,
             window,
             lower_boundary,
             upper_boundary,
-        }
+            level_smoothing,
+            trend_smoothing,
+        })
     }
 }
 
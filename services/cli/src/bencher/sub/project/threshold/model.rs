@@ -1,4 +1,4 @@
-use bencher_client::types::{Boundary, ModelTest, SampleSize, Window};
+use bencher_client::types::{Boundary, ModelTest, SampleSize, Smoothing, Window};
 
 use crate::{
     parser::project::threshold::{CliModel, CliModelTest},
@@ -13,6 +13,8 @@ pub struct Model {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub level_smoothing: Option<Smoothing>,
+    pub trend_smoothing: Option<Smoothing>,
 }
 
 impl TryFrom<CliModel> for Model {
@@ -26,6 +28,8 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         } = model;
         bencher_json::Model {
             test: test.into(),
@@ -34,6 +38,8 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         }
         .validate()
         .map_err(CliError::Model)?;
@@ -44,6 +50,8 @@ impl TryFrom<CliModel> for Model {
             window: window.map(Into::into),
             lower_boundary: lower_boundary.map(Into::into),
             upper_boundary: upper_boundary.map(Into::into),
+            level_smoothing: level_smoothing.map(Into::into),
+            trend_smoothing: trend_smoothing.map(Into::into),
         })
     }
 }
@@ -58,6 +66,12 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::Welch => Self::Welch,
+            CliModelTest::MannWhitney => Self::MannWhitney,
+            CliModelTest::ChangePoint => Self::ChangePoint,
+            CliModelTest::Bootstrap => Self::Bootstrap,
+            CliModelTest::Ewma => Self::Ewma,
+            CliModelTest::Holt => Self::Holt,
         }
     }
 }
@@ -72,6 +86,12 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::Welch => Self::Welch,
+            CliModelTest::MannWhitney => Self::MannWhitney,
+            CliModelTest::ChangePoint => Self::ChangePoint,
+            CliModelTest::Bootstrap => Self::Bootstrap,
+            CliModelTest::Ewma => Self::Ewma,
+            CliModelTest::Holt => Self::Holt,
         }
     }
 }
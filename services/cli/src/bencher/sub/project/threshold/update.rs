@@ -45,6 +45,8 @@ impl From<Update> for JsonUpdateThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         } = model;
         #[allow(clippy::inconsistent_struct_constructor)]
         Self {
@@ -54,6 +56,8 @@ impl From<Update> for JsonUpdateThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            level_smoothing,
+            trend_smoothing,
         }
     }
 }
@@ -0,0 +1,81 @@
+use bencher_client::types::{JsonNewWebhook, WebhookEvent};
+use bencher_json::{ResourceId, Secret, Url};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::{CliWebhookCreate, CliWebhookEvent},
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ResourceId,
+    pub url: Url,
+    pub secret: Secret,
+    pub events: Vec<WebhookEvent>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliWebhookCreate) -> Result<Self, Self::Error> {
+        let CliWebhookCreate {
+            project,
+            url,
+            secret,
+            events,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            url,
+            secret,
+            events: events.into_iter().map(Into::into).collect(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliWebhookEvent> for WebhookEvent {
+    fn from(event: CliWebhookEvent) -> Self {
+        match event {
+            CliWebhookEvent::AlertCreated => Self::AlertCreated,
+            CliWebhookEvent::AlertResolved => Self::AlertResolved,
+            CliWebhookEvent::ReportCreated => Self::ReportCreated,
+        }
+    }
+}
+
+impl From<Create> for JsonNewWebhook {
+    fn from(create: Create) -> Self {
+        let Create {
+            url,
+            secret,
+            events,
+            ..
+        } = create;
+        Self {
+            url: url.into(),
+            secret: secret.into(),
+            events,
+        }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_post()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::{ResourceId, WebhookUuid};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ResourceId,
+    pub webhook: WebhookUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliWebhookDelete) -> Result<Self, Self::Error> {
+        let CliWebhookDelete {
+            project,
+            webhook,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            webhook,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_delete()
+                    .project(self.project.clone())
+                    .webhook(self.webhook)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use bencher_client::types::{JsonDirection, ProjWebhookDeliveriesSort};
+use bencher_json::{ResourceId, WebhookUuid};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::{
+        project::webhook::{CliWebhookDeliveries, CliWebhookDeliveriesSort},
+        CliPagination,
+    },
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Deliveries {
+    pub project: ResourceId,
+    pub webhook: WebhookUuid,
+    pub pagination: Pagination,
+    pub backend: AuthBackend,
+}
+
+#[derive(Debug)]
+pub struct Pagination {
+    pub sort: Option<ProjWebhookDeliveriesSort>,
+    pub direction: Option<JsonDirection>,
+    pub per_page: Option<u8>,
+    pub page: Option<u32>,
+}
+
+impl TryFrom<CliWebhookDeliveries> for Deliveries {
+    type Error = CliError;
+
+    fn try_from(deliveries: CliWebhookDeliveries) -> Result<Self, Self::Error> {
+        let CliWebhookDeliveries {
+            project,
+            webhook,
+            pagination,
+            backend,
+        } = deliveries;
+        Ok(Self {
+            project,
+            webhook,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliPagination<CliWebhookDeliveriesSort>> for Pagination {
+    fn from(pagination: CliPagination<CliWebhookDeliveriesSort>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(|sort| match sort {
+                CliWebhookDeliveriesSort::Created => ProjWebhookDeliveriesSort::Created,
+            }),
+            direction: direction.map(Into::into),
+            page,
+            per_page,
+        }
+    }
+}
+
+impl SubCmd for Deliveries {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                let mut client = client
+                    .proj_webhook_deliveries_get()
+                    .project(self.project.clone())
+                    .webhook(self.webhook);
+                if let Some(sort) = self.pagination.sort {
+                    client = client.sort(sort);
+                }
+                if let Some(direction) = self.pagination.direction {
+                    client = client.direction(direction);
+                }
+                if let Some(per_page) = self.pagination.per_page {
+                    client = client.per_page(per_page);
+                }
+                if let Some(page) = self.pagination.page {
+                    client = client.page(page);
+                }
+                client.send().await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{bencher::sub::SubCmd, parser::project::webhook::CliWebhook, CliError};
+
+mod create;
+mod delete;
+mod deliveries;
+mod list;
+mod test;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Webhook {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Update(update::Update),
+    Delete(delete::Delete),
+    Test(test::Test),
+    Deliveries(deliveries::Deliveries),
+}
+
+impl TryFrom<CliWebhook> for Webhook {
+    type Error = CliError;
+
+    fn try_from(webhook: CliWebhook) -> Result<Self, Self::Error> {
+        Ok(match webhook {
+            CliWebhook::List(list) => Self::List(list.try_into()?),
+            CliWebhook::Create(create) => Self::Create(create.try_into()?),
+            CliWebhook::View(view) => Self::View(view.try_into()?),
+            CliWebhook::Update(update) => Self::Update(update.try_into()?),
+            CliWebhook::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliWebhook::Test(test) => Self::Test(test.try_into()?),
+            CliWebhook::Deliveries(deliveries) => Self::Deliveries(deliveries.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Webhook {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+            Self::Test(test) => test.exec().await,
+            Self::Deliveries(deliveries) => deliveries.exec().await,
+        }
+    }
+}
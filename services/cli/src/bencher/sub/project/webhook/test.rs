@@ -0,0 +1,48 @@
+use bencher_json::{ResourceId, WebhookUuid};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookTest,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Test {
+    pub project: ResourceId,
+    pub webhook: WebhookUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookTest> for Test {
+    type Error = CliError;
+
+    fn try_from(test: CliWebhookTest) -> Result<Self, Self::Error> {
+        let CliWebhookTest {
+            project,
+            webhook,
+            backend,
+        } = test;
+        Ok(Self {
+            project,
+            webhook,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Test {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_test_post()
+                    .project(self.project.clone())
+                    .webhook(self.webhook)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod notification;
 pub mod token;
 #[allow(clippy::module_inception)]
 pub mod user;
@@ -0,0 +1,34 @@
+use crate::{bencher::sub::SubCmd, parser::user::notification::CliNotification, CliError};
+
+mod list;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Notification {
+    List(list::List),
+    View(view::View),
+    Update(update::Update),
+}
+
+impl TryFrom<CliNotification> for Notification {
+    type Error = CliError;
+
+    fn try_from(notification: CliNotification) -> Result<Self, Self::Error> {
+        Ok(match notification {
+            CliNotification::List(list) => Self::List(list.try_into()?),
+            CliNotification::View(view) => Self::View(view.try_into()?),
+            CliNotification::Update(update) => Self::Update(update.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Notification {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+        }
+    }
+}
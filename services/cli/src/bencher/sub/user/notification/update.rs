@@ -0,0 +1,60 @@
+use bencher_client::types::JsonUpdateNotification;
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::user::notification::CliNotificationUpdate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub user: ResourceId,
+    pub project: ResourceId,
+    pub alert_email: Option<bool>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliNotificationUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliNotificationUpdate) -> Result<Self, Self::Error> {
+        let CliNotificationUpdate {
+            user,
+            project,
+            alert_email,
+            backend,
+        } = update;
+        Ok(Self {
+            user,
+            project,
+            alert_email,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateNotification {
+    fn from(update: Update) -> Self {
+        let Update { alert_email, .. } = update;
+        Self { alert_email }
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .user_notification_patch()
+                    .user(self.user.clone())
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::user::notification::CliNotificationView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub user: ResourceId,
+    pub project: ResourceId,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliNotificationView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliNotificationView) -> Result<Self, Self::Error> {
+        let CliNotificationView {
+            user,
+            project,
+            backend,
+        } = view;
+        Ok(Self {
+            user,
+            project,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .user_notification_get()
+                    .user(self.user.clone())
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
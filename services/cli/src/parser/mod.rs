@@ -14,10 +14,10 @@ use organization::{member::CliMember, CliOrganization};
 use project::{
     alert::CliAlert, benchmark::CliBenchmark, branch::CliBranch, measure::CliMeasure,
     metric::CliMetric, perf::CliPerf, plot::CliPlot, report::CliReport, run::CliRun,
-    testbed::CliTestbed, threshold::CliThreshold, CliProject,
+    testbed::CliTestbed, threshold::CliThreshold, webhook::CliWebhook, CliProject,
 };
 use system::{auth::CliAuth, server::CliServer};
-use user::{token::CliToken, CliUser};
+use user::{notification::CliNotification, token::CliToken, CliUser};
 
 /// Bencher CLI
 #[derive(Parser, Debug)]
@@ -82,6 +82,9 @@ pub enum CliSub {
     /// Manage alerts
     #[clap(subcommand)]
     Alert(CliAlert),
+    /// Manage webhooks
+    #[clap(subcommand)]
+    Webhook(CliWebhook),
 
     /// Manage user
     #[clap(subcommand)]
@@ -89,6 +92,9 @@ pub enum CliSub {
     /// Manage user API tokens
     #[clap(subcommand)]
     Token(CliToken),
+    /// Manage user notification preferences
+    #[clap(subcommand)]
+    Notification(CliNotification),
 
     /// Server commands
     #[clap(subcommand)]
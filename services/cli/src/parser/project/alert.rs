@@ -1,4 +1,4 @@
-use bencher_json::{AlertUuid, ResourceId};
+use bencher_json::{AlertUuid, DateTime, ResourceId};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -14,6 +14,8 @@ pub enum CliAlert {
     // Update an alert
     #[clap(alias = "edit")]
     Update(CliAlertUpdate),
+    /// View the history of an alert
+    History(CliAlertHistory),
     /// View alert stats
     Stats(CliAlertStats),
 }
@@ -63,6 +65,18 @@ pub struct CliAlertUpdate {
     #[clap(long)]
     pub status: Option<CliAlertStatus>,
 
+    /// User slug or UUID to assign an acknowledged alert to
+    #[clap(long)]
+    pub assignee: Option<ResourceId>,
+
+    /// Silence the alert until this time (seconds since epoch)
+    #[clap(long)]
+    pub silenced_until: Option<DateTime>,
+
+    /// Silence the alert until the branch reaches this version number
+    #[clap(long)]
+    pub silenced_version: Option<u32>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -75,6 +89,34 @@ pub enum CliAlertStatus {
     Active,
     /// Dismissed
     Dismissed,
+    /// Acknowledged
+    Acknowledged,
+    /// Silenced
+    Silenced,
+    /// Resolved
+    Resolved,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAlertHistory {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Alert UUID
+    pub alert: AlertUuid,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliAlertHistorySort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAlertHistorySort {
+    /// Creation date time of the alert event
+    Created,
 }
 
 #[derive(Parser, Debug)]
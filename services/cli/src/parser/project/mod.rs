@@ -16,6 +16,7 @@ pub mod report;
 pub mod run;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 #[derive(Subcommand, Debug)]
 pub enum CliProject {
@@ -35,6 +35,14 @@ pub struct CliRun {
     #[clap(value_enum, long, requires = "iter")]
     pub fold: Option<CliRunFold>,
 
+    /// CSV adapter settings
+    #[clap(flatten)]
+    pub csv: CliRunCsv,
+
+    /// Strip the `-cpu` suffix (ie `BenchmarkFoo-8`) from Go benchmark names
+    #[clap(long)]
+    pub go_strip_cpu: bool,
+
     /// Backdate the report (seconds since epoch)
     /// NOTE: This will NOT effect the ordering of past reports
     #[clap(long)]
@@ -44,7 +52,7 @@ pub struct CliRun {
     #[clap(long)]
     pub allow_failure: bool,
 
-    /// Error on alert
+    /// Error on any active alert
     #[clap(long)]
     pub err: bool,
 
@@ -118,7 +126,10 @@ pub struct CliRunHash {
 
 #[derive(Args, Debug)]
 pub struct CliRunCommand {
-    /// Benchmark command output file path
+    /// Benchmark command output file path.
+    /// If a `criterion` output directory (ie `target/criterion`) is given,
+    /// then the saved `criterion` estimates will be used as the results
+    /// with the `json` or `magic` adapter
     #[clap(long, conflicts_with = "file_size")]
     pub file: Option<Utf8PathBuf>,
 
@@ -172,6 +183,8 @@ pub enum CliRunAdapter {
     CppCatch2,
     /// ➕ C++ Google
     CppGoogle,
+    /// ➕ C++ Google JSON
+    CppGoogleJson,
     /// 🕳 Go
     Go,
     /// 🕳 Go Bench
@@ -206,10 +219,60 @@ pub enum CliRunAdapter {
     RustIai,
     /// 🦀 Rust Iai-Callgrind
     RustIaiCallgrind,
+    /// 🦀 Rust Divan
+    RustDivan,
     /// ❯_ Shell
     Shell,
     /// ❯_ Shell Hyperfine
     ShellHyperfine,
+    /// ❯_ Shell perf stat
+    ShellPerfStat,
+    /// 🧪 Test
+    Test,
+    /// 🧪 Test `JUnit`
+    TestJunit,
+    /// 🧪 Test TAP
+    TestTap,
+    /// 📊 CSV
+    Csv,
+    /// 📈 `OpenMetrics`
+    #[clap(alias = "openmetrics")]
+    OpenMetrics,
+    /// 🚦 Load
+    Load,
+    /// 🚦 Load k6
+    LoadK6,
+    /// 🚦 Load wrk
+    LoadWrk,
+    /// 🚦 Load Vegeta
+    LoadVegeta,
+}
+
+#[derive(Args, Debug)]
+pub struct CliRunCsv {
+    /// CSV column delimiter (default is detected from the first row)
+    #[clap(value_enum, long = "csv-delimiter")]
+    pub delimiter: Option<CliRunCsvDelimiter>,
+    /// Whether the first CSV row is a header (default is detected from the first row)
+    #[clap(long = "csv-header")]
+    pub header: Option<bool>,
+    /// CSV benchmark name column, as a header name or zero-based index (default is the first column)
+    #[clap(long = "csv-benchmark")]
+    pub benchmark: Option<String>,
+    /// Map CSV columns to a Measure as `MEASURE=VALUE[,LOWER_VALUE[,UPPER_VALUE]]`,
+    /// with each column as a header name or zero-based index (default is every other column)
+    #[clap(long = "csv-measure")]
+    pub measure: Option<Vec<String>>,
+}
+
+/// CSV Column Delimiters
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunCsvDelimiter {
+    /// Comma separated values
+    Comma,
+    /// Tab separated values
+    Tab,
 }
 
 /// Suggested Central Tendency (Average)
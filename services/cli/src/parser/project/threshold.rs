@@ -1,4 +1,7 @@
-use bencher_json::{Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window};
+use bencher_json::{
+    BenchmarkSelector, Boundary, ModelUuid, NameId, ResourceId, SampleSize, Smoothing,
+    ThresholdUuid, Window,
+};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -72,6 +75,10 @@ pub struct CliThresholdCreate {
     #[clap(long)]
     pub measure: NameId,
 
+    /// Only apply to benchmarks matching this exact name, glob, or `/regex/`
+    #[clap(long)]
+    pub benchmark: Option<BenchmarkSelector>,
+
     #[clap(flatten)]
     pub model: CliModel,
 
@@ -118,6 +125,14 @@ pub struct CliModel {
     /// Upper boundary
     #[clap(long)]
     pub upper_boundary: Option<Boundary>,
+
+    /// Level smoothing factor (EWMA and Holt only)
+    #[clap(long)]
+    pub level_smoothing: Option<Smoothing>,
+
+    /// Trend smoothing factor (Holt only)
+    #[clap(long)]
+    pub trend_smoothing: Option<Smoothing>,
 }
 
 /// Supported threshold model tests
@@ -140,6 +155,23 @@ pub enum CliModelTest {
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Welch's t-test (two-sample)
+    #[clap(alias = "welch_t")]
+    Welch,
+    /// Mann-Whitney U test (two-sample)
+    #[clap(alias = "mann_whitney_u")]
+    MannWhitney,
+    /// Change point detection (CUSUM)
+    #[clap(alias = "cusum")]
+    ChangePoint,
+    /// Bootstrap percentiles (non-parametric)
+    Bootstrap,
+    /// Exponentially weighted moving average (EWMA)
+    #[clap(alias = "exponentially_weighted")]
+    Ewma,
+    /// Holt linear trend
+    #[clap(alias = "holt_linear")]
+    Holt,
 }
 
 #[derive(Parser, Debug)]
@@ -0,0 +1,162 @@
+use bencher_json::{ResourceId, Secret, Url, WebhookUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliWebhook {
+    /// List webhooks
+    #[clap(alias = "ls")]
+    List(CliWebhookList),
+    /// Create a webhook
+    #[clap(alias = "add")]
+    Create(CliWebhookCreate),
+    /// View a webhook
+    #[clap(alias = "get")]
+    View(CliWebhookView),
+    // Update a webhook
+    #[clap(alias = "edit")]
+    Update(CliWebhookUpdate),
+    /// Delete a webhook
+    #[clap(alias = "rm")]
+    Delete(CliWebhookDelete),
+    /// Send a test delivery to a webhook
+    Test(CliWebhookTest),
+    /// List the deliveries for a webhook
+    Deliveries(CliWebhookDeliveries),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookList {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhooksSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhooksSort {
+    /// Creation date time of the webhook
+    Created,
+    // Modification date time of the webhook
+    Modified,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookCreate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// URL to send webhook deliveries to
+    #[clap(long)]
+    pub url: Url,
+
+    /// Secret used to sign webhook deliveries
+    #[clap(long)]
+    pub secret: Secret,
+
+    /// Events to subscribe to
+    #[clap(long, required = true)]
+    pub events: Vec<CliWebhookEvent>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookEvent {
+    /// A new alert was generated
+    AlertCreated,
+    /// An alert was resolved
+    AlertResolved,
+    /// A new report was created
+    ReportCreated,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookView {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookUpdate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    /// URL to send webhook deliveries to
+    #[clap(long)]
+    pub url: Option<Url>,
+
+    /// Secret used to sign webhook deliveries
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    /// Events to subscribe to, replacing all existing events
+    #[clap(long)]
+    pub events: Option<Vec<CliWebhookEvent>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookTest {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDeliveries {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhookDeliveriesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookDeliveriesSort {
+    /// Creation date time of the webhook delivery
+    Created,
+}
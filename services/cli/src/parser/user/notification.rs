@@ -0,0 +1,64 @@
+use bencher_json::ResourceId;
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliNotification {
+    /// List notification preferences
+    #[clap(alias = "ls")]
+    List(CliNotificationList),
+    /// View notification preferences for a project
+    #[clap(alias = "get")]
+    View(CliNotificationView),
+    // Update notification preferences for a project
+    #[clap(alias = "edit")]
+    Update(CliNotificationUpdate),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationList {
+    /// User slug or UUID
+    pub user: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliNotificationsSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliNotificationsSort {
+    /// Name of the project
+    Name,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationView {
+    /// User slug or UUID
+    pub user: ResourceId,
+
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationUpdate {
+    /// User slug or UUID
+    pub user: ResourceId,
+
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Email the user when a report for the project generates alerts
+    #[clap(long)]
+    pub alert_email: Option<bool>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -9,6 +9,7 @@ import {
 import type { JsonAuthUser } from "../../../../types/bencher";
 import { ActionButton } from "../../../../config/types";
 import DeleteButton from "./DeleteButton";
+import TestButton from "./TestButton";
 import type { Params } from "astro";
 
 export interface Props {
@@ -73,6 +74,15 @@ const DeckButton = (props: Props) => {
 					</div>
 				</div>
 			</Match>
+			<Match when={props.config?.kind === ActionButton.TEST && isAllowed()}>
+				<TestButton
+					apiUrl={props.apiUrl}
+					user={props.user}
+					path={props.path}
+					data={props.data}
+					redirect={props.config.path}
+				/>
+			</Match>
 		</Switch>
 	);
 };
@@ -0,0 +1,96 @@
+import { type Accessor, type Resource, createSignal } from "solid-js";
+import {
+	type JsonAuthUser,
+	type JsonWebhookDelivery,
+	WebhookDeliveryStatus,
+} from "../../../../types/bencher";
+import { httpPost } from "../../../../util/http";
+import {
+	NotifyKind,
+	navigateNotify,
+	pageNotify,
+} from "../../../../util/notify";
+import { pathname } from "../../../../util/url";
+import { validJwt } from "../../../../util/valid";
+import * as Sentry from "@sentry/astro";
+
+export interface Props {
+	apiUrl: string;
+	user: JsonAuthUser;
+	path: Accessor<string>;
+	data: Resource<object>;
+	redirect: (pathname: string, data: object) => string;
+}
+
+const TestButton = (props: Props) => {
+	const [testing, setTesting] = createSignal(false);
+
+	const sendTest = () => {
+		const data = props.data();
+		// This guarantees that the wasm has been loaded
+		if (!data) {
+			return;
+		}
+
+		const token = props.user?.token;
+		if (!validJwt(token)) {
+			return;
+		}
+
+		setTesting(true);
+		httpPost(props.apiUrl, `${props.path()}/test`, token, {})
+			.then((resp) => {
+				setTesting(false);
+				const delivery = resp.data as JsonWebhookDelivery;
+				if (delivery.status === WebhookDeliveryStatus.Delivered) {
+					navigateNotify(
+						NotifyKind.OK,
+						`Hop to it! Test delivery succeeded with status ${delivery.response_status}.`,
+						props.redirect(pathname(), data),
+						null,
+						null,
+					);
+				} else {
+					navigateNotify(
+						NotifyKind.ERROR,
+						`Lettuce romaine calm! Test delivery failed: ${
+							delivery.error ?? delivery.response_status
+						}`,
+						props.redirect(pathname(), data),
+						null,
+						null,
+					);
+				}
+			})
+			.catch((error) => {
+				setTesting(false);
+				console.error(error);
+				Sentry.captureException(error);
+				pageNotify(
+					NotifyKind.ERROR,
+					"Lettuce romaine calm! Failed to send test delivery. Please, try again.",
+				);
+			});
+	};
+
+	return (
+		<div class="buttons is-right">
+			<button
+				class="button is-small"
+				type="button"
+				disabled={testing()}
+				onMouseDown={(e) => {
+					e.preventDefault();
+					sendTest();
+				}}
+			>
+				<span class="icon">
+					<i class="fas fa-paper-plane" />
+				</span>
+				<span>Send Test Delivery</span>
+			</button>
+		</div>
+	);
+};
+
+export default TestButton;
@@ -42,7 +42,7 @@ const ViewCard = (props: Props) => {
 					<div class="field is-expanded">
 						<div class="control">
 							<Show
-								when={props.value}
+								when={props.value || typeof props.value === "boolean"}
 								fallback={
 									<input class="input is-static" type="text" readonly />
 								}
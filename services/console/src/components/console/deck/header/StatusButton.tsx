@@ -27,6 +27,9 @@ const StatusButton = (props: Props) => {
 			case AlertStatus.Active:
 				return { status: AlertStatus.Dismissed };
 			case AlertStatus.Dismissed:
+			case AlertStatus.Acknowledged:
+			case AlertStatus.Silenced:
+			case AlertStatus.Resolved:
 				return { status: AlertStatus.Active };
 			default:
 				console.error("Unknown status");
@@ -90,7 +93,12 @@ const StatusButton = (props: Props) => {
 					<span>Dismiss</span>
 				</button>
 			</Match>
-			<Match when={props.data()?.status === AlertStatus.Dismissed}>
+			<Match
+				when={
+					props.data()?.status &&
+					props.data()?.status !== AlertStatus.Active
+				}
+			>
 				<button
 					class="button is-fullwidth"
 					type="button"
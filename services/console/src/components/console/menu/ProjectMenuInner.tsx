@@ -10,6 +10,7 @@ enum Section {
 	MEASURES = "measures",
 	THRESHOLDS = "thresholds",
 	ALERTS = "alerts",
+	WEBHOOKS = "webhooks",
 	SETTINGS = "settings",
 }
 
@@ -80,6 +81,9 @@ const ProjectMenuInner = (props: {
 						</nav>
 					</a>
 				</li>
+				<li>
+					<a href={path(Section.WEBHOOKS)}>Webhooks</a>
+				</li>
 				<li>
 					<a href={path(Section.SETTINGS)}>Settings</a>
 				</li>
@@ -2,6 +2,7 @@ import type { JsonAuthUser } from "../../../types/bencher";
 
 enum Section {
 	TOKENS = "tokens",
+	NOTIFICATIONS = "notifications",
 	SETTINGS = "settings",
 	HELP = "help",
 }
@@ -20,6 +21,9 @@ const UserMenu = (props: { user?: JsonAuthUser }) => {
 				<li>
 					<a href={path(Section.TOKENS)}>API Tokens</a>
 				</li>
+				<li>
+					<a href={path(Section.NOTIFICATIONS)}>Notifications</a>
+				</li>
 				<li>
 					<a href={path(Section.SETTINGS)}>Settings</a>
 				</li>
@@ -40,6 +40,8 @@ export interface PosterFieldConfig {
 	validate: boolean;
 	config: FieldConfig;
 	nullable?: null | boolean;
+	// Checked checkbox fields with the same group are sent as an array of their keys
+	group?: string;
 }
 
 export type PosterForm = Record<string, PosterField>;
@@ -51,6 +53,7 @@ export interface PosterField {
 	valid: null | boolean;
 	validate: boolean;
 	nullable: undefined | null | boolean;
+	group: undefined | string;
 }
 
 const initForm = (fields: PosterFieldConfig[]) => {
@@ -64,6 +67,7 @@ const initForm = (fields: PosterFieldConfig[]) => {
 				valid: field.valid,
 				validate: field.validate,
 				nullable: field.nullable,
+				group: field.group,
 			};
 		}
 	}
@@ -85,7 +89,7 @@ const Poster = (props: Props) => {
 	const httpOperation = async (
 		path: string,
 		token: string,
-		data: Record<string, undefined | number | string>,
+		data: Record<string, undefined | number | string | string[]>,
 	) => {
 		switch (props.operation) {
 			case Operation.EDIT:
@@ -110,9 +114,15 @@ const Poster = (props: Props) => {
 		}
 
 		setSubmitting(true);
-		const data: Record<string, undefined | number | string> = {};
+		const data: Record<string, undefined | number | string | string[]> = {};
 		for (const key of Object.keys(form)) {
 			const value = form?.[key]?.value;
+			const group = form?.[key]?.group;
+			if (group) {
+				const keys = (data[group] as undefined | string[]) ?? [];
+				data[group] = value ? [...keys, key] : keys;
+				continue;
+			}
 			switch (form?.[key]?.kind) {
 				case FieldKind.SELECT:
 					if (form?.[key]?.nullable && !value?.selected) {
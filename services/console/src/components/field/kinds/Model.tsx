@@ -6,6 +6,7 @@ import {
 	validIqrBoundary,
 	validPercentageBoundary,
 	validSampleSize,
+	validSmoothing,
 	validU32,
 } from "../../../util/valid";
 import type { FieldConfig, FieldHandler, FieldValue } from "../Field";
@@ -92,6 +93,20 @@ const STATISTIC_FIELDS = {
 		help: "Must be any multiplier greater than or equal to zero",
 		validate: validIqrBoundary,
 	},
+	level_smoothing: {
+		type: "input",
+		placeholder: "0.3",
+		icon: "fas fa-wave-square",
+		help: "Must be greater than 0.00 and less than or equal to 1.00 (higher follows recent samples more closely)",
+		validate: validSmoothing,
+	},
+	trend_smoothing: {
+		type: "input",
+		placeholder: "0.1",
+		icon: "fas fa-chart-line",
+		help: "Must be greater than 0.00 and less than or equal to 1.00 (higher follows changes in trend more closely)",
+		validate: validSmoothing,
+	},
 	min_sample_size: {
 		type: "number",
 		placeholder: "30",
@@ -147,6 +162,30 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: "Delta Interquartile Range (ΔIQR)",
 			},
+			{
+				value: ModelTest.Welch,
+				option: "Welch's t-test",
+			},
+			{
+				value: ModelTest.MannWhitney,
+				option: "Mann-Whitney U test",
+			},
+			{
+				value: ModelTest.ChangePoint,
+				option: "Change Point Detection",
+			},
+			{
+				value: ModelTest.Bootstrap,
+				option: "Bootstrap",
+			},
+			{
+				value: ModelTest.Ewma,
+				option: "Exponentially Weighted Moving Average (EWMA)",
+			},
+			{
+				value: ModelTest.Holt,
+				option: "Holt Linear Trend",
+			},
 		],
 	};
 };
@@ -197,6 +236,18 @@ const testFragment = (modelTest: ModelTest) => {
 			return "iqr-thresholds";
 		case ModelTest.DeltaIqr:
 			return "delta-iqr-thresholds";
+		case ModelTest.Welch:
+			return "welchs-t-test-thresholds";
+		case ModelTest.MannWhitney:
+			return "mann-whitney-u-test-thresholds";
+		case ModelTest.ChangePoint:
+			return "change-point-thresholds";
+		case ModelTest.Bootstrap:
+			return "bootstrap-thresholds";
+		case ModelTest.Ewma:
+			return "ewma-thresholds";
+		case ModelTest.Holt:
+			return "holt-linear-trend-thresholds";
 	}
 };
 
@@ -227,6 +278,15 @@ const cdfConfig = (modelTest: ModelTest) => {
 	];
 };
 
+const smoothingConfig = (modelTest: ModelTest) => {
+	const fields = cdfConfig(modelTest);
+	fields.splice(3, 0, LEVEL_SMOOTHING);
+	if (modelTest === ModelTest.Holt) {
+		fields.splice(4, 0, TREND_SMOOTHING);
+	}
+	return fields;
+};
+
 const iqrConfig = (modelTest: ModelTest) => {
 	return [
 		testSelectConfig(modelTest),
@@ -254,6 +314,28 @@ const iqrConfig = (modelTest: ModelTest) => {
 	];
 };
 
+const LEVEL_SMOOTHING = {
+	kind: FieldKind.NUMBER,
+	label: "Level Smoothing",
+	key: "level_smoothing",
+	value: "",
+	valid: true,
+	validate: true,
+	nullable: true,
+	config: STATISTIC_FIELDS.level_smoothing,
+};
+
+const TREND_SMOOTHING = {
+	kind: FieldKind.NUMBER,
+	label: "Trend Smoothing",
+	key: "trend_smoothing",
+	value: "",
+	valid: true,
+	validate: true,
+	nullable: true,
+	config: STATISTIC_FIELDS.trend_smoothing,
+};
+
 const SAMPLE_SIZE = [
 	{
 		kind: FieldKind.NUMBER,
@@ -340,6 +422,12 @@ const FIELDS = {
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.Welch]: cdfConfig(ModelTest.Welch),
+	[ModelTest.MannWhitney]: cdfConfig(ModelTest.MannWhitney),
+	[ModelTest.ChangePoint]: cdfConfig(ModelTest.ChangePoint),
+	[ModelTest.Bootstrap]: cdfConfig(ModelTest.Bootstrap),
+	[ModelTest.Ewma]: smoothingConfig(ModelTest.Ewma),
+	[ModelTest.Holt]: smoothingConfig(ModelTest.Holt),
 };
 
 const initForm = (fields: object[]) => {
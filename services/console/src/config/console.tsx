@@ -10,7 +10,9 @@ import projectsConfig from "./project/projects";
 import reportsConfig from "./project/reports";
 import testbedsConfig from "./project/testbeds";
 import thresholdsConfig from "./project/thresholds";
+import webhooksConfig, { webhookDeliveriesConfig } from "./project/webhooks";
 import { BencherResource } from "./types";
+import notificationsConfig from "./user/notifications";
 import tokensConfig from "./user/tokens";
 import usersConfig from "./user/users";
 
@@ -29,9 +31,12 @@ const consoleConfig = {
 	[BencherResource.METRICS]: metricsConfig,
 	[BencherResource.THRESHOLDS]: thresholdsConfig,
 	[BencherResource.ALERTS]: alertsConfig,
+	[BencherResource.WEBHOOKS]: webhooksConfig,
+	[BencherResource.WEBHOOK_DELIVERIES]: webhookDeliveriesConfig,
 	// User
 	[BencherResource.USERS]: usersConfig,
 	[BencherResource.TOKENS]: tokensConfig,
+	[BencherResource.NOTIFICATIONS]: notificationsConfig,
 };
 
 export default consoleConfig;
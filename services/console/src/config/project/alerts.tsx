@@ -81,6 +81,24 @@ const alertsConfig = {
 					keys: ["status"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Assignee",
+					keys: ["assignee", "name"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Silenced Until",
+					keys: ["silenced_until"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Silenced Until Version",
+					keys: ["silenced_version"],
+					display: Display.RAW,
+				},
 				{
 					kind: Card.NESTED_FIELD,
 					label: "Branch",
@@ -5,6 +5,7 @@ import {
 	isAllowedProjectDelete,
 	isAllowedProjectEdit,
 } from "../../util/auth";
+import { validOptionBenchmarkSelector } from "../../util/valid";
 import { ActionButton, Button, Card, Display, Operation, Row } from "../types";
 import { addPath, createdUuidPath, parentPath, viewUuidPath } from "../util";
 
@@ -48,6 +49,13 @@ const THRESHOLD_FIELDS = {
 		url: (params: undefined | Params, per_page: number, page: number) =>
 			paginationUrl(params, "measures", per_page, page),
 	},
+	benchmark: {
+		type: "text",
+		placeholder: "Benchmark Name, Glob, or /Regex/",
+		icon: "fas fa-tachometer-alt",
+		help: "Must be a benchmark name, a glob with * or ?, or a regular expression wrapped in slashes",
+		validate: validOptionBenchmarkSelector,
+	},
 };
 
 const thresholdsConfig = {
@@ -148,6 +156,16 @@ const thresholdsConfig = {
 					validate: true,
 					config: THRESHOLD_FIELDS.measure,
 				},
+				{
+					kind: FieldKind.INPUT,
+					label: "Benchmark Selector",
+					key: "benchmark",
+					value: "",
+					valid: true,
+					validate: true,
+					nullable: true,
+					config: THRESHOLD_FIELDS.benchmark,
+				},
 				{
 					kind: FieldKind.MODEL,
 					label: null,
@@ -209,6 +227,12 @@ const thresholdsConfig = {
 					keys: ["measure", "name"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.FIELD,
+					label: "Benchmark Selector",
+					key: "benchmark",
+					display: Display.RAW,
+				},
 				{
 					kind: Card.NESTED_FIELD,
 					label: "Threshold Model Test",
@@ -0,0 +1,242 @@
+import type { Params } from "astro";
+import { validNonEmpty, validUrl } from "../../util/valid";
+import {
+	ActionButton,
+	Button,
+	Card,
+	Display,
+	Operation,
+	Row,
+} from "../types";
+import { parentPath, addPath, viewUuidPath, createdUuidPath } from "../util";
+import FieldKind from "../../components/field/kind";
+import { isAllowedProjectManage } from "../../util/auth";
+
+export const WEBHOOK_ICON = "fas fa-paper-plane";
+
+const WEBHOOK_FIELDS = {
+	url: {
+		type: "text",
+		placeholder: "https://example.com/webhook",
+		icon: WEBHOOK_ICON,
+		help: "Must be a valid http or https URL",
+		validate: validUrl,
+	},
+	secret: {
+		type: "password",
+		placeholder: "Webhook Secret",
+		icon: "fas fa-key",
+		help: "Used to sign the X-Bencher-Signature header of each delivery",
+		validate: validNonEmpty,
+	},
+	alert_created: {
+		label: "alert_created",
+		placeholder: "A new alert was generated",
+	},
+	alert_resolved: {
+		label: "alert_resolved",
+		placeholder: "An alert was resolved",
+	},
+	report_created: {
+		label: "report_created",
+		placeholder: "A new report was created",
+	},
+};
+
+const eventField = (event: keyof typeof WEBHOOK_FIELDS, value: boolean) => {
+	return {
+		kind: FieldKind.CHECKBOX,
+		label: event === "alert_created" ? "Events" : "",
+		key: event,
+		value,
+		valid: null,
+		validate: false,
+		config: WEBHOOK_FIELDS[event],
+		group: "events",
+	};
+};
+
+const webhooksConfig = {
+	[Operation.LIST]: {
+		operation: Operation.LIST,
+		header: {
+			title: "Webhooks",
+			buttons: [
+				{
+					kind: Button.ADD,
+					title: "Webhook",
+					path: addPath,
+					is_allowed: isAllowedProjectManage,
+				},
+				{ kind: Button.REFRESH },
+			],
+		},
+		table: {
+			url: (params: Params) => `/v0/projects/${params?.project}/webhooks`,
+			add: {
+				prefix: (
+					<div>
+						<h4>🐰 Route your regressions!</h4>
+						<p>
+							Webhooks send a signed request whenever an alert fires.
+							<br />
+							Tap below to add a webhook.
+						</p>
+					</div>
+				),
+				path: addPath,
+				text: "Add a Webhook",
+			},
+			row: {
+				key: "url",
+				items: [
+					{
+						kind: Row.TEXT,
+						key: "events",
+					},
+					{},
+					{},
+					{},
+				],
+				button: {
+					text: "View",
+					path: viewUuidPath,
+				},
+			},
+			name: "webhooks",
+		},
+	},
+	[Operation.ADD]: {
+		operation: Operation.ADD,
+		header: {
+			title: "Add Webhook",
+			path: parentPath,
+			path_to: "Webhooks",
+		},
+		form: {
+			url: (params: Params) => `/v0/projects/${params?.project}/webhooks`,
+			fields: [
+				{
+					kind: FieldKind.INPUT,
+					label: "URL",
+					key: "url",
+					value: "",
+					valid: null,
+					validate: true,
+					config: WEBHOOK_FIELDS.url,
+				},
+				{
+					kind: FieldKind.INPUT,
+					label: "Secret",
+					key: "secret",
+					value: "",
+					valid: null,
+					validate: true,
+					config: WEBHOOK_FIELDS.secret,
+				},
+				eventField("alert_created", true),
+				eventField("alert_resolved", true),
+				eventField("report_created", false),
+			],
+			path: createdUuidPath,
+		},
+	},
+	[Operation.VIEW]: {
+		operation: Operation.VIEW,
+		header: {
+			key: "url",
+			path: parentPath,
+			path_to: "Webhooks",
+			buttons: [{ kind: Button.REFRESH }],
+		},
+		deck: {
+			url: (params: Params) =>
+				`/v0/projects/${params?.project}/webhooks/${params?.webhook}`,
+			cards: [
+				{
+					kind: Card.FIELD,
+					label: "Webhook URL",
+					key: "url",
+					display: Display.RAW,
+					is_allowed: isAllowedProjectManage,
+					field: {
+						kind: FieldKind.INPUT,
+						label: "URL",
+						key: "url",
+						value: "",
+						valid: null,
+						validate: true,
+						config: WEBHOOK_FIELDS.url,
+					},
+				},
+				{
+					kind: Card.FIELD,
+					label: "Events",
+					key: "events",
+					display: Display.RAW,
+				},
+				{
+					kind: Card.FIELD,
+					label: "Webhook UUID",
+					key: "uuid",
+					display: Display.RAW,
+				},
+			],
+			buttons: [
+				{
+					kind: ActionButton.TEST,
+					path: (pathname: string) => `${pathname}/deliveries`,
+					is_allowed: isAllowedProjectManage,
+				},
+				{
+					kind: ActionButton.DELETE,
+					subtitle: "⚠️ All pending deliveries for this Webhook will be dropped! ⚠️",
+					path: parentPath,
+					is_allowed: isAllowedProjectManage,
+				},
+			],
+		},
+	},
+};
+
+export const webhookDeliveriesConfig = {
+	[Operation.LIST]: {
+		operation: Operation.LIST,
+		header: {
+			title: "Webhook Deliveries",
+			buttons: [{ kind: Button.REFRESH }],
+		},
+		table: {
+			url: (params: Params) =>
+				`/v0/projects/${params?.project}/webhooks/${params?.webhook}/deliveries`,
+			row: {
+				keys: [["event"], ["status"], ["created"]],
+				items: [
+					{
+						kind: Row.TEXT,
+						key: "status",
+					},
+					{
+						kind: Row.TEXT,
+						key: "response_status",
+					},
+					{
+						kind: Row.TEXT,
+						key: "attempts",
+					},
+					{
+						kind: Row.DATE_TIME,
+						key: "created",
+					},
+				],
+				button: {
+					text: "View Webhook",
+					path: parentPath,
+				},
+			},
+			name: "deliveries",
+		},
+	},
+};
+
+export default webhooksConfig;
@@ -13,8 +13,11 @@ export enum BencherResource {
 	METRICS = "metrics",
 	THRESHOLDS = "thresholds",
 	ALERTS = "alerts",
+	WEBHOOKS = "webhooks",
+	WEBHOOK_DELIVERIES = "webhook_deliveries",
 	USERS = "users",
 	TOKENS = "tokens",
+	NOTIFICATIONS = "notifications",
 	HELP = "help",
 }
 
@@ -44,10 +47,16 @@ export const resourceSingular = (resource: BencherResource) => {
 			return "threshold";
 		case BencherResource.ALERTS:
 			return "alert";
+		case BencherResource.WEBHOOKS:
+			return "webhook";
+		case BencherResource.WEBHOOK_DELIVERIES:
+			return "webhook delivery";
 		case BencherResource.USERS:
 			return "user";
 		case BencherResource.TOKENS:
 			return "token";
+		case BencherResource.NOTIFICATIONS:
+			return "notification";
 		case BencherResource.HELP:
 			return "help";
 	}
@@ -79,10 +88,16 @@ export const resourcePlural = (resource: BencherResource) => {
 			return "thresholds";
 		case BencherResource.ALERTS:
 			return "alerts";
+		case BencherResource.WEBHOOKS:
+			return "webhooks";
+		case BencherResource.WEBHOOK_DELIVERIES:
+			return "webhook deliveries";
 		case BencherResource.USERS:
 			return "users";
 		case BencherResource.TOKENS:
 			return "tokens";
+		case BencherResource.NOTIFICATIONS:
+			return "notifications";
 		case BencherResource.HELP:
 			return "help";
 	}
@@ -111,6 +126,7 @@ export enum Button {
 
 export enum ActionButton {
 	DELETE = "delete",
+	TEST = "test",
 }
 
 export enum Row {
@@ -0,0 +1,83 @@
+import FieldKind from "../../components/field/kind";
+import type { JsonNotification } from "../../types/bencher";
+import type { Params } from "../../util/url";
+import { Button, Card, Display, Operation } from "../types";
+import { parentPath } from "../util";
+
+const notificationsConfig = {
+	[Operation.LIST]: {
+		operation: Operation.LIST,
+		header: {
+			title: "Notifications",
+			buttons: [{ kind: Button.REFRESH }],
+		},
+		table: {
+			url: (params: Params) => `/v0/users/${params?.user}/notifications`,
+			add: {
+				prefix: (
+					<div>
+						<h4>🐰 You are not a member of any projects yet!</h4>
+						<p>
+							Once you are a member of a project,
+							<br />
+							you can choose to get an email whenever it generates alerts.
+						</p>
+					</div>
+				),
+				path: (_pathname: string) => "/console/organizations",
+				text: "View Organizations",
+			},
+			row: {
+				keys: [["project", "name"]],
+				items: [{}, {}, {}, {}],
+				button: {
+					text: "View",
+					path: (pathname: string, datum: JsonNotification) =>
+						`${pathname}/${datum?.project?.slug}`,
+				},
+			},
+			name: "notifications",
+		},
+	},
+	[Operation.VIEW]: {
+		operation: Operation.VIEW,
+		header: {
+			keys: [["project", "name"]],
+			path: parentPath,
+			path_to: "Notifications",
+			buttons: [{ kind: Button.REFRESH }],
+		},
+		deck: {
+			url: (params: Params) =>
+				`/v0/users/${params?.user}/notifications/${params?.project}`,
+			cards: [
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Project",
+					keys: ["project", "name"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.FIELD,
+					label: "Alert Emails",
+					key: "alert_email",
+					display: Display.SWITCH,
+					is_allowed: (_params: Params) => true,
+					field: {
+						kind: FieldKind.SWITCH,
+						label: "Email me when a report for this project generates alerts",
+						key: "alert_email",
+						value: false,
+						valid: true,
+						validate: false,
+						config: {
+							label: "Alert Emails",
+						},
+					},
+				},
+			],
+		},
+	},
+};
+
+export default notificationsConfig;
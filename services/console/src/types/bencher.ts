@@ -14,6 +14,8 @@ export interface JsonNewMetric {
 	value: number;
 	lower_value?: number;
 	upper_value?: number;
+	/** The raw samples that the value was computed from, if the harness reports them. */
+	samples?: number[];
 }
 
 export type JsonMetricsMap = Record<MeasureNameId, JsonNewMetric>;
@@ -46,14 +48,30 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	Welch = "welch",
+	MannWhitney = "mann_whitney",
+	ChangePoint = "change_point",
+	Bootstrap = "bootstrap",
+	Ewma = "ewma",
+	Holt = "holt",
 }
 
 export type SampleSize = number;
 
 export type Window = number;
 
+/**
+ * Select benchmarks by name.
+ * A selector wrapped in slashes (`/^foo_.*$/`) is a regular expression.
+ * A selector containing `*` or `?` is a glob.
+ * Otherwise, the selector is an exact benchmark name.
+ */
+export type BenchmarkSelector = string;
+
 export type Boundary = number;
 
+export type Smoothing = number;
+
 export interface JsonModel {
 	uuid: ModelUuid;
 	threshold: Uuid;
@@ -63,6 +81,8 @@ export interface JsonModel {
 	window?: Window;
 	lower_boundary?: Boundary;
 	upper_boundary?: Boundary;
+	level_smoothing?: Smoothing;
+	trend_smoothing?: Smoothing;
 	created: string;
 	replaced?: string;
 }
@@ -86,6 +106,8 @@ export interface JsonBoundary {
 	baseline?: number;
 	lower_limit?: number;
 	upper_limit?: number;
+	/** The p-value of the two-sample test, if one was used. */
+	p_value?: number;
 }
 
 export interface JsonBenchmarkMetric {
@@ -137,6 +159,7 @@ export interface JsonThreshold {
 	branch: JsonBranch;
 	testbed: JsonTestbed;
 	measure: JsonMeasure;
+	benchmark?: BenchmarkSelector;
 	statistic?: JsonModel;
 	model: JsonModel;
 	created: string;
@@ -151,6 +174,35 @@ export enum BoundaryLimit {
 export enum AlertStatus {
 	Active = "active",
 	Dismissed = "dismissed",
+	/** The alert has been acknowledged and assigned to a user. */
+	Acknowledged = "acknowledged",
+	/** The alert is silenced until a date time and/or a branch version number. */
+	Silenced = "silenced",
+	/** A later report for the same branch, testbed, benchmark, and measure was within the boundary limits. */
+	Resolved = "resolved",
+}
+
+export enum WebhookEvent {
+	/** A new alert was generated. */
+	AlertCreated = "alert_created",
+	/** An alert was resolved. */
+	AlertResolved = "alert_resolved",
+	/** A new report was created. */
+	ReportCreated = "report_created",
+	/**
+	 * A test delivery.
+	 * Test deliveries are always sent, so this event can not be subscribed to.
+	 */
+	Test = "test",
+}
+
+export enum WebhookDeliveryStatus {
+	/** The delivery is waiting to be sent or retried. */
+	Pending = "pending",
+	/** The delivery received a successful (2xx) response. */
+	Delivered = "delivered",
+	/** The delivery ran out of attempts without a successful response. */
+	Failed = "failed",
 }
 
 export interface JsonAlert {
@@ -160,7 +212,18 @@ export interface JsonAlert {
 	threshold: JsonThreshold;
 	benchmark: JsonBenchmarkMetric;
 	limit: BoundaryLimit;
+	/**
+	 * The composite threshold that generated the alert, if any.
+	 * The threshold and benchmark are for the first member that detected an outlier.
+	 */
+	composite_threshold?: Uuid;
 	status: AlertStatus;
+	/** The user that the alert is assigned to, if it is acknowledged. */
+	assignee?: JsonUser;
+	/** The alert is silenced until this date time, if it is silenced. */
+	silenced_until?: string;
+	/** The alert is silenced until the branch reaches this version number, if it is silenced. */
+	silenced_version?: VersionNumber;
 	created: string;
 	modified: string;
 }
@@ -342,6 +405,95 @@ export interface JsonAlertStats {
 export interface JsonUpdateAlert {
 	/** The new status of the alert. */
 	status?: AlertStatus;
+	/**
+	 * The slug or UUID of the user to assign an `acknowledged` alert to.
+	 * The user must be a member of the project's organization.
+	 * If not set when acknowledging an alert, then the alert is assigned to the authenticated user.
+	 */
+	assignee?: ResourceId;
+	/**
+	 * Silence the alert until this date time.
+	 * Only valid for a `silenced` alert.
+	 */
+	silenced_until?: string;
+	/**
+	 * Silence the alert until the branch reaches this version number.
+	 * Only valid for a `silenced` alert.
+	 */
+	silenced_version?: VersionNumber;
+}
+
+/** A change to the status of an alert */
+export interface JsonAlertEvent {
+	uuid: Uuid;
+	alert: Uuid;
+	status: AlertStatus;
+	assignee?: JsonUser;
+	silenced_until?: string;
+	silenced_version?: VersionNumber;
+	/**
+	 * The user that made the change.
+	 * Not set for changes made automatically by Bencher.
+	 */
+	user?: JsonUser;
+	/** The report that caused the change, for changes made automatically by Bencher. */
+	report?: Uuid;
+	created: string;
+}
+
+export interface JsonNewWebhook {
+	/** The URL to send webhook deliveries to. */
+	url: Url;
+	/**
+	 * The secret used to sign webhook deliveries.
+	 * Each delivery has an HMAC-SHA256 signature of its body in the `X-Bencher-Signature` header.
+	 */
+	secret: Secret;
+	/**
+	 * The events to subscribe to.
+	 * There must be at least one event.
+	 */
+	events: WebhookEvent[];
+}
+
+export interface JsonWebhook {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	events: WebhookEvent[];
+	created: string;
+	modified: string;
+}
+
+export interface JsonUpdateWebhook {
+	/** The new URL to send webhook deliveries to. */
+	url?: Url;
+	/** The new secret used to sign webhook deliveries. */
+	secret?: Secret;
+	/**
+	 * The new events to subscribe to.
+	 * This replaces all of the existing events.
+	 */
+	events?: WebhookEvent[];
+}
+
+export interface JsonWebhookDelivery {
+	uuid: Uuid;
+	webhook: Uuid;
+	event: WebhookEvent;
+	status: WebhookDeliveryStatus;
+	/** The JSON body of the delivery. */
+	payload: string;
+	/** The number of delivery attempts so far. */
+	attempts: number;
+	/** When the next delivery attempt will be made, if the delivery is still pending. */
+	next_attempt?: string;
+	/** The HTTP status code of the response to the last delivery attempt, if any. */
+	response_status?: number;
+	/** The error from the last delivery attempt, if any. */
+	error?: string;
+	created: string;
+	modified: string;
 }
 
 export interface JsonPerfAlert {
@@ -376,6 +528,66 @@ export interface JsonBranchVersion {
 	modified: string;
 }
 
+export interface JsonChangePoint {
+	metric: Uuid;
+	report: Uuid;
+	iteration: Iteration;
+	start_time: string;
+	version: JsonVersion;
+	direction: BoundaryLimit;
+	before: number;
+	after: number;
+	p_value: number;
+}
+
+export enum CompositeOperator {
+	And = "and",
+	Or = "or",
+}
+
+export interface JsonNewCompositeMember {
+	/** The UUID of the member threshold. */
+	threshold: Uuid;
+	/**
+	 * Only count an outlier on this side of the member threshold boundary.
+	 * If not set, an outlier on either side counts.
+	 */
+	limit?: BoundaryLimit;
+}
+
+export interface JsonNewCompositeThreshold {
+	/**
+	 * How the member threshold boundaries are combined.
+	 * With `and`, every member must detect an outlier to generate an alert.
+	 * With `or`, any member detecting an outlier generates an alert.
+	 */
+	operator: CompositeOperator;
+	/**
+	 * The member thresholds to combine.
+	 * There must be at least two members,
+	 * and all of them must share the same branch and testbed.
+	 * Member thresholds no longer generate alerts on their own.
+	 */
+	members: JsonNewCompositeMember[];
+}
+
+export interface JsonCompositeMember {
+	threshold: Uuid;
+	measure: JsonMeasure;
+	limit?: BoundaryLimit;
+}
+
+export interface JsonCompositeThreshold {
+	uuid: Uuid;
+	project: Uuid;
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	operator: CompositeOperator;
+	members: JsonCompositeMember[];
+	created: string;
+	modified: string;
+}
+
 export interface JsonOneMetric {
 	uuid: Uuid;
 	report: Uuid;
@@ -387,6 +599,7 @@ export interface JsonOneMetric {
 	benchmark: JsonBenchmark;
 	measure: JsonMeasure;
 	metric: JsonMetric;
+	samples?: number[];
 	threshold?: JsonThresholdModel;
 	boundary?: JsonBoundary;
 	alert?: JsonPerfAlert;
@@ -453,6 +666,7 @@ export interface JsonPerfMetric {
 	end_time: string;
 	version: JsonVersion;
 	metric: JsonMetric;
+	samples?: number[];
 	threshold?: JsonThresholdModel;
 	boundary?: JsonBoundary;
 	alert?: JsonPerfAlert;
@@ -561,9 +775,11 @@ export enum Adapter {
 	RustCriterion = "rust_criterion",
 	RustIai = "rust_iai",
 	RustIaiCallgrind = "rust_iai_callgrind",
+	RustDivan = "rust_divan",
 	Cpp = "cpp",
 	CppGoogle = "cpp_google",
 	CppCatch2 = "cpp_catch2",
+	CppGoogleJson = "cpp_google_json",
 	Go = "go",
 	GoBench = "go_bench",
 	Java = "java",
@@ -580,6 +796,16 @@ export enum Adapter {
 	RubyBenchmark = "ruby_benchmark",
 	Shell = "shell",
 	ShellHyperfine = "shell_hyperfine",
+	ShellPerfStat = "shell_perf_stat",
+	Test = "test",
+	TestJunit = "test_junit",
+	TestTap = "test_tap",
+	Csv = "csv",
+	OpenMetrics = "open_metrics",
+	Load = "load",
+	LoadK6 = "load_k6",
+	LoadWrk = "load_wrk",
+	LoadVegeta = "load_vegeta",
 }
 
 export interface JsonReport {
@@ -692,6 +918,19 @@ export interface JsonUpdateUser {
 	locked?: boolean;
 }
 
+/** The notification preferences of a user for a project */
+export interface JsonNotification {
+	user: Uuid;
+	project: JsonProject;
+	/** Whether the user is emailed when a report for the project generates alerts. */
+	alert_email: boolean;
+}
+
+export interface JsonUpdateNotification {
+	/** Update whether the user is emailed when a report for the project generates alerts. */
+	alert_email?: boolean;
+}
+
 export interface JsonNewToken {
 	/**
 	 * The name of the token.
@@ -1,5 +1,6 @@
 import {
 	is_valid_benchmark_name,
+	is_valid_benchmark_selector,
 	is_valid_boundary,
 	is_valid_branch_name,
 	is_valid_card_cvc,
@@ -14,6 +15,7 @@ import {
 	is_valid_plan_level,
 	is_valid_sample_size,
 	is_valid_slug,
+	is_valid_smoothing,
 	is_valid_url,
 	is_valid_user_name,
 	is_valid_uuid,
@@ -64,6 +66,11 @@ export const validBranchName = (branch_name: string): boolean =>
 export const validBenchmarkName = (benchmark_name: string): boolean =>
 	validString(benchmark_name, is_valid_benchmark_name);
 
+export const validOptionBenchmarkSelector = (
+	benchmark_selector: undefined | null | string,
+): boolean =>
+	validOptionString(benchmark_selector, is_valid_benchmark_selector);
+
 export const validNonEmpty = (non_empty: string): boolean =>
 	validString(non_empty, is_valid_non_empty);
 
@@ -79,6 +86,9 @@ export const validJwt = (token: undefined | null | string): boolean =>
 export const validOptionJwt = (token: undefined | null | string): boolean =>
 	validOptionString(token, (i) => i.length === 0 || is_valid_jwt(i));
 
+export const validUrl = (url: string): boolean =>
+	validString(url, is_valid_url);
+
 export const validOptionUrl = (url: undefined | null | string): boolean =>
 	validOptionString(url, (i) => i.length === 0 || is_valid_url(i));
 
@@ -138,6 +148,9 @@ export const validCdfBoundary = (boundary: string): boolean =>
 export const validIqrBoundary = (boundary: string): boolean =>
 	validateNumber(boundary, is_valid_iqr_boundary);
 
+export const validSmoothing = (smoothing: string): boolean =>
+	validateNumber(smoothing, is_valid_smoothing);
+
 export const validSampleSize = (sample_size: string) =>
 	validU32(sample_size) && validateNumber(sample_size, is_valid_sample_size);
 